@@ -0,0 +1,106 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Build script: extract the explanation of each rule from the doc comment of its
+//! check method, displayed by `poexam rules --explain <rule>`.
+
+use std::{env, fmt::Write as _, fs, path::Path};
+
+fn main() {
+    let rules_dir = Path::new("src/rules");
+    println!("cargo:rerun-if-changed={}", rules_dir.display());
+    let mut paths: Vec<_> = fs::read_dir(rules_dir)
+        .expect("read rules directory")
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    paths.sort();
+    let mut explanations: Vec<(String, String)> = vec![];
+    for path in paths {
+        let content = fs::read_to_string(&path).expect("read rule file");
+        explanations.extend(extract_explanations(&content));
+    }
+    explanations.sort();
+    let mut output = String::from("const RULE_EXPLANATIONS: &[(&str, &str)] = &[\n");
+    for (name, explanation) in explanations {
+        let _ = writeln!(output, "    ({name:?}, {explanation:?}),");
+    }
+    output.push_str("];\n");
+    let out_path = Path::new(&env::var("OUT_DIR").expect("OUT_DIR")).join("explanations.rs");
+    fs::write(out_path, output).expect("write explanations");
+}
+
+/// Extract the explanation of each rule implemented in a file: the first doc comment
+/// of a check method in the `impl RuleChecker` block, after the name of the rule.
+fn extract_explanations(content: &str) -> Vec<(String, String)> {
+    let mut explanations = vec![];
+    let mut name: Option<String> = None;
+    let mut in_name = false;
+    let mut doc: Vec<&str> = vec![];
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("impl RuleChecker for ") {
+            name = None;
+            doc.clear();
+        } else if trimmed.starts_with("fn name(&self)") {
+            in_name = true;
+        } else if in_name {
+            name = Some(trimmed.trim_matches('"').to_string());
+            in_name = false;
+        } else if let Some(text) = trimmed.strip_prefix("///") {
+            doc.push(text.strip_prefix(' ').unwrap_or(text));
+        } else if trimmed.starts_with("#[") {
+            // Attribute between the doc comment and the method.
+        } else if trimmed.starts_with("fn check_")
+            && !doc.is_empty()
+            && let Some(name) = name.take()
+        {
+            explanations.push((name, format_doc(&doc)));
+            doc.clear();
+        } else {
+            doc.clear();
+        }
+    }
+    explanations
+}
+
+/// Format a doc comment for display in a terminal: the code blocks are indented and
+/// the links are replaced by their text.
+fn format_doc(doc: &[&str]) -> String {
+    let mut output = String::new();
+    let mut in_code = false;
+    for line in doc {
+        if line.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            output.push_str("    ");
+            output.push_str(line);
+        } else {
+            output.push_str(&remove_links(line));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Replace the links `[text](target)` by `text`.
+fn remove_links(line: &str) -> String {
+    let mut output = String::new();
+    let mut rest = line;
+    while let Some(end) = rest.find("](") {
+        let Some(start) = rest[..end].rfind('[') else {
+            break;
+        };
+        let Some(close) = rest[end..].find(')').map(|pos| end + pos) else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        output.push_str(&rest[start + 1..end]);
+        rest = &rest[close + 1..];
+    }
+    output.push_str(rest);
+    output
+}
@@ -0,0 +1,197 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Analyze entries of PO files: number of words and characters of each entry.
+
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::args;
+use crate::dir::find_po_files;
+use crate::po::format::strip_formats;
+use crate::po::parser::Parser;
+use crate::stats::{count_chars, count_words};
+
+/// Analysis of an entry: number of words and characters in the source strings
+/// (`msgid` and `msgid_plural`).
+#[derive(Debug, Serialize)]
+pub struct EntryAnalysis {
+    /// Path to the PO file.
+    pub path: PathBuf,
+    /// Line number of the entry.
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msgctxt: Option<String>,
+    pub msgid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msgid_plural: Option<String>,
+    /// Number of words in the source strings (format strings are ignored).
+    pub words_id: u64,
+    /// Number of characters in the source strings (format strings are ignored).
+    pub chars_id: u64,
+    pub fuzzy: bool,
+    pub obsolete: bool,
+    pub translated: bool,
+}
+
+/// Analyze all entries yielded by the parser (the header is skipped).
+fn analyze_entries(
+    path: &Path,
+    parser: &mut Parser,
+    args: &args::AnalyzeArgs,
+) -> Vec<EntryAnalysis> {
+    parser
+        .filter(|entry| !entry.is_header())
+        .filter(|entry| {
+            !args.untranslated || (!entry.obsolete && (entry.fuzzy || !entry.is_translated()))
+        })
+        .map(|entry| {
+            let (words_id, chars_id) = entry
+                .msgid
+                .iter()
+                .chain(entry.msgid_plural.iter())
+                .map(|msgid| strip_formats(&msgid.value, entry.format_language))
+                .fold((0, 0), |(words, chars), stripped| {
+                    (
+                        words + count_words(&stripped, args.words_keep_underscore),
+                        chars + count_chars(&stripped),
+                    )
+                });
+            EntryAnalysis {
+                path: path.to_path_buf(),
+                line: entry.line_number,
+                msgctxt: entry.msgctxt.as_ref().map(|msgctxt| msgctxt.value.clone()),
+                msgid: entry
+                    .msgid
+                    .as_ref()
+                    .map(|msgid| msgid.value.clone())
+                    .unwrap_or_default(),
+                msgid_plural: entry
+                    .msgid_plural
+                    .as_ref()
+                    .map(|msgid_plural| msgid_plural.value.clone()),
+                words_id,
+                chars_id,
+                fuzzy: entry.fuzzy,
+                obsolete: entry.obsolete,
+                translated: entry.is_translated(),
+            }
+        })
+        .collect()
+}
+
+/// Analyze all entries of a single PO file at the given path.
+fn analyze_file(
+    path: &Path,
+    args: &args::AnalyzeArgs,
+) -> Result<Vec<EntryAnalysis>, std::io::Error> {
+    let file = Parser::from_path(path)?;
+    Ok(analyze_entries(path, &mut file.parser(), args))
+}
+
+/// Analyze and display entries of all PO files, as JSON (sorted by path).
+///
+/// Returns 1 if at least one file could not be analyzed, otherwise 0.
+pub fn run_analyze(args: &args::AnalyzeArgs) -> i32 {
+    let mut po_files: Vec<PathBuf> = find_po_files(&args.files, &args.exclude, false)
+        .into_iter()
+        .collect();
+    po_files.sort();
+    let results: Vec<Result<Vec<EntryAnalysis>, std::io::Error>> = po_files
+        .par_iter()
+        .map(|path| {
+            analyze_file(path, args).inspect_err(|e| {
+                eprintln!("Error processing file {}: {}", path.display(), e);
+            })
+        })
+        .collect();
+    let rc = i32::from(results.iter().any(Result::is_err));
+    let entries: Vec<EntryAnalysis> = results
+        .into_iter()
+        .flat_map(Result::unwrap_or_default)
+        .collect();
+    println!("{}", serde_json::to_string(&entries).unwrap_or_default());
+    rc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = r#"
+msgid ""
+msgstr "Language: fr\n"
+
+#, c-format
+msgid "%d files in the folder"
+msgstr "%d fichiers dans le dossier"
+
+msgctxt "menu"
+msgid "Open file"
+msgstr ""
+
+#, fuzzy
+msgid "Save as"
+msgstr "Enregistrer"
+
+#~ msgid "Old string"
+#~ msgstr ""
+
+msgid "one file"
+msgid_plural "%d files"
+msgstr[0] "un fichier"
+msgstr[1] "%d fichiers"
+"#;
+
+    fn analyze_args() -> args::AnalyzeArgs {
+        args::AnalyzeArgs {
+            files: vec![],
+            exclude: vec![],
+            untranslated: false,
+            words_keep_underscore: false,
+        }
+    }
+
+    #[test]
+    fn test_analyze_entries() {
+        let mut parser = Parser::new(CONTENT.as_bytes());
+        let entries = analyze_entries(Path::new("fr.po"), &mut parser, &analyze_args());
+        let json = serde_json::to_string(&entries).expect("serialize entries");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+        let value = value.as_array().expect("array");
+        assert_eq!(value.len(), 5);
+        assert_eq!(value[0]["path"], "fr.po");
+        assert_eq!(value[0]["line"], 5);
+        assert!(value[0].get("msgctxt").is_none());
+        assert!(value[0].get("msgid_plural").is_none());
+        assert_eq!(value[0]["msgid"], "%d files in the folder");
+        assert_eq!(value[0]["words_id"], 4);
+        assert_eq!(value[0]["chars_id"], 16);
+        assert_eq!(value[0]["fuzzy"], false);
+        assert_eq!(value[0]["obsolete"], false);
+        assert_eq!(value[0]["translated"], true);
+        assert_eq!(value[1]["msgctxt"], "menu");
+        assert_eq!(value[1]["words_id"], 2);
+        assert_eq!(value[1]["translated"], false);
+        assert_eq!(value[2]["fuzzy"], true);
+        assert_eq!(value[3]["obsolete"], true);
+        assert_eq!(value[4]["msgid_plural"], "%d files");
+        assert_eq!(value[4]["words_id"], 4);
+        assert_eq!(value[4]["chars_id"], 13);
+    }
+
+    #[test]
+    fn test_analyze_entries_untranslated() {
+        let mut parser = Parser::new(CONTENT.as_bytes());
+        let args = args::AnalyzeArgs {
+            untranslated: true,
+            ..analyze_args()
+        };
+        let entries = analyze_entries(Path::new("fr.po"), &mut parser, &args);
+        let msgids: Vec<&str> = entries.iter().map(|e| e.msgid.as_str()).collect();
+        assert_eq!(msgids, vec!["Open file", "Save as"]);
+    }
+}
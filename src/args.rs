@@ -7,9 +7,11 @@
 use std::path::PathBuf;
 
 use clap::{Args, Parser, Subcommand, ValueEnum};
+use colored::Colorize;
+use globset::Glob;
 use serde::{Deserialize, Serialize};
 
-use crate::diagnostic::Severity;
+use crate::diagnostic::{HighlightStyle, Severity};
 
 #[derive(Debug, Parser)]
 #[command(
@@ -22,6 +24,58 @@ use crate::diagnostic::Severity;
 pub struct Cli {
     #[command(subcommand)]
     pub command: Command,
+
+    /// Use colors in output: `auto` uses colors only if the output is a terminal
+    /// (the environment variables `NO_COLOR` and `CLICOLOR_FORCE` are honored)
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub color: ColorChoice,
+
+    /// Number of threads used to process files in parallel with commands `check`
+    /// and `stats` [default: number of CPUs]
+    #[arg(short, long, global = true, value_name = "N")]
+    pub jobs: Option<usize>,
+}
+
+/// When to use colors in output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    #[default]
+    /// Use colors if the output is a terminal, unless `NO_COLOR` is set
+    Auto,
+
+    /// Always use colors
+    Always,
+
+    /// Never use colors
+    Never,
+}
+
+impl ColorChoice {
+    /// Enable or disable colors in output.
+    pub fn apply(self) {
+        match self {
+            Self::Auto => colored::control::unset_override(),
+            Self::Always => colored::control::set_override(true),
+            Self::Never => colored::control::set_override(false),
+        }
+    }
+}
+
+/// Configure the number of threads used to process files in parallel (`None` or
+/// `0` keeps the default: one thread per CPU).
+pub fn apply_jobs(jobs: Option<usize>) {
+    let Some(jobs) = jobs.filter(|jobs| *jobs > 0) else {
+        return;
+    };
+    if let Err(err) = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build_global()
+    {
+        eprintln!(
+            "{}: could not set number of jobs to {jobs}: {err}",
+            "Warning".yellow()
+        );
+    }
 }
 
 #[derive(Debug, Subcommand)]
@@ -29,14 +83,23 @@ pub enum Command {
     /// Check files
     Check(Box<CheckArgs>),
 
+    /// Fix leading/trailing whitespace and newlines in files
+    Fix(FixArgs),
+
     /// Display rules used to check files
     Rules(RulesArgs),
 
     /// Display statistics about files
     Stats(StatsArgs),
 
+    /// Display words and characters of each entry in files (JSON)
+    Analyze(AnalyzeArgs),
+
     /// Run the language server (LSP) over stdin/stdout
     Lsp(LspArgs),
+
+    /// Display the JSON Schema of diagnostics displayed with `check --output json`
+    Schema(SchemaArgs),
 }
 
 /// Arguments for the `check` command.
@@ -47,6 +110,16 @@ pub struct CheckArgs {
     #[clap(help = "List of files or directories [default: .]")]
     pub files: Vec<PathBuf>,
 
+    /// Exclude files matching this glob pattern, relative to the searched directory
+    /// (can be given multiple times), for example: `**/_build/**`
+    #[arg(long)]
+    pub exclude: Vec<Glob>,
+
+    /// Read the list of files from this file (one path per line, `-` for standard
+    /// input) instead of searching the files or directories
+    #[arg(long, value_name = "PATH", conflicts_with = "files")]
+    pub files_from: Option<PathBuf>,
+
     /// Display settings used to check files
     #[arg(long)]
     pub show_settings: bool,
@@ -71,6 +144,19 @@ pub struct CheckArgs {
     #[arg(long)]
     pub obsolete: bool,
 
+    /// Check only untranslated entries (with `--only-fuzzy`: untranslated and fuzzy entries)
+    #[arg(long)]
+    pub only_untranslated: bool,
+
+    /// Check only fuzzy entries, implies `--fuzzy` (with `--only-untranslated`: untranslated and fuzzy entries)
+    #[arg(long)]
+    pub only_fuzzy: bool,
+
+    /// Check all files as templates, like `*.pot` files: only the rules checking the
+    /// source strings are used, and untranslated entries are checked
+    #[arg(long)]
+    pub pot: bool,
+
     /// Select rules to apply (comma-separated list), see `poexam rules`
     #[arg(short, long)]
     pub select: Option<String>,
@@ -87,7 +173,7 @@ pub struct CheckArgs {
     #[arg(long)]
     pub path_dicts: Option<PathBuf>,
 
-    /// Path to a directory containing files with list of words to add per language (files are `*.dic`, e.g. `en_US.dic`, with one word per line)
+    /// Path to a directory containing files with list of words to add per language (files are `*.dic` or `*.txt` with one word per line, or `*.json` with an array of strings, e.g. `en_US.dic`)
     #[arg(long)]
     pub path_words: Option<PathBuf>,
 
@@ -99,14 +185,38 @@ pub struct CheckArgs {
     #[arg(long)]
     pub no_trans_file: Option<PathBuf>,
 
+    /// Path to a directory with glossaries used by rule "glossary": one file per language (e.g. `fr.tsv`), with a source term and its translation on each line, separated by a tab
+    #[arg(long)]
+    pub glossary_dir: Option<PathBuf>,
+
     /// Language used to check source strings (default: `en_US`)
     #[arg(long)]
     pub lang_id: Option<String>,
 
+    /// Language used to check translated strings (default: language found in PO file header)
+    #[arg(long)]
+    pub lang_str: Option<String>,
+
     /// Check spelling only for these languages (comma-separated list of language ids, e.g. `en_US,fr`); by default all languages are checked
     #[arg(long)]
     pub langs: Option<String>,
 
+    /// Display the top suggestion of the dictionary for each misspelled word in spelling rules (slower)
+    #[arg(long)]
+    pub spell_suggest: bool,
+
+    /// Minimum length of words checked in spelling rules, shorter words are ignored (default: 2)
+    #[arg(long)]
+    pub spell_min_length: Option<usize>,
+
+    /// Split identifiers at lowercase to uppercase boundaries (e.g. `fileName` is checked as `file` and `Name`) in spelling rules
+    #[arg(long)]
+    pub spell_split_identifiers: bool,
+
+    /// Keep underscores in words (e.g. `snake_case` is one word) in spelling rules
+    #[arg(long)]
+    pub words_keep_underscore: bool,
+
     /// Factor used to determine if a translation is too short compared to the source (default: 8, min: 2)
     #[arg(long, value_parser = clap::value_parser!(u16).range(2..))]
     pub short_factor: Option<u16>,
@@ -115,7 +225,35 @@ pub struct CheckArgs {
     #[arg(long, value_parser = clap::value_parser!(u16).range(2..))]
     pub long_factor: Option<u16>,
 
-    /// Report only diagnostics with this severity (can be given multiple times); by default all diagnostics are reported
+    /// Minimum ratio of translation/source lengths in rule "length-ratio" (default: 0.2)
+    #[arg(long)]
+    pub length_ratio_min: Option<f64>,
+
+    /// Maximum ratio of translation/source lengths in rule "length-ratio" (default: 3.0)
+    #[arg(long)]
+    pub length_ratio_max: Option<f64>,
+
+    /// Source strings allowed to be unchanged in translation in rule "unchanged" (comma-separated list, e.g. `OK,Menu,Email`)
+    #[arg(long)]
+    pub unchanged_allow: Option<String>,
+
+    /// Minimum number of words in source string to report an unchanged translation in rule "unchanged" (default: 0)
+    #[arg(long)]
+    pub unchanged_min_words: Option<usize>,
+
+    /// Maximum number of words in source string to require a context (msgctxt) in rule "context-missing" (default: 1)
+    #[arg(long)]
+    pub context_max_words: Option<usize>,
+
+    /// Languages using a decimal comma, checked by rule "number-locale" (comma-separated list, default: `de,es,fr,it,nl,pt,ru`)
+    #[arg(long)]
+    pub number_locales: Option<String>,
+
+    /// Maximum number of obsolete entries in a file before it is reported by rule "obsolete-count" (default: 10)
+    #[arg(long)]
+    pub max_obsolete: Option<usize>,
+
+    /// Report only diagnostics with this severity (can be given multiple times), after the severity overrides of the configuration file; by default all diagnostics are reported
     #[arg(short = 'e', long, value_enum)]
     pub severity: Vec<Severity>,
 
@@ -143,14 +281,54 @@ pub struct CheckArgs {
     #[arg(short, long)]
     pub file_stats: bool,
 
+    /// Display paths of files relative to this directory (default: current directory);
+    /// files outside this directory are displayed with an absolute path
+    #[arg(long)]
+    pub relative_to: Option<PathBuf>,
+
+    /// Display the location of diagnostics (`path:line`) as hyperlinks to the files,
+    /// for terminals supporting OSC-8 hyperlinks (ignored if the output is not a terminal)
+    #[arg(long)]
+    pub hyperlinks: bool,
+
+    /// Style used to display the highlighted parts of lines in diagnostics (used only with `human` output format)
+    #[arg(long, value_enum, default_value_t)]
+    pub highlight_style: HighlightStyle,
+
+    /// Report only diagnostics on lines added or changed in this unified diff file
+    /// (for example the output of `git diff`), or `-` to read it from standard input
+    #[arg(long, conflicts_with = "fix")]
+    pub diff: Option<PathBuf>,
+
     /// Output format
     #[arg(short, long, value_enum, default_value_t)]
     pub output: CheckOutputFormat,
 
+    /// Display an object with a summary of the check (`summary`) and the diagnostics
+    /// (`diagnostics`) instead of an array of diagnostics (used only with `json` output format)
+    #[arg(long)]
+    pub json_summary: bool,
+
     /// Quiet mode: do not report any error, only set the exit code
     #[arg(short, long)]
     pub quiet: bool,
 
+    /// Display a progress bar on standard error while files are checked (only with
+    /// `human` output format, if standard error is a terminal and not in quiet mode)
+    #[arg(long)]
+    pub progress: bool,
+
+    /// Exit with a non-zero code only if the number of problems is greater than this value
+    /// (default: 0)
+    #[arg(long)]
+    pub max_problems: Option<usize>,
+
+    /// Exit with a non-zero code if there is any warning or error, even if the
+    /// number of problems does not exceed `--max-problems`; without `--max-problems`,
+    /// info diagnostics alone do not make the command fail
+    #[arg(long)]
+    pub error_on_warning: bool,
+
     /// Rewrite files in place, applying every diagnostic that carries a safe auto-fix
     #[arg(long)]
     pub fix: bool,
@@ -164,6 +342,11 @@ pub struct CheckArgs {
     /// 0 disables wrapping (matches `msgcat --width=0` / `msgcat --no-wrap`)
     #[arg(long)]
     pub width: Option<usize>,
+
+    /// Watch the files after the first check and check again the files changed, until
+    /// Ctrl-C is pressed (only with human output, incompatible with `--fix`)
+    #[arg(long, conflicts_with = "fix")]
+    pub watch: bool,
 }
 
 /// Sort of errors.
@@ -180,20 +363,171 @@ pub enum CheckSort {
     Rule,
 }
 
+/// Rules whose fixes are applied by the `fix` command.
+pub const FIX_RULES: [&str; 3] = ["newlines", "whitespace-end", "whitespace-start"];
+
+/// Arguments for the `fix` command.
+#[derive(Debug, Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct FixArgs {
+    /// List of files or directories
+    #[clap(help = "List of files or directories [default: .]")]
+    pub files: Vec<PathBuf>,
+
+    /// Exclude files matching this glob pattern, relative to the searched directory
+    /// (can be given multiple times), for example: `**/_build/**`
+    #[arg(long)]
+    pub exclude: Vec<Glob>,
+
+    /// Force a configuration file
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+
+    /// Ignore all configuration files
+    #[arg(long)]
+    pub no_config: bool,
+
+    /// Fix fuzzy entries (not fixed by default)
+    #[arg(long)]
+    pub fuzzy: bool,
+
+    /// Fix obsolete entries (not fixed by default)
+    #[arg(long)]
+    pub obsolete: bool,
+
+    /// Do not write files, display the fixed content of changed files on standard output
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Output page width used when rewriting msgstr blocks (default: 79);
+    /// 0 disables wrapping (matches `msgcat --width=0` / `msgcat --no-wrap`)
+    #[arg(long)]
+    pub width: Option<usize>,
+}
+
+impl FixArgs {
+    /// Build the arguments of a `check` command that reports only the diagnostics
+    /// fixed by the `fix` command.
+    pub fn to_check_args(&self) -> CheckArgs {
+        CheckArgs {
+            files: self.files.clone(),
+            exclude: self.exclude.clone(),
+            files_from: None,
+            show_settings: false,
+            config: self.config.clone(),
+            no_config: self.no_config,
+            fuzzy: self.fuzzy,
+            noqa: false,
+            obsolete: self.obsolete,
+            only_untranslated: false,
+            only_fuzzy: false,
+            pot: false,
+            select: Some(FIX_RULES.join(",")),
+            ignore: None,
+            path_msgfmt: None,
+            path_dicts: None,
+            path_words: None,
+            force_trans_file: None,
+            no_trans_file: None,
+            glossary_dir: None,
+            lang_id: None,
+            lang_str: None,
+            langs: None,
+            spell_suggest: false,
+            spell_min_length: None,
+            spell_split_identifiers: false,
+            words_keep_underscore: false,
+            short_factor: None,
+            long_factor: None,
+            length_ratio_min: None,
+            length_ratio_max: None,
+            unchanged_allow: None,
+            unchanged_min_words: None,
+            context_max_words: None,
+            number_locales: None,
+            max_obsolete: None,
+            severity: vec![],
+            punc_ignore_ellipsis: false,
+            accelerator: None,
+            no_errors: false,
+            sort: CheckSort::default(),
+            rule_stats: false,
+            file_stats: false,
+            relative_to: None,
+            hyperlinks: false,
+            highlight_style: HighlightStyle::default(),
+            diff: None,
+            output: CheckOutputFormat::default(),
+            json_summary: false,
+            quiet: false,
+            max_problems: None,
+            progress: false,
+            error_on_warning: false,
+            fix: !self.dry_run,
+            unsafe_fixes: false,
+            width: self.width,
+            watch: false,
+        }
+    }
+}
+
 /// Arguments for the `rules` command.
 #[derive(Debug, Args)]
-pub struct RulesArgs;
+pub struct RulesArgs {
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t)]
+    pub output: RulesOutputFormat,
+
+    /// Display the explanation of a rule, with examples of wrong and correct entries
+    #[arg(long, value_name = "RULE")]
+    pub explain: Option<String>,
+}
+
+/// Arguments for the `analyze` command.
+#[derive(Debug, Args)]
+pub struct AnalyzeArgs {
+    /// List of files or directories (default: .)
+    pub files: Vec<PathBuf>,
+
+    /// Exclude files matching this glob pattern, relative to the searched directory
+    /// (can be given multiple times), for example: `**/_build/**`
+    #[arg(long)]
+    pub exclude: Vec<Glob>,
+
+    /// Display only the entries to translate: untranslated or fuzzy (obsolete entries are ignored)
+    #[arg(short, long)]
+    pub untranslated: bool,
+
+    /// Keep underscores in words (e.g. `snake_case` is one word) when counting words
+    #[arg(long)]
+    pub words_keep_underscore: bool,
+}
 
 /// Arguments for the `lsp` command.
 #[derive(Debug, Args)]
 pub struct LspArgs;
 
+/// Arguments for the `schema` command.
+#[derive(Debug, Args)]
+pub struct SchemaArgs;
+
 /// Arguments for the `stats` command.
 #[derive(Debug, Args)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct StatsArgs {
     /// List of files or directories (default: .)
     pub files: Vec<PathBuf>,
 
+    /// Exclude files matching this glob pattern, relative to the searched directory
+    /// (can be given multiple times), for example: `**/_build/**`
+    #[arg(long)]
+    pub exclude: Vec<Glob>,
+
+    /// Read the list of files from this file (one path per line, `-` for standard
+    /// input) instead of searching the files or directories
+    #[arg(long, value_name = "PATH", conflicts_with = "files")]
+    pub files_from: Option<PathBuf>,
+
     /// Output format
     #[arg(short, long, value_enum, default_value_t)]
     pub output: StatsOutputFormat,
@@ -205,6 +539,31 @@ pub struct StatsArgs {
     /// Display extra statistics on words and characters
     #[arg(short, long)]
     pub words: bool,
+
+    /// Display the number of sentences in extra statistics (requires `--words`)
+    #[arg(long, requires = "words")]
+    pub sentences: bool,
+
+    /// Keep underscores in words (e.g. `snake_case` is one word) when counting words
+    #[arg(long, requires = "words")]
+    pub words_keep_underscore: bool,
+
+    /// Group statistics by language (found in header of files), sorted by language
+    #[arg(short, long)]
+    pub group_by_language: bool,
+
+    /// Exit with a non-zero code if the percentage of translated entries of a file is lower than this value
+    #[arg(long, value_parser = clap::value_parser!(u64).range(0..=100))]
+    pub min_translated: Option<u64>,
+
+    /// Exit with a non-zero code if the percentage of translated entries of all files is lower than this value
+    #[arg(long, value_parser = clap::value_parser!(u64).range(0..=100))]
+    pub min_translated_total: Option<u64>,
+
+    /// Display a progress bar on standard error while files are read (only with
+    /// `human` output format and if standard error is a terminal)
+    #[arg(long)]
+    pub progress: bool,
 }
 
 /// Output format for `check` command.
@@ -217,11 +576,23 @@ pub enum CheckOutputFormat {
     /// JSON
     Json,
 
+    /// NDJSON (newline-delimited JSON): one diagnostic per line
+    Ndjson,
+
     /// List of all misspelled words (one per line)
     Misspelled,
 
     /// SARIF (Static Analysis Results Interchange Format) v2.1.0
     Sarif,
+
+    /// GitHub Actions workflow commands (annotations)
+    Github,
+
+    /// `JUnit` XML
+    Junit,
+
+    /// TAP (Test Anything Protocol) version 13
+    Tap,
 }
 
 impl std::fmt::Display for CheckOutputFormat {
@@ -230,8 +601,33 @@ impl std::fmt::Display for CheckOutputFormat {
         match self {
             Self::Human => write!(f, "human"),
             Self::Json => write!(f, "json"),
+            Self::Ndjson => write!(f, "ndjson"),
             Self::Misspelled => write!(f, "misspelled"),
             Self::Sarif => write!(f, "sarif"),
+            Self::Github => write!(f, "github"),
+            Self::Junit => write!(f, "junit"),
+            Self::Tap => write!(f, "tap"),
+        }
+    }
+}
+
+/// Output format for `rules` command.
+#[derive(Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+pub enum RulesOutputFormat {
+    #[default]
+    /// Human readable text format
+    Human,
+
+    /// JSON
+    Json,
+}
+
+impl std::fmt::Display for RulesOutputFormat {
+    /// Display the output format as a string.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Human => write!(f, "human"),
+            Self::Json => write!(f, "json"),
         }
     }
 }
@@ -266,4 +662,7 @@ pub enum StatsSort {
 
     /// Sort by status (high % translated first), then by path
     Status,
+
+    /// Sort by size (most entries first), then by path
+    Size,
 }
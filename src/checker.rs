@@ -7,11 +7,14 @@
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     fs::File,
-    io::Read,
+    hash::{BuildHasher, RandomState},
+    io::{Read, Write},
     ops::Range,
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
+use colored::Colorize;
 use rayon::prelude::*;
 use spellbook::Dictionary;
 
@@ -19,16 +22,25 @@ use crate::{
     args,
     config::{self, Config, find_config_path},
     diagnostic::{Diagnostic, Severity},
-    dict,
-    dir::find_po_files,
+    dict::{self, DictCache},
+    diff::ChangedLines,
+    dir::{find_po_files, get_po_files, relative_path},
     fix::{Edit, FixTarget, apply_msgstr_fixes},
     po::{
         entry::Entry, parser::Parser, wrap::format_msgstr_block, writer::write_with_replacements,
     },
+    progress::Progress,
     result::display_result,
-    rules::rule::{Rule, Rules, get_selected_rules},
+    rules::{
+        glossary::{GlossaryTerm, load_glossary},
+        rule::{Rule, Rules, get_selected_rules},
+    },
+    watch::watch_files,
 };
 
+/// Minimum size of a file (in bytes) to check its entries in parallel.
+const PARALLEL_MIN_SIZE: usize = 1024 * 1024;
+
 #[derive(Default)]
 pub struct CheckFileResult {
     pub path: PathBuf,
@@ -40,20 +52,51 @@ pub struct CheckFileResult {
     pub fixes_applied: usize,
 }
 
+impl CheckFileResult {
+    /// Make the path of the file (and of its diagnostics) relative to the directory
+    /// `base` (see [`relative_path`]).
+    fn relative_to(mut self, base: &Path) -> Self {
+        let path = relative_path(&self.path, base);
+        for diag in &mut self.diagnostics {
+            diag.path.clone_from(&path);
+        }
+        self.path = path;
+        self
+    }
+}
+
 #[derive(Default)]
 pub struct Checker<'d> {
     pub path: PathBuf,
     pub config: Config,
-    pub dict_id: Option<Dictionary>,
-    pub dict_str: Option<Dictionary>,
+    pub dict_id: Option<Arc<Dictionary>>,
+    pub dict_str: Option<Arc<Dictionary>>,
     /// Lowercase words loaded from `check.force_trans_file` (one per line).
     /// Used by the `force-trans` rule.
     pub force_trans_words: Option<HashSet<String>>,
     /// Lowercase words loaded from `check.no_trans_file` (one per line).
     /// Used by the `no-trans` rule.
     pub no_trans_words: Option<HashSet<String>>,
+    /// Terms loaded from the glossary of the language of the file (in
+    /// `check.glossary_dir`). Used by the `glossary` rule.
+    pub glossary: Option<Vec<GlossaryTerm>>,
+    /// Whether the `punc-end` rule is enabled: the `ellipsis` rule then leaves
+    /// the trailing punctuation differences to it.
+    pub punc_end_rule: bool,
+    /// Line number of the first entry with the same context and id, for each
+    /// duplicate entry (by line number). Used by the `duplicate` rule.
+    pub duplicate_lines: HashMap<usize, usize>,
+    /// Number of obsolete entries in the file, counted while checking the entries.
+    /// Used by the `obsolete-count` rule.
+    pub obsolete_entries: usize,
+    /// Whether the file is a template (`*.pot`): untranslated entries are checked
+    /// with empty translations.
+    pub template: bool,
     pub diagnostics: Vec<Diagnostic>,
     parser: Parser<'d>,
+    /// Cache of dictionaries shared by all files checked in the same run; without
+    /// cache, the dictionaries are loaded for this file only.
+    dict_cache: Option<&'d DictCache>,
 }
 
 impl<'d> Checker<'d> {
@@ -77,11 +120,40 @@ impl<'d> Checker<'d> {
         self
     }
 
+    /// Set the cache of dictionaries shared by all files checked in the same run.
+    pub const fn with_dict_cache(mut self, dict_cache: Option<&'d DictCache>) -> Self {
+        self.dict_cache = dict_cache;
+        self
+    }
+
+    /// Set whether the file being checked is a template (`*.pot`).
+    pub const fn with_template(mut self, template: bool) -> Self {
+        self.template = template;
+        self
+    }
+
     /// Get the language of the file being checked (e.g. `pt_BR`).
     pub fn language(&self) -> &str {
         self.parser.language()
     }
 
+    /// Return `true` if the file being checked starts with a UTF-8 byte-order mark (BOM).
+    pub const fn has_bom(&self) -> bool {
+        self.parser.has_bom()
+    }
+
+    /// Get the plural expression defined in the header of the file being checked
+    /// (e.g. `(n > 1)`), empty if not defined.
+    pub fn plural_expr(&self) -> &str {
+        self.parser.plural_expr()
+    }
+
+    /// Get the value of a field in the header of the file being checked
+    /// (the name is case-insensitive).
+    pub fn header_field(&self, name: &str) -> Option<&str> {
+        self.parser.header_field(name)
+    }
+
     /// Get the language code of the file being checked (e.g. `pt`).
     pub fn language_code(&self) -> &str {
         self.parser.language_code()
@@ -92,6 +164,16 @@ impl<'d> Checker<'d> {
         self.parser.country()
     }
 
+    /// Return `true` if the file being checked is encoded in UTF-8.
+    pub const fn is_utf8(&self) -> bool {
+        self.parser.is_utf8()
+    }
+
+    /// Return the charset declared in the header if it is not recognized.
+    pub fn unknown_charset(&self) -> Option<&str> {
+        self.parser.unknown_charset()
+    }
+
     /// Return the encoding name.
     pub fn encoding_name(&self) -> &'static str {
         self.parser.encoding_name()
@@ -146,20 +228,245 @@ impl<'d> Checker<'d> {
             diags.extend(rule.check_ctxt(self, entry, msgctxt));
         }
         if let (Some(msgid), Some(msgstr_0)) = (&entry.msgid, entry.msgstr.get(&0))
-            && (!msgstr_0.value.is_empty() || (untranslated_rule && rule_is_untranslated))
+            && (!msgstr_0.value.is_empty()
+                || self.template
+                || (untranslated_rule && rule_is_untranslated))
         {
             diags.extend(rule.check_msg(self, entry, msgid, msgstr_0));
         }
         if let Some(msgid_plural) = &entry.msgid_plural {
             for (_, msgstr_n) in entry.iter_plural_strs() {
-                if !msgstr_n.value.is_empty() || (untranslated_rule && rule_is_untranslated) {
+                if !msgstr_n.value.is_empty()
+                    || self.template
+                    || (untranslated_rule && rule_is_untranslated)
+                {
                     diags.extend(rule.check_msg(self, entry, msgid_plural, msgstr_n));
                 }
             }
         }
+        if let Some(msgctxt) = &entry.msgctxt {
+            for diag in &mut diags {
+                diag.msgctxt = Some(msgctxt.value.clone());
+            }
+        }
+        diags
+    }
+
+    /// Get the dictionary for a language, from the cache of dictionaries if set.
+    fn get_dict(&self, language: &str) -> Result<Arc<Dictionary>, Box<dyn std::error::Error>> {
+        let path_dicts = self.config.check.path_dicts.as_path();
+        let path_words = self.config.check.path_words.as_ref();
+        match self.dict_cache {
+            Some(dict_cache) => dict_cache.get(path_dicts, path_words, language),
+            None => dict::get_dict(path_dicts, path_words, language).map(Arc::new),
+        }
+    }
+
+    /// Load the dictionaries used by the spelling rules, according to the language
+    /// of the file (option `lang_str` or language found in header).
+    ///
+    /// A warning is reported only once if a dictionary can not be loaded.
+    fn load_dicts(&mut self, rules: &Rules, error_dict_id: &mut bool, error_dict_str: &mut bool) {
+        if (rules.spelling_ctxt_rule || rules.spelling_id_rule)
+            && (self.config.check.langs.is_empty()
+                || self.config.check.langs.contains(&self.config.check.lang_id))
+        {
+            self.dict_id = match self.get_dict(&self.config.check.lang_id) {
+                Ok(dict) => Some(dict),
+                Err(err) => {
+                    if !*error_dict_id {
+                        self.diagnostics.push(Diagnostic::new(
+                            &self.path,
+                            "spelling-ctxt-id",
+                            Severity::Warning,
+                            err.to_string(),
+                        ));
+                    }
+                    *error_dict_id = true;
+                    None
+                }
+            }
+        }
+        let language = self
+            .config
+            .check
+            .lang_str
+            .as_deref()
+            .unwrap_or_else(|| self.parser.language());
+        if (rules.spelling_str_rule && self.dict_str.is_none())
+            && (self.config.check.langs.is_empty()
+                || self.config.check.langs.iter().any(|s| s == language))
+        {
+            let dict = if language.is_empty() {
+                Err(
+                    "language not found in header (option --lang-str can be used), \
+                    spelling rule ignored"
+                        .into(),
+                )
+            } else {
+                self.get_dict(language)
+            };
+            self.dict_str = match dict {
+                Ok(dict) => Some(dict),
+                Err(err) => {
+                    if !*error_dict_str {
+                        self.diagnostics.push(Diagnostic::new(
+                            &self.path,
+                            "spelling-str",
+                            Severity::Warning,
+                            err.to_string(),
+                        ));
+                    }
+                    *error_dict_str = true;
+                    None
+                }
+            };
+        }
+    }
+
+    /// Load the glossary used by the `glossary` rule, according to the language code
+    /// of the file (found in header): file `<lang>.tsv` in `check.glossary_dir`.
+    ///
+    /// A warning is reported if the glossary can not be loaded.
+    fn load_glossary(&mut self, rules: &Rules) {
+        if !rules.glossary_rule {
+            return;
+        }
+        let Some(glossary_dir) = &self.config.check.glossary_dir else {
+            return;
+        };
+        let language = self.parser.language_code();
+        let message = if language.is_empty() {
+            "language not found in header, glossary rule ignored".to_string()
+        } else {
+            let path = glossary_dir.join(format!("{language}.tsv"));
+            match load_glossary(&path) {
+                Ok(terms) => {
+                    self.glossary = Some(terms);
+                    return;
+                }
+                Err(err) => format!(
+                    "glossary file not found (path: {}): {err}, glossary rule ignored",
+                    path.display()
+                ),
+            }
+        };
+        self.diagnostics.push(Diagnostic::new(
+            &self.path,
+            "glossary",
+            Severity::Warning,
+            message,
+        ));
+    }
+
+    /// Check an entry with an empty msgid and without context which is not the header
+    /// (not the first entry): only the rule `empty-msgid` checks it.
+    fn check_empty_msgid(&self, entry: &Entry, rules: &Rules) -> Vec<Diagnostic> {
+        rules
+            .enabled
+            .iter()
+            .filter(|r| r.name() == "empty-msgid")
+            .filter(|r| !entry.noqa && !entry.noqa_rules.iter().any(|n| n == r.name()))
+            .flat_map(|r| r.check_entry(self, entry))
+            .collect()
+    }
+
+    /// Check an entry that is skipped by most rules (untranslated or fuzzy entry) with
+    /// the rules matching `filter` only.
+    fn check_entry_with_rules(
+        &self,
+        entry: &Entry,
+        rules: &Rules,
+        filter: impl Fn(&Rule) -> bool,
+    ) -> Vec<Diagnostic> {
+        rules
+            .enabled
+            .iter()
+            .filter(|r| filter(r))
+            .filter(|r| !entry.noqa && !entry.noqa_rules.iter().any(|n| n == r.name()))
+            .flat_map(|r| self.check_entry(entry, r, rules.untranslated_rule))
+            .collect()
+    }
+
+    /// Check an entry of the body of the file (any entry except the header) with all
+    /// rules.
+    fn check_body_entry(&self, entry: &Entry, rules: &Rules) -> Vec<Diagnostic> {
+        if entry.is_header() && entry.msgctxt.is_none() {
+            return self.check_empty_msgid(entry, rules);
+        }
+        if !self.is_entry_selected(entry)
+            || (entry.noqa && !self.config.check.noqa && !rules.noqa_rule)
+            || (entry.obsolete && !self.config.check.obsolete && !rules.obsolete_rule)
+        {
+            return vec![];
+        }
+        if !entry.is_translated()
+            && !rules.untranslated_rule
+            && !self.template
+            && !self.config.check.only_untranslated
+        {
+            // Only the rules checking the source strings of untranslated entries.
+            return self.check_entry_with_rules(entry, rules, |r| r.checks_untranslated());
+        }
+        if entry.fuzzy && !self.config.check.fuzzy && !rules.fuzzy_rule {
+            // Only the rule `fuzzy-stale`.
+            if !rules.fuzzy_stale_rule {
+                return vec![];
+            }
+            return self.check_entry_with_rules(entry, rules, |r| r.name() == "fuzzy-stale");
+        }
+        let mut diags = vec![];
+        for rule in &rules.enabled {
+            if rule.name() != "noqa"
+                && (entry.noqa || entry.noqa_rules.iter().any(|r| r == rule.name()))
+            {
+                continue;
+            }
+            diags.extend(self.check_entry(entry, rule, rules.untranslated_rule));
+        }
         diags
     }
 
+    /// Whether the entry is selected by the options `only_untranslated` and `only_fuzzy`
+    /// (all entries are selected if none of them is set).
+    fn is_entry_selected(&self, entry: &Entry) -> bool {
+        let check = &self.config.check;
+        if !check.only_untranslated && !check.only_fuzzy {
+            return true;
+        }
+        (check.only_untranslated && !entry.is_translated()) || (check.only_fuzzy && entry.fuzzy)
+    }
+
+    /// Return the line number of the first entry with the same context and id as
+    /// `entry` (obsolete entries are ignored), and remember the line and byte range
+    /// of `entry` if this is the first one.
+    ///
+    /// Entries are indexed by the hash of their context and id, so that no string
+    /// is allocated; when the hash is found, the first entry is parsed again to
+    /// compare its context and id with `entry` (in case of hash collision, `entry`
+    /// is not reported).
+    fn find_duplicate(
+        &self,
+        entry: &Entry,
+        hasher: &RandomState,
+        entry_lines: &mut HashMap<u64, (usize, Range<usize>)>,
+    ) -> Option<usize> {
+        if entry.obsolete {
+            return None;
+        }
+        let msgid = entry.msgid.as_ref()?;
+        let msgctxt = entry.msgctxt.as_ref().map(|m| m.value.as_str());
+        let hash = hasher.hash_one((msgctxt, msgid.value.as_str()));
+        let Some((line, byte_range)) = entry_lines.get(&hash) else {
+            entry_lines.insert(hash, (entry.line_number, entry.byte_range.clone()));
+            return None;
+        };
+        let first = self.parser.parse_entry_at(byte_range.clone())?;
+        (first.msgctxt.as_ref().map(|m| m.value.as_str()) == msgctxt
+            && first.msgid.as_ref().map(|m| m.value.as_str()) == Some(msgid.value.as_str()))
+        .then_some(*line)
+    }
+
     /// Perform all checks on every entry of the PO file.
     ///
     /// This function calls the following function defined in the rule that implements
@@ -168,6 +475,10 @@ impl<'d> Checker<'d> {
     ///
     /// Then, for each entry, it calls the function [`check_entry`](crate::checker::Checker::check_entry)
     /// to check the entry with the given rule.
+    ///
+    /// The header is checked first; for large files (see [`PARALLEL_MIN_SIZE`]), the
+    /// entries of the body are then checked in parallel, and the diagnostics are
+    /// reported in the order of the entries.
     pub(crate) fn do_all_checks(&mut self, rules: &Rules) {
         // Load word lists for `force-trans` / `no-trans` rules if enabled. These
         // lists are independent of the PO file's header, so we load them up
@@ -180,63 +491,30 @@ impl<'d> Checker<'d> {
             self.no_trans_words =
                 self.load_rule_word_list("no-trans", self.config.check.no_trans_file.clone());
         }
+        self.punc_end_rule = rules.punc_end_rule;
         // Run rules for the entire file (e.g. check compilation of the file with msgfmt command).
         for rule in &rules.enabled {
             self.diagnostics.extend(rule.check_file(self));
         }
         let mut error_dict_id = false;
         let mut error_dict_str = false;
+        let hasher = RandomState::new();
+        let mut entry_lines: HashMap<u64, (usize, Range<usize>)> = HashMap::new();
+        let mut first_entry = true;
+        let mut body_entries: Option<Vec<Entry>> =
+            (self.parser.data_len() >= PARALLEL_MIN_SIZE).then(Vec::new);
         while let Some(entry) = self.parser.next() {
+            let header_allowed = std::mem::replace(&mut first_entry, false);
+            if entry.obsolete {
+                self.obsolete_entries += 1;
+            }
             if entry.is_header() {
-                if (rules.spelling_ctxt_rule || rules.spelling_id_rule)
-                    && (self.config.check.langs.is_empty()
-                        || self.config.check.langs.contains(&self.config.check.lang_id))
-                {
-                    self.dict_id = match dict::get_dict(
-                        self.config.check.path_dicts.as_path(),
-                        self.config.check.path_words.as_ref(),
-                        &self.config.check.lang_id,
-                    ) {
-                        Ok(dict) => Some(dict),
-                        Err(err) => {
-                            if !error_dict_id {
-                                self.diagnostics.push(Diagnostic::new(
-                                    &self.path,
-                                    "spelling-ctxt-id",
-                                    Severity::Warning,
-                                    err.to_string(),
-                                ));
-                            }
-                            error_dict_id = true;
-                            None
-                        }
-                    }
-                }
-                let language = self.parser.language();
-                if (rules.spelling_str_rule && self.dict_str.is_none())
-                    && (self.config.check.langs.is_empty()
-                        || self.config.check.langs.iter().any(|s| s == language))
-                {
-                    self.dict_str = match dict::get_dict(
-                        self.config.check.path_dicts.as_path(),
-                        self.config.check.path_words.as_ref(),
-                        language,
-                    ) {
-                        Ok(dict) => Some(dict),
-                        Err(err) => {
-                            if !error_dict_str {
-                                self.diagnostics.push(Diagnostic::new(
-                                    &self.path,
-                                    "spelling-str",
-                                    Severity::Warning,
-                                    err.to_string(),
-                                ));
-                            }
-                            error_dict_str = true;
-                            None
-                        }
-                    };
+                if !header_allowed || entry.msgctxt.is_some() || entry.obsolete {
+                    self.check_or_push_body_entry(entry, rules, body_entries.as_mut());
+                    continue;
                 }
+                self.load_dicts(rules, &mut error_dict_id, &mut error_dict_str);
+                self.load_glossary(rules);
                 if let Some(msgstr_0) = entry.msgstr.get(&0) {
                     for rule in &rules.enabled {
                         if rule.name() != "noqa"
@@ -250,22 +528,44 @@ impl<'d> Checker<'d> {
                 }
                 continue;
             }
-            if (!entry.is_translated() && !rules.untranslated_rule)
-                || (entry.fuzzy && !self.config.check.fuzzy && !rules.fuzzy_rule)
-                || (entry.noqa && !self.config.check.noqa && !rules.noqa_rule)
-                || (entry.obsolete && !self.config.check.obsolete && !rules.obsolete_rule)
-            {
-                continue;
+            if header_allowed {
+                // No header: the language of translations can still be set by option.
+                self.load_dicts(rules, &mut error_dict_id, &mut error_dict_str);
+                self.load_glossary(rules);
             }
-            for rule in &rules.enabled {
-                if rule.name() != "noqa"
-                    && (entry.noqa || entry.noqa_rules.iter().any(|r| r == rule.name()))
-                {
-                    continue;
-                }
-                self.diagnostics
-                    .extend(self.check_entry(&entry, rule, rules.untranslated_rule));
+            if rules.duplicate_rule
+                && let Some(line) = self.find_duplicate(&entry, &hasher, &mut entry_lines)
+            {
+                self.duplicate_lines.insert(entry.line_number, line);
             }
+            self.check_or_push_body_entry(entry, rules, body_entries.as_mut());
+        }
+        if let Some(entries) = body_entries {
+            let diags: Vec<Diagnostic> = entries
+                .par_iter()
+                .flat_map_iter(|entry| self.check_body_entry(entry, rules))
+                .collect();
+            self.diagnostics.extend(diags);
+        }
+        // Run rules for the entire file, once all entries are checked (e.g. count of entries).
+        for rule in &rules.enabled {
+            self.diagnostics.extend(rule.check_file_end(self));
+        }
+    }
+
+    /// Check an entry of the body of the file, or push it to `body_entries` (if set)
+    /// to check it later, in parallel with the other entries.
+    fn check_or_push_body_entry(
+        &mut self,
+        entry: Entry,
+        rules: &Rules,
+        body_entries: Option<&mut Vec<Entry>>,
+    ) {
+        if let Some(entries) = body_entries {
+            entries.push(entry);
+        } else {
+            let diags = self.check_body_entry(&entry, rules);
+            self.diagnostics.extend(diags);
         }
     }
 }
@@ -357,7 +657,8 @@ fn apply_fixes_to_data(
 }
 
 /// Rewrite the file on disk with the fixed bytes, then re-run the rules on the
-/// new contents so the returned diagnostics reflect the post-fix state.
+/// new contents (with `checker`, created on `new_data`) so the returned diagnostics
+/// reflect the post-fix state.
 ///
 /// Returns a `CheckFileResult` carrying either the re-check result or a single
 /// `fix-write-error` diagnostic if writing the file fails.
@@ -365,7 +666,7 @@ fn rewrite_and_recheck(
     path: &PathBuf,
     new_data: &[u8],
     fixes_applied: usize,
-    config: Config,
+    mut checker: Checker,
     rules: Rules,
     existing_diagnostics: Vec<Diagnostic>,
 ) -> CheckFileResult {
@@ -379,13 +680,12 @@ fn rewrite_and_recheck(
         ));
         return CheckFileResult {
             path: path.clone(),
-            config,
+            config: checker.config,
             rules,
             diagnostics,
             fixes_applied,
         };
     }
-    let mut checker = Checker::new(new_data).with_path(path).with_config(config);
     checker.do_all_checks(&rules);
     CheckFileResult {
         path: path.clone(),
@@ -396,8 +696,23 @@ fn rewrite_and_recheck(
     }
 }
 
-/// Check a single PO file and return the list of diagnostics found.
-fn check_file(path: &PathBuf, args: &args::CheckArgs) -> CheckFileResult {
+/// Return `true` if the file must be checked as a template: with option `--pot`
+/// or if the file has the extension `.pot`.
+fn is_template(path: &Path, args: &args::CheckArgs) -> bool {
+    args.pot || path.extension().is_some_and(|ext| ext == "pot")
+}
+
+/// Load the config, select the rules and read the content of a PO file.
+///
+/// For a template file (see [`is_template`]), only the rules checking the source
+/// strings are selected.
+///
+/// Returns a single error diagnostic if the config is invalid, a rule is unknown
+/// or the file can not be read.
+fn load_file(
+    path: &PathBuf,
+    args: &args::CheckArgs,
+) -> Result<(Config, Rules, Vec<u8>), Box<Diagnostic>> {
     let path_config = if args.no_config {
         None
     } else {
@@ -412,66 +727,67 @@ fn check_file(path: &PathBuf, args: &args::CheckArgs) -> CheckFileResult {
     let config = match Config::new(path_config.as_ref()) {
         Ok(cfg) => cfg.with_args_check(args),
         Err(err) => {
-            return CheckFileResult {
-                path: path.clone(),
-                diagnostics: vec![Diagnostic::new(
-                    path.as_path(),
-                    "config-error",
-                    Severity::Error,
-                    format!(
-                        "invalid config file (path: {}): {err}",
-                        path_config.unwrap_or_default().display()
-                    ),
-                )],
-                ..Default::default()
-            };
-        }
-    };
-    let rules = match get_selected_rules(&config) {
-        Ok(selected_rules) => selected_rules,
-        Err(err) => {
-            return CheckFileResult {
-                path: path.clone(),
-                diagnostics: vec![Diagnostic::new(
-                    path.as_path(),
-                    "rules-error",
-                    Severity::Error,
-                    err.to_string(),
-                )],
-                ..Default::default()
-            };
+            return Err(Box::new(Diagnostic::new(
+                path.as_path(),
+                "config-error",
+                Severity::Error,
+                format!(
+                    "invalid config file (path: {}): {err}",
+                    path_config.unwrap_or_default().display()
+                ),
+            )));
         }
     };
+    let mut rules = get_selected_rules(&config).map_err(|err| {
+        Box::new(Diagnostic::new(
+            path.as_path(),
+            "rules-error",
+            Severity::Error,
+            err.to_string(),
+        ))
+    })?;
+    if is_template(path, args) {
+        rules = rules.source_only();
+    }
     let mut data: Vec<u8> = Vec::new();
-    match File::open(path) {
-        Ok(mut file) => {
-            if let Err(err) = file.read_to_end(&mut data) {
-                return CheckFileResult {
-                    path: path.clone(),
-                    diagnostics: vec![Diagnostic::new(
-                        path.as_path(),
-                        "read-error",
-                        Severity::Error,
-                        err.to_string(),
-                    )],
-                    ..Default::default()
-                };
-            }
-        }
-        Err(err) => {
+    File::open(path)
+        .and_then(|mut file| file.read_to_end(&mut data))
+        .map_err(|err| {
+            Box::new(Diagnostic::new(
+                path.as_path(),
+                "read-error",
+                Severity::Error,
+                err.to_string(),
+            ))
+        })?;
+    Ok((config, rules, data))
+}
+
+/// Check a single PO file and return the list of diagnostics found.
+///
+/// The dictionaries are loaded from `dict_cache` if set (shared by all files
+/// checked in the same run).
+fn check_file(
+    path: &PathBuf,
+    args: &args::CheckArgs,
+    dict_cache: Option<&DictCache>,
+) -> CheckFileResult {
+    let (config, rules, data) = match load_file(path, args) {
+        Ok(loaded) => loaded,
+        Err(diag) => {
             return CheckFileResult {
                 path: path.clone(),
-                diagnostics: vec![Diagnostic::new(
-                    path.as_path(),
-                    "read-error",
-                    Severity::Error,
-                    err.to_string(),
-                )],
+                diagnostics: vec![*diag],
                 ..Default::default()
             };
         }
-    }
-    let mut checker = Checker::new(&data).with_path(path).with_config(config);
+    };
+    let template = is_template(path, args);
+    let mut checker = Checker::new(&data)
+        .with_path(path)
+        .with_config(config)
+        .with_template(template)
+        .with_dict_cache(dict_cache);
     checker.do_all_checks(&rules);
     if args.fix {
         if let Some((new_data, fixes_applied)) = apply_fixes_to_data(
@@ -483,7 +799,19 @@ fn check_file(path: &PathBuf, args: &args::CheckArgs) -> CheckFileResult {
             let config = std::mem::take(&mut checker.config);
             let diagnostics = std::mem::take(&mut checker.diagnostics);
             drop(checker);
-            return rewrite_and_recheck(path, &new_data, fixes_applied, config, rules, diagnostics);
+            let recheck = Checker::new(&new_data)
+                .with_path(path)
+                .with_config(config)
+                .with_template(template)
+                .with_dict_cache(dict_cache);
+            return rewrite_and_recheck(
+                path,
+                &new_data,
+                fixes_applied,
+                recheck,
+                rules,
+                diagnostics,
+            );
         }
     }
     CheckFileResult {
@@ -495,15 +823,172 @@ fn check_file(path: &PathBuf, args: &args::CheckArgs) -> CheckFileResult {
     }
 }
 
-/// Check and display result for all PO files.
-pub fn run_check(args: &args::CheckArgs) -> i32 {
+/// Check the given PO files and display result.
+///
+/// The paths displayed are relative to the directory given with `--relative-to`
+/// (current directory by default).
+///
+/// With `--diff`, only the diagnostics on lines changed in the diff are kept.
+pub fn check_files(paths: &[PathBuf], args: &args::CheckArgs) -> i32 {
     let start = std::time::Instant::now();
-    let result: Vec<CheckFileResult> = find_po_files(&args.files)
-        .par_iter()
-        .map(|path| check_file(path, args))
-        .collect();
+    let base = args
+        .relative_to
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("."));
+    let changed_lines = match args
+        .diff
+        .as_deref()
+        .map(ChangedLines::from_path)
+        .transpose()
+    {
+        Ok(changed_lines) => changed_lines,
+        Err(err) => {
+            eprintln!("{}: could not read diff: {err}", "Error".red());
+            return 1;
+        }
+    };
+    let progress = Progress::new(
+        paths.len(),
+        args.progress && args.output == args::CheckOutputFormat::Human && !args.quiet,
+    );
+    // Dictionaries are loaded once per run, the files with words may have changed
+    // since the previous run (with `--watch`).
+    let dict_cache = DictCache::default();
+    let result = check_paths(
+        paths,
+        args,
+        &base,
+        changed_lines.as_ref(),
+        &progress,
+        &dict_cache,
+    );
+    progress.finish();
     let elapsed = start.elapsed();
-    display_result(&result, args, &elapsed)
+    display_result(&mut std::io::stdout(), &result, args, &elapsed)
+}
+
+/// Check the PO files in parallel (using the global thread pool, see `--jobs`),
+/// with paths relative to `base` and only the diagnostics on changed lines if
+/// `changed_lines` is set; `progress` is incremented after each file and the
+/// dictionaries are shared by all files with `dict_cache`.
+fn check_paths(
+    paths: &[PathBuf],
+    args: &args::CheckArgs,
+    base: &Path,
+    changed_lines: Option<&ChangedLines>,
+    progress: &Progress,
+    dict_cache: &DictCache,
+) -> Vec<CheckFileResult> {
+    paths
+        .par_iter()
+        .map(|path| {
+            let mut file_result = check_file(path, args, Some(dict_cache)).relative_to(base);
+            if let Some(changed_lines) = changed_lines {
+                file_result
+                    .diagnostics
+                    .retain(|diag| changed_lines.contains_diagnostic(diag));
+            }
+            progress.inc();
+            file_result
+        })
+        .collect()
+}
+
+/// Check and display result for all PO files.
+///
+/// With `--watch`, the files are watched after the first check, and checked again
+/// when they change.
+pub fn run_check(args: &args::CheckArgs) -> i32 {
+    if args.watch && args.output != args::CheckOutputFormat::Human {
+        eprintln!(
+            "{}: option --watch can be used only with human output",
+            "Error".red()
+        );
+        return 1;
+    }
+    if args.files_from.as_deref() == Some(Path::new("-"))
+        && args.diff.as_deref() == Some(Path::new("-"))
+    {
+        eprintln!(
+            "{}: options --files-from and --diff can not both read standard input",
+            "Error".red()
+        );
+        return 1;
+    }
+    let Some(paths) = get_po_files(
+        &args.files,
+        args.files_from.as_deref(),
+        &args.exclude,
+        !args.fix,
+    ) else {
+        return 1;
+    };
+    let paths: Vec<PathBuf> = paths.into_iter().collect();
+    let rc = check_files(&paths, args);
+    if args.watch {
+        return watch_files(&paths, args);
+    }
+    rc
+}
+
+/// Apply the fixes to a single PO file and return the fixed content, without
+/// writing the file (used by `fix --dry-run`).
+///
+/// Returns `None` if there is nothing to fix in the file.
+fn fix_file_dry_run(
+    path: &PathBuf,
+    args: &args::CheckArgs,
+) -> Result<Option<Vec<u8>>, Box<Diagnostic>> {
+    let (config, rules, data) = load_file(path, args)?;
+    let mut checker = Checker::new(&data)
+        .with_path(path)
+        .with_config(config)
+        .with_template(is_template(path, args));
+    checker.do_all_checks(&rules);
+    let fixed = apply_fixes_to_data(
+        &data,
+        &checker.diagnostics,
+        checker.config.check.width,
+        checker.config.check.unsafe_fixes,
+    );
+    drop(checker);
+    Ok(fixed
+        .map(|(new_data, _)| new_data)
+        .filter(|new_data| *new_data != data))
+}
+
+/// Fix all PO files and display result.
+///
+/// With `--dry-run`, files are not written: the fixed content of each changed file
+/// is displayed on standard output, after a header with the file name
+/// (`==> po/fr.po <==`).
+pub fn run_fix(args: &args::FixArgs) -> i32 {
+    let check_args = args.to_check_args();
+    if args.dry_run {
+        let mut rc = 0;
+        let mut first = true;
+        let mut stdout = std::io::stdout().lock();
+        for path in find_po_files(&args.files, &args.exclude, false) {
+            match fix_file_dry_run(&path, &check_args) {
+                Ok(None) => {}
+                Ok(Some(data)) => {
+                    let separator = if first { "" } else { "\n" };
+                    first = false;
+                    if writeln!(stdout, "{separator}==> {} <==", path.display()).is_err()
+                        || stdout.write_all(&data).is_err()
+                    {
+                        return 1;
+                    }
+                }
+                Err(diag) => {
+                    eprintln!("{}: {}", path.display(), diag.message);
+                    rc = 1;
+                }
+            }
+        }
+        return rc;
+    }
+    run_check(&check_args)
 }
 
 /// Check in-memory PO `data` and return the diagnostics found, without reading
@@ -545,12 +1030,17 @@ mod tests {
     fn default_check_args() -> args::CheckArgs {
         args::CheckArgs {
             files: vec![],
+            exclude: vec![],
+            files_from: None,
             show_settings: false,
             config: None,
             no_config: false,
             fuzzy: false,
             noqa: false,
             obsolete: false,
+            only_untranslated: false,
+            only_fuzzy: false,
+            pot: false,
             select: None,
             ignore: None,
             path_msgfmt: None,
@@ -558,10 +1048,23 @@ mod tests {
             path_words: None,
             force_trans_file: None,
             no_trans_file: None,
+            glossary_dir: None,
             lang_id: None,
+            lang_str: None,
             langs: None,
+            spell_suggest: false,
+            spell_min_length: None,
+            spell_split_identifiers: false,
+            words_keep_underscore: false,
             short_factor: None,
             long_factor: None,
+            length_ratio_min: None,
+            length_ratio_max: None,
+            unchanged_allow: None,
+            unchanged_min_words: None,
+            context_max_words: None,
+            number_locales: None,
+            max_obsolete: None,
             severity: vec![],
             punc_ignore_ellipsis: false,
             accelerator: None,
@@ -569,11 +1072,20 @@ mod tests {
             sort: args::CheckSort::default(),
             rule_stats: false,
             file_stats: false,
+            relative_to: None,
+            hyperlinks: false,
+            highlight_style: crate::diagnostic::HighlightStyle::default(),
+            diff: None,
             output: args::CheckOutputFormat::default(),
+            json_summary: false,
             quiet: true,
+            max_problems: None,
+            progress: false,
+            error_on_warning: false,
             fix: false,
             unsafe_fixes: false,
             width: None,
+            watch: false,
         }
     }
 
@@ -624,6 +1136,7 @@ msgstr \"olá\"
         assert_eq!(checker.country(), "");
         // No `Content-Type` header parsed yet → encoding defaults to UTF-8.
         assert_eq!(checker.encoding_name(), "UTF-8");
+        assert!(checker.is_utf8());
         assert_eq!(checker.nplurals(), 0);
     }
 
@@ -640,6 +1153,47 @@ msgstr \"olá\"
         assert!(checker.diagnostics.is_empty());
     }
 
+    #[test]
+    fn test_do_all_checks_reports_decoding_error() {
+        let content = "msgid \"\"
+msgstr \"Content-Type: text/plain; charset=UTF-8\\n\"
+
+msgid \"tested\"
+msgstr \"testé\"
+";
+        // ISO-8859-15 bytes in a file declared as UTF-8.
+        let content_iso = encoding_rs::ISO_8859_15.encode(content).0;
+        let mut checker = Checker::new(content_iso.as_ref());
+        checker.do_all_checks(&Rules::new(vec![Box::new(
+            crate::rules::encoding::EncodingRule {},
+        )]));
+        assert_eq!(checker.encoding_name(), "UTF-8");
+        assert_eq!(checker.diagnostics.len(), 1);
+        let diag = &checker.diagnostics[0];
+        assert_eq!(diag.rule, "encoding");
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(diag.message, "decoding error (not valid UTF-8)");
+        assert_eq!(diag.lines[0].line_number, 4);
+    }
+
+    #[test]
+    fn test_charset_extracted_from_header_after_parsing() {
+        let mut checker = Checker::new(
+            b"msgid \"\"\nmsgstr \"Content-Type: text/plain; charset=ISO-8859-15\\n\"\n",
+        );
+        checker.do_all_checks(&Rules::new(vec![Box::new(
+            crate::rules::charset::CharsetRule {},
+        )]));
+        assert!(!checker.is_utf8());
+        assert_eq!(checker.encoding_name(), "ISO-8859-15");
+        assert_eq!(checker.diagnostics.len(), 1);
+        assert_eq!(checker.diagnostics[0].rule, "charset");
+        assert_eq!(
+            checker.diagnostics[0].message,
+            "file charset is ISO-8859-15, consider converting to UTF-8"
+        );
+    }
+
     #[test]
     fn test_do_all_checks_on_empty_input_does_nothing() {
         let mut checker = Checker::new(b"");
@@ -647,18 +1201,161 @@ msgstr \"olá\"
         assert!(checker.diagnostics.is_empty());
     }
 
+    #[test]
+    fn test_do_all_checks_large_file_in_parallel() {
+        use std::fmt::Write as _;
+
+        // Synthetic large file: one missing newline every 10 entries, one duplicate
+        // every 1000 entries and a misplaced empty msgid.
+        let mut content = String::from(
+            "msgid \"\"\nmsgstr \"Language: fr\\nPlural-Forms: nplurals=2; plural=(n > 1);\\n\"\n",
+        );
+        let count = 30_000;
+        for i in 0..count {
+            let msgid = if i % 1000 == 999 { 0 } else { i };
+            let newline = if i % 10 == 5 { "" } else { "\\n" };
+            let _ = write!(
+                content,
+                "\nmsgid \"this is the entry number {msgid}\\n\"\nmsgstr \"ceci est l'entrée numéro {msgid}{newline}\"\n"
+            );
+            if i == count / 2 {
+                content.push_str("\nmsgid \"\"\nmsgstr \"test\"\n");
+            }
+        }
+        assert!(content.len() >= PARALLEL_MIN_SIZE);
+        let mut config = Config::default();
+        config.check.select = vec![
+            "duplicate".to_string(),
+            "empty-msgid".to_string(),
+            "newlines".to_string(),
+        ];
+        let rules = get_selected_rules(&config).unwrap();
+        let mut checker = Checker::new(content.as_bytes()).with_config(config);
+        checker.do_all_checks(&rules);
+        assert_eq!(checker.nplurals(), 2);
+        let count_rule = |name: &str| {
+            checker
+                .diagnostics
+                .iter()
+                .filter(|d| d.rule == name)
+                .count()
+        };
+        assert_eq!(count_rule("newlines"), 2 * count / 10);
+        assert_eq!(count_rule("duplicate"), count / 1000);
+        assert_eq!(count_rule("empty-msgid"), 1);
+        // Diagnostics are reported in the order of the entries.
+        let lines: Vec<usize> = checker
+            .diagnostics
+            .iter()
+            .map(|d| d.lines[0].line_number)
+            .collect();
+        assert!(lines.is_sorted());
+    }
+
+    #[test]
+    fn test_check_paths_jobs() {
+        let tmp = tmp_dir("jobs");
+        let paths: Vec<PathBuf> = (0..8)
+            .map(|idx| write_po(tmp.path(), &format!("fr{idx}.po"), PO_PT_BR))
+            .collect();
+        let mut args = default_check_args();
+        args.no_config = true;
+        let check_with_jobs = |jobs: usize| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(jobs)
+                .build()
+                .expect("build thread pool");
+            let progress = Progress::new(paths.len(), false);
+            pool.install(|| {
+                check_paths(
+                    &paths,
+                    &args,
+                    tmp.path(),
+                    None,
+                    &progress,
+                    &DictCache::default(),
+                )
+            })
+            .into_iter()
+            .map(|result| {
+                let diags: Vec<String> =
+                    result.diagnostics.iter().map(ToString::to_string).collect();
+                (result.path, diags)
+            })
+            .collect::<Vec<_>>()
+        };
+        let result = check_with_jobs(1);
+        assert_eq!(result.len(), 8);
+        assert!(result.iter().all(|(_, diags)| !diags.is_empty()));
+        assert_eq!(result, check_with_jobs(4));
+    }
+
+    #[test]
+    fn test_check_files_progress() {
+        use clap::Parser as _;
+        let cli = args::Cli::try_parse_from(["poexam", "check", "--progress", "fr.po"])
+            .expect("parse arguments");
+        let args::Command::Check(cli_args) = cli.command else {
+            panic!("expected check command");
+        };
+        assert!(cli_args.progress);
+        let tmp = tmp_dir("progress");
+        let paths = vec![write_po(tmp.path(), "fr.po", PO_PT_BR)];
+        let mut args = default_check_args();
+        args.no_config = true;
+        let rc = check_files(&paths, &args);
+        assert_eq!(rc, 1);
+        args.progress = true;
+        assert_eq!(check_files(&paths, &args), rc);
+    }
+
+    #[test]
+    fn test_run_check_files_from_conflicts() {
+        use clap::Parser as _;
+        assert!(
+            args::Cli::try_parse_from(["poexam", "check", "--files-from", "list.txt", "fr.po"])
+                .is_err()
+        );
+        let mut args = default_check_args();
+        args.files_from = Some(PathBuf::from("-"));
+        args.diff = Some(PathBuf::from("-"));
+        assert_eq!(run_check(&args), 1);
+    }
+
     #[test]
     fn test_check_file_missing_path_returns_read_error() {
         let missing = PathBuf::from("/this/path/should/not/exist/file.po");
         let mut args = default_check_args();
         args.no_config = true;
-        let result = check_file(&missing, &args);
+        let result = check_file(&missing, &args, None);
         assert_eq!(result.path, missing);
         assert_eq!(result.diagnostics.len(), 1);
         assert_eq!(result.diagnostics[0].rule, "read-error");
         assert_eq!(result.diagnostics[0].severity, Severity::Error);
     }
 
+    #[test]
+    fn test_check_file_result_relative_to() {
+        let tmp = tmp_dir("relative-to");
+        let po_path = write_po(tmp.path(), "fr.po", PO_PT_BR);
+        let mut args = default_check_args();
+        args.no_config = true;
+        // Path inside the base directory.
+        let result = check_file(&po_path, &args, None).relative_to(tmp.path());
+        assert_eq!(result.path, PathBuf::from("fr.po"));
+        assert!(!result.diagnostics.is_empty());
+        assert!(
+            result
+                .diagnostics
+                .iter()
+                .all(|diag| diag.path == Path::new("fr.po"))
+        );
+        // Path outside the base directory: absolute path.
+        let result = check_file(&po_path, &args, None).relative_to(&tmp.path().join("sub"));
+        assert_eq!(result.path, po_path);
+        assert!(result.diagnostics.iter().all(|diag| diag.path == po_path));
+    }
+
     #[test]
     fn test_check_file_invalid_config_returns_config_error() {
         let tmp = tmp_dir("bad-config");
@@ -668,7 +1365,7 @@ msgstr \"olá\"
 
         let mut args = default_check_args();
         args.config = Some(cfg_path);
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
         assert_eq!(result.diagnostics.len(), 1);
         assert_eq!(result.diagnostics[0].rule, "config-error");
         assert_eq!(result.diagnostics[0].severity, Severity::Error);
@@ -682,7 +1379,7 @@ msgstr \"olá\"
         let mut args = default_check_args();
         args.no_config = true;
         args.select = Some("does-not-exist-rule".to_string());
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
         assert_eq!(result.diagnostics.len(), 1);
         assert_eq!(result.diagnostics[0].rule, "rules-error");
         assert_eq!(result.diagnostics[0].severity, Severity::Error);
@@ -697,7 +1394,7 @@ msgstr \"olá\"
         args.no_config = true;
         // Pick a non-default rule that won't fire on a non-fuzzy, non-obsolete entry.
         args.select = Some("fuzzy".to_string());
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
         assert_eq!(result.path, po_path);
         assert!(
             result.diagnostics.is_empty(),
@@ -706,6 +1403,60 @@ msgstr \"olá\"
         );
     }
 
+    #[test]
+    fn test_check_file_fuzzy_stale() {
+        // Fuzzy entries are checked only by the rule "fuzzy-stale", other rules still
+        // skip them.
+        let tmp = tmp_dir("fuzzy-stale");
+        let content = "msgid \"\"\nmsgstr \"\"\n\n\
+            #, fuzzy\n#| msgid \"test\"\nmsgid \"test\"\nmsgstr \"test \"\n";
+        let po_path = write_po(tmp.path(), "fr.po", content);
+        let mut args = default_check_args();
+        args.no_config = true;
+        args.select = Some("fuzzy-stale,whitespace-end".to_string());
+        let rules: Vec<&str> = check_file(&po_path, &args, None)
+            .diagnostics
+            .iter()
+            .map(|d| d.rule)
+            .collect();
+        assert_eq!(rules, vec!["fuzzy-stale"]);
+    }
+
+    #[test]
+    fn test_check_file_template() {
+        // In a template file, translation rules like "untranslated" are skipped and
+        // source rules like "spelling-id" check the untranslated entries.
+        let tmp = tmp_dir("template");
+        let content = "msgid \"\"\nmsgstr \"\"\n\nmsgid \"this is a tyypo\"\nmsgstr \"\"\n";
+        let pot_path = write_po(tmp.path(), "messages.pot", content);
+        let po_path = write_po(tmp.path(), "fr.po", content);
+        let mut args = default_check_args();
+        args.no_config = true;
+        args.select = Some("untranslated,spelling-id".to_string());
+        args.path_dicts = Some(
+            [env!("CARGO_MANIFEST_DIR"), "resources", "test"]
+                .iter()
+                .collect(),
+        );
+        let rules = |result: &CheckFileResult| -> Vec<&str> {
+            result.diagnostics.iter().map(|d| d.rule).collect()
+        };
+        assert_eq!(
+            rules(&check_file(&pot_path, &args, None)),
+            vec!["spelling-id"]
+        );
+        assert_eq!(
+            rules(&check_file(&po_path, &args, None)),
+            vec!["untranslated"]
+        );
+        // Option `--pot`: all files are checked as templates.
+        args.pot = true;
+        assert_eq!(
+            rules(&check_file(&po_path, &args, None)),
+            vec!["spelling-id"]
+        );
+    }
+
     #[test]
     fn test_check_file_uses_args_config_when_provided() {
         // A `--config` path that doesn't exist must surface as a config error
@@ -714,11 +1465,47 @@ msgstr \"olá\"
         let po_path = write_po(tmp.path(), "fr.po", PO_PT_BR);
         let mut args = default_check_args();
         args.config = Some(PathBuf::from("/no/such/poexam.toml"));
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
         assert_eq!(result.diagnostics.len(), 1);
         assert_eq!(result.diagnostics[0].rule, "config-error");
     }
 
+    #[test]
+    fn test_check_file_discovers_config_in_ancestor_dir() {
+        // A `.poexam.toml` at the root of the tree applies to PO files in
+        // subdirectories; command-line arguments take precedence over it, and
+        // `--no-config` disables it.
+        let tmp = tmp_dir("discover-config");
+        std::fs::write(
+            tmp.path().join(".poexam.toml"),
+            "[check]\nselect = [\"whitespace-start\", \"whitespace-end\"]\n\
+             ignore = [\"whitespace-start\"]\n\n[severity]\nwhitespace-end = \"error\"\n",
+        )
+        .expect("write config");
+        let sub_dir = tmp.path().join("po").join("fr");
+        std::fs::create_dir_all(&sub_dir).expect("create sub dir");
+        let po_path = write_po(&sub_dir, "fr.po", "msgid \" hello \"\nmsgstr \"bonjour\"\n");
+
+        let args = default_check_args();
+        let result = check_file(&po_path, &args, None);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].rule, "whitespace-end");
+        assert_eq!(result.diagnostics[0].severity, Severity::Error);
+
+        let mut args = default_check_args();
+        args.ignore = Some("whitespace-end".to_string());
+        let result = check_file(&po_path, &args, None);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].rule, "whitespace-start");
+
+        let mut args = default_check_args();
+        args.no_config = true;
+        args.select = Some("whitespace-end".to_string());
+        let result = check_file(&po_path, &args, None);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].severity, Severity::Info);
+    }
+
     #[test]
     fn test_run_check_clean_file_returns_zero() {
         let tmp = tmp_dir("run-clean");
@@ -767,7 +1554,7 @@ msgstr \"monde\"
         args.no_config = true;
         args.select = Some("whitespace-start,whitespace-end".to_string());
         args.fix = true;
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
 
         // Re-checking the rewritten file must report zero whitespace diagnostics.
         let whitespace_diags = result
@@ -797,7 +1584,7 @@ msgstr \"monde\"
         args.no_config = true;
         args.select = Some("whitespace-start,whitespace-end".to_string());
         args.fix = true;
-        let _ = check_file(&po_path, &args);
+        let _ = check_file(&po_path, &args, None);
 
         let after = std::fs::read(&po_path).expect("read after");
         assert_eq!(
@@ -806,6 +1593,76 @@ msgstr \"monde\"
         );
     }
 
+    fn default_fix_args() -> args::FixArgs {
+        args::FixArgs {
+            files: vec![],
+            exclude: vec![],
+            config: None,
+            no_config: true,
+            fuzzy: false,
+            obsolete: false,
+            dry_run: false,
+            width: None,
+        }
+    }
+
+    #[test]
+    fn test_fix_command_fixes_only_whitespace_rules() {
+        let tmp = tmp_dir("fix-command");
+        let content = format!("{PO_WHITESPACE_ISSUES}\nmsgid \"a b\"\nmsgstr \"x  y\"\n");
+        let po_path = write_po(tmp.path(), "fr.po", &content);
+
+        let mut check_args = default_fix_args().to_check_args();
+        check_args.quiet = true;
+        let result = check_file(&po_path, &check_args, None);
+        assert!(result.diagnostics.is_empty());
+        assert_eq!(result.fixes_applied, 2);
+
+        let fixed = std::fs::read_to_string(&po_path).expect("read fixed file");
+        assert!(fixed.contains("msgstr \"bonjour \""));
+        assert!(fixed.contains("msgstr \" monde\""));
+        // Double spaces are not fixed by the `fix` command.
+        assert!(fixed.contains("msgstr \"x  y\""));
+    }
+
+    #[test]
+    fn test_fix_command_dry_run_does_not_write_file() {
+        let tmp = tmp_dir("fix-dry-run");
+        let po_path = write_po(tmp.path(), "fr.po", PO_WHITESPACE_ISSUES);
+
+        let mut args = default_fix_args();
+        args.dry_run = true;
+        let fixed = fix_file_dry_run(&po_path, &args.to_check_args())
+            .expect("fixed content")
+            .expect("changed content");
+        let fixed = String::from_utf8(fixed).expect("UTF-8 content");
+        assert!(fixed.contains("msgstr \"bonjour \""));
+        assert!(fixed.contains("msgstr \" monde\""));
+
+        let after = std::fs::read_to_string(&po_path).expect("read after");
+        assert_eq!(after, PO_WHITESPACE_ISSUES);
+    }
+
+    #[test]
+    fn test_fix_command_dry_run_unchanged_file() {
+        let tmp = tmp_dir("fix-dry-run-unchanged");
+        let po_path = write_po(tmp.path(), "fr.po", PO_PT_BR);
+        let fixed =
+            fix_file_dry_run(&po_path, &default_fix_args().to_check_args()).expect("fixed content");
+        assert!(fixed.is_none());
+    }
+
+    #[test]
+    fn test_fix_command_dry_run_read_error() {
+        let tmp = tmp_dir("fix-dry-run-error");
+        let diag = fix_file_dry_run(
+            &tmp.path().join("missing.po"),
+            &default_fix_args().to_check_args(),
+        )
+        .expect_err("missing file");
+        assert_eq!(diag.rule, "read-error");
+    }
+
     /// PO content carrying one safe fix (a leading-whitespace mismatch, fixed by
     /// `whitespace-start`) and one unsafe fix (a differing function name, fixed
     /// by `functions` via positional replacement).
@@ -829,7 +1686,7 @@ msgstr \"Appelez bar()\"
         args.no_config = true;
         args.select = Some("whitespace-start,functions".to_string());
         args.fix = true;
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
 
         let fixed = std::fs::read_to_string(&po_path).expect("read fixed file");
         // Safe fix applied: the leading space is mirrored into the translation.
@@ -853,7 +1710,7 @@ msgstr \"Appelez bar()\"
         args.select = Some("whitespace-start,functions".to_string());
         args.fix = true;
         args.unsafe_fixes = true;
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
 
         let fixed = std::fs::read_to_string(&po_path).expect("read fixed file");
         // Both the safe and the unsafe fix are applied.
@@ -919,7 +1776,7 @@ msgstr \"bonjour\"
         args.select = Some("obsolete".to_string());
         args.obsolete = true;
         args.fix = true;
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
 
         let remaining = result
             .diagnostics
@@ -966,7 +1823,7 @@ msgstr \"ceci est un un test et et\"
         // The double-words fix is unsafe (a few constructions legitimately repeat
         // a word), so it is applied only with --unsafe-fixes.
         args.unsafe_fixes = true;
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
 
         let remaining = result
             .diagnostics
@@ -1011,7 +1868,7 @@ msgstr \"bonjour\"
         args.no_config = true;
         args.select = Some("header".to_string());
         args.fix = true;
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
 
         // Both fixable header diagnostics should be gone after --fix.
         let remaining = result
@@ -1059,7 +1916,7 @@ msgstr \"Guillemets : « test »\"
         args.no_config = true;
         args.select = Some("punc-space-str".to_string());
         args.fix = true;
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
 
         let remaining = result
             .diagnostics
@@ -1091,7 +1948,7 @@ msgstr \"Guillemets : « test »\"
         args.no_config = true;
         args.select = Some("punc-start,punc-end".to_string());
         args.fix = true;
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
 
         // Re-checking the rewritten file must report zero punc diagnostics.
         let remaining = result
@@ -1120,7 +1977,7 @@ msgstr \"Guillemets : « test »\"
         args.no_config = true;
         args.select = Some("unicode-ctrl".to_string());
         args.fix = true;
-        let result = check_file(&po_path, &args);
+        let result = check_file(&po_path, &args, None);
 
         // Both stray chars were fixable, so re-check reports no unicode-ctrl diagnostics.
         let remaining = result
@@ -1176,6 +2033,173 @@ msgstr \"Guillemets : « test »\"
         assert!(diags.is_empty(), "expected no diagnostics, got {diags:?}");
     }
 
+    #[test]
+    fn test_check_empty_msgid_not_header() {
+        let content = format!(
+            "{PO_PT_BR}
+msgid \"\"
+msgstr \"Language: fr\\n\"
+
+msgctxt \"ctxt\"
+msgid \"\"
+msgstr \"Contexte\"
+
+msgid \"a\"
+msgstr \"b\"
+"
+        );
+        let mut checker = Checker::new(content.as_bytes());
+        checker.do_all_checks(&get_selected_rules(&Config::default()).expect("default rules"));
+        let diags: Vec<_> = checker
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "empty-msgid")
+            .collect();
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert_eq!(diags[0].message, "unexpected empty msgid");
+        assert_eq!(diags[0].lines[0].line_number, 9);
+    }
+
+    #[test]
+    fn test_check_duplicate_messages() {
+        let content = format!(
+            "{PO_PT_BR}
+msgid \"a\"
+msgstr \"b\"
+
+msgctxt \"ctxt\"
+msgid \"a\"
+msgstr \"c\"
+
+msgid \"a\"
+msgstr \"d\"
+
+#~ msgid \"a\"
+#~ msgstr \"e\"
+"
+        );
+        let mut checker = Checker::new(content.as_bytes());
+        checker.do_all_checks(&get_selected_rules(&Config::default()).expect("default rules"));
+        let diags: Vec<_> = checker
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "duplicate")
+            .collect();
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "duplicate message (first defined at line 9)"
+        );
+        assert_eq!(diags[0].lines[0].line_number, 16);
+    }
+
+    fn check_only_entries(only_untranslated: bool, only_fuzzy: bool) -> Vec<(String, usize)> {
+        let content = "
+msgid \" translated\"
+msgstr \"traduit\"
+
+msgid \"untranslated\"
+msgstr \"\"
+
+#, fuzzy
+msgid \" fuzzy\"
+msgstr \"flou\"
+";
+        let mut config = Config::default();
+        config.check.select = vec!["untranslated".to_string(), "whitespace-start".to_string()];
+        config.check.only_untranslated = only_untranslated;
+        config.check.only_fuzzy = only_fuzzy;
+        config.check.fuzzy = only_fuzzy;
+        let rules = get_selected_rules(&config).expect("rules");
+        let mut checker = Checker::new(content.as_bytes()).with_config(config);
+        checker.do_all_checks(&rules);
+        checker
+            .diagnostics
+            .iter()
+            .map(|d| (d.rule.to_string(), d.lines[0].line_number))
+            .collect()
+    }
+
+    #[test]
+    fn test_check_only_untranslated_or_fuzzy() {
+        let diag = |rule: &str, line| (rule.to_string(), line);
+        assert_eq!(
+            check_only_entries(false, false),
+            vec![diag("whitespace-start", 2), diag("untranslated", 5)]
+        );
+        assert_eq!(
+            check_only_entries(true, false),
+            vec![diag("untranslated", 5)]
+        );
+        assert_eq!(
+            check_only_entries(false, true),
+            vec![diag("whitespace-start", 9)]
+        );
+        assert_eq!(
+            check_only_entries(true, true),
+            vec![diag("untranslated", 5), diag("whitespace-start", 9)]
+        );
+    }
+
+    #[test]
+    fn test_check_severity_filter_uses_overridden_severity() {
+        let content = format!(
+            "{PO_PT_BR}
+#, c-format
+msgid \"%s test\"
+msgstr \"%d test\"
+"
+        );
+        let mut config = Config::default();
+        config.check.severity = vec![Severity::Error];
+        let rules = get_selected_rules(&config).expect("default rules");
+        let mut checker = Checker::new(content.as_bytes()).with_config(config);
+        checker.do_all_checks(&rules);
+        assert!(checker.diagnostics.iter().any(|d| d.rule == "formats"));
+        // Rule "formats" reports errors, downgraded to info: excluded by the filter.
+        let mut config = Config::default();
+        config.check.severity = vec![Severity::Error];
+        config
+            .severity
+            .insert("formats".to_string(), Severity::Info);
+        let rules = get_selected_rules(&config).expect("default rules");
+        let mut checker = Checker::new(content.as_bytes()).with_config(config);
+        checker.do_all_checks(&rules);
+        assert!(!checker.diagnostics.iter().any(|d| d.rule == "formats"));
+    }
+
+    #[test]
+    fn test_check_bom_reported_once() {
+        let content = format!("\u{FEFF}{PO_PT_BR}\nmsgid \"a\"\nmsgstr \"b\"\n");
+        let mut checker = Checker::new(content.as_bytes());
+        checker.do_all_checks(&get_selected_rules(&Config::default()).expect("default rules"));
+        let bom_diags: Vec<_> = checker
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "bom")
+            .collect();
+        assert_eq!(bom_diags.len(), 1);
+        assert_eq!(bom_diags[0].message, "file starts with a UTF-8 BOM");
+    }
+
+    #[test]
+    fn test_check_bytes_diagnostics_have_msgctxt() {
+        let content = r#"
+msgctxt "menu"
+msgid "Open "
+msgstr "Ouvrir"
+
+msgid "Open "
+msgstr "Ouvrir"
+"#;
+        let config = config_with_select(&["whitespace-end"]);
+        let diags = check_bytes(content.as_bytes(), Path::new("fr.po"), config);
+        assert_eq!(diags.len(), 2);
+        assert_eq!(diags[0].msgctxt.as_deref(), Some("menu"));
+        assert!(diags[1].msgctxt.is_none());
+    }
+
     #[test]
     fn test_check_bytes_invalid_rule_returns_rules_error() {
         let config = config_with_select(&["does-not-exist-rule"]);
@@ -5,7 +5,7 @@
 //! Configuration options.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::error::Error;
 use std::fs::read_to_string;
 use std::path::{Path, PathBuf};
@@ -14,19 +14,28 @@ use crate::args;
 use crate::diagnostic::Severity;
 use crate::dict;
 use crate::po::wrap::DEFAULT_PAGE_WIDTH;
+use crate::rules::number_locale::DEFAULT_NUMBER_LOCALES;
 
 pub const DEFAULT_PATH_MSGFMT: &str = "/usr/bin/msgfmt";
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct Config {
     #[serde(skip)]
     pub path: Option<PathBuf>,
 
     #[serde(default)]
     pub check: CheckConfig,
+
+    /// Severity overrides: rule name → severity used for all its diagnostics.
+    #[serde(default)]
+    pub severity: BTreeMap<String, Severity>,
+
+    /// Quotes expected in translations: language code → opening and closing quotes.
+    #[serde(default)]
+    pub quotes: BTreeMap<String, String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CheckConfig {
     #[serde(default)]
@@ -38,6 +47,12 @@ pub struct CheckConfig {
     #[serde(default)]
     pub obsolete: bool,
 
+    #[serde(default)]
+    pub only_untranslated: bool,
+
+    #[serde(default)]
+    pub only_fuzzy: bool,
+
     #[serde(default = "default_check_select")]
     pub select: Vec<String>,
 
@@ -59,18 +74,57 @@ pub struct CheckConfig {
     #[serde(default)]
     pub no_trans_file: Option<PathBuf>,
 
+    #[serde(default)]
+    pub glossary_dir: Option<PathBuf>,
+
     #[serde(default = "default_check_lang_id")]
     pub lang_id: String,
 
+    #[serde(default)]
+    pub lang_str: Option<String>,
+
     #[serde(default)]
     pub langs: Vec<String>,
 
+    #[serde(default)]
+    pub spell_suggest: bool,
+
+    #[serde(default = "default_check_spell_min_length")]
+    pub spell_min_length: usize,
+
+    #[serde(default)]
+    pub spell_split_identifiers: bool,
+
+    #[serde(default)]
+    pub words_keep_underscore: bool,
+
     #[serde(default = "default_check_short_factor")]
     pub short_factor: u16,
 
     #[serde(default = "default_check_long_factor")]
     pub long_factor: u16,
 
+    #[serde(default = "default_check_length_ratio_min")]
+    pub length_ratio_min: f64,
+
+    #[serde(default = "default_check_length_ratio_max")]
+    pub length_ratio_max: f64,
+
+    #[serde(default)]
+    pub unchanged_allow: Vec<String>,
+
+    #[serde(default)]
+    pub unchanged_min_words: usize,
+
+    #[serde(default = "default_check_context_max_words")]
+    pub context_max_words: usize,
+
+    #[serde(default = "default_check_number_locales")]
+    pub number_locales: Vec<String>,
+
+    #[serde(default = "default_check_max_obsolete")]
+    pub max_obsolete: usize,
+
     #[serde(default)]
     pub severity: Vec<Severity>,
 
@@ -107,6 +161,11 @@ fn default_check_lang_id() -> String {
     String::from(dict::DEFAULT_LANG_ID)
 }
 
+/// Default value for `check.spell_min_length`.
+const fn default_check_spell_min_length() -> usize {
+    2
+}
+
 /// Default value for `check.short_factor`.
 fn default_check_short_factor() -> u16 {
     8
@@ -117,6 +176,34 @@ fn default_check_long_factor() -> u16 {
     8
 }
 
+/// Default value for `check.length_ratio_min`.
+const fn default_check_length_ratio_min() -> f64 {
+    0.2
+}
+
+/// Default value for `check.length_ratio_max`.
+const fn default_check_length_ratio_max() -> f64 {
+    3.0
+}
+
+/// Default value for `check.context_max_words`.
+const fn default_check_context_max_words() -> usize {
+    1
+}
+
+/// Default value for `check.number_locales`.
+fn default_check_number_locales() -> Vec<String> {
+    DEFAULT_NUMBER_LOCALES
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Default value for `check.max_obsolete`.
+const fn default_check_max_obsolete() -> usize {
+    10
+}
+
 /// Default value for `check.accelerator`.
 const fn default_check_accelerator() -> char {
     '&'
@@ -133,6 +220,8 @@ impl Default for CheckConfig {
             fuzzy: false,
             noqa: false,
             obsolete: false,
+            only_untranslated: false,
+            only_fuzzy: false,
             select: default_check_select(),
             ignore: vec![],
             path_msgfmt: default_check_path_msgfmt(),
@@ -140,10 +229,23 @@ impl Default for CheckConfig {
             path_words: None,
             force_trans_file: None,
             no_trans_file: None,
+            glossary_dir: None,
             lang_id: default_check_lang_id(),
+            lang_str: None,
             langs: vec![],
+            spell_suggest: false,
+            spell_min_length: default_check_spell_min_length(),
+            spell_split_identifiers: false,
+            words_keep_underscore: false,
             short_factor: default_check_short_factor(),
             long_factor: default_check_long_factor(),
+            length_ratio_min: default_check_length_ratio_min(),
+            length_ratio_max: default_check_length_ratio_max(),
+            unchanged_allow: vec![],
+            unchanged_min_words: 0,
+            context_max_words: default_check_context_max_words(),
+            number_locales: default_check_number_locales(),
+            max_obsolete: default_check_max_obsolete(),
             severity: vec![],
             punc_ignore_ellipsis: false,
             accelerator: default_check_accelerator(),
@@ -154,7 +256,13 @@ impl Default for CheckConfig {
 }
 
 impl Config {
-    /// Create a configuration by reading a configuration file.
+    /// Create a configuration by reading a configuration file (default configuration
+    /// if `path` is `None`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can not be read, is not valid TOML or contains
+    /// invalid values.
     pub fn new(path: Option<&PathBuf>) -> Result<Self, Box<dyn Error>> {
         let content = match path {
             Some(cfg_path) => match read_to_string(cfg_path) {
@@ -178,6 +286,24 @@ impl Config {
             )
             .into());
         }
+        if !(0.0..config.check.length_ratio_max).contains(&config.check.length_ratio_min) {
+            return Err(format!(
+                "invalid `check.length_ratio_min` / `check.length_ratio_max`: {} / {} \
+                 (expected 0 <= min < max)",
+                config.check.length_ratio_min, config.check.length_ratio_max,
+            )
+            .into());
+        }
+        if let Some((lang, quotes)) = config
+            .quotes
+            .iter()
+            .find(|(_, quotes)| quotes.chars().count() != 2)
+        {
+            return Err(format!(
+                "invalid `quotes.{lang}`: \"{quotes}\" (expected opening and closing quotes)",
+            )
+            .into());
+        }
         if let Some(path) = path {
             config.path = Some(PathBuf::from(path));
         }
@@ -192,8 +318,8 @@ impl Config {
             .map(Path::to_path_buf)
     }
 
-    /// Resolve relative `path_words` / `force_trans_file` / `no_trans_file`
-    /// values against the directory of the loaded config file, canonicalizing
+    /// Resolve relative `path_words` / `force_trans_file` / `no_trans_file` /
+    /// `glossary_dir` values against the directory of the loaded config file, canonicalizing
     /// when possible. A no-op for absolute paths or when no config file path is
     /// set.
     ///
@@ -207,9 +333,12 @@ impl Config {
         resolve_config_relative(&mut self.check.path_words, config_dir);
         resolve_config_relative(&mut self.check.force_trans_file, config_dir);
         resolve_config_relative(&mut self.check.no_trans_file, config_dir);
+        resolve_config_relative(&mut self.check.glossary_dir, config_dir);
     }
 
     /// Update the configuration with command-line arguments.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
     pub fn with_args_check(mut self, args: &args::CheckArgs) -> Self {
         if args.fuzzy {
             self.check.fuzzy = true;
@@ -220,6 +349,16 @@ impl Config {
         if args.obsolete {
             self.check.obsolete = true;
         }
+        if args.only_untranslated {
+            self.check.only_untranslated = true;
+        }
+        if args.only_fuzzy {
+            self.check.only_fuzzy = true;
+        }
+        if self.check.only_fuzzy {
+            // Fuzzy entries must be checked to check only them.
+            self.check.fuzzy = true;
+        }
         if let Some(select) = &args.select {
             self.check.select = select.split(',').map(|s| s.trim().to_string()).collect();
         }
@@ -250,18 +389,66 @@ impl Config {
             let config_dir = self.config_dir();
             resolve_config_relative(&mut self.check.no_trans_file, config_dir.as_deref());
         }
+        if let Some(glossary_dir) = &args.glossary_dir {
+            self.check.glossary_dir = Some(PathBuf::from(glossary_dir));
+        } else {
+            let config_dir = self.config_dir();
+            resolve_config_relative(&mut self.check.glossary_dir, config_dir.as_deref());
+        }
         if let Some(lang_id) = &args.lang_id {
             self.check.lang_id = String::from(lang_id);
         }
+        if let Some(lang_str) = &args.lang_str {
+            self.check.lang_str = Some(String::from(lang_str));
+        }
         if let Some(langs) = &args.langs {
             self.check.langs = langs.split(',').map(|s| s.trim().to_string()).collect();
         }
+        if args.spell_split_identifiers {
+            self.check.spell_split_identifiers = true;
+        }
+        if args.words_keep_underscore {
+            self.check.words_keep_underscore = true;
+        }
+        if let Some(spell_min_length) = args.spell_min_length {
+            self.check.spell_min_length = spell_min_length;
+        }
+        if args.spell_suggest {
+            self.check.spell_suggest = true;
+        }
         if let Some(short_factor) = args.short_factor {
             self.check.short_factor = short_factor;
         }
         if let Some(long_factor) = args.long_factor {
             self.check.long_factor = long_factor;
         }
+        if let Some(length_ratio_min) = args.length_ratio_min {
+            self.check.length_ratio_min = length_ratio_min;
+        }
+        if let Some(length_ratio_max) = args.length_ratio_max {
+            self.check.length_ratio_max = length_ratio_max;
+        }
+        if let Some(unchanged_allow) = &args.unchanged_allow {
+            self.check.unchanged_allow = unchanged_allow
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .collect();
+        }
+        if let Some(unchanged_min_words) = args.unchanged_min_words {
+            self.check.unchanged_min_words = unchanged_min_words;
+        }
+        if let Some(number_locales) = &args.number_locales {
+            self.check.number_locales = number_locales
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .collect();
+        }
+        if let Some(context_max_words) = args.context_max_words {
+            self.check.context_max_words = context_max_words;
+        }
+        if let Some(max_obsolete) = args.max_obsolete {
+            self.check.max_obsolete = max_obsolete;
+        }
         if !args.severity.is_empty() {
             self.check.severity.clone_from(&args.severity);
         }
@@ -357,12 +544,17 @@ mod tests {
     fn default_check_args() -> args::CheckArgs {
         args::CheckArgs {
             files: vec![],
+            exclude: vec![],
+            files_from: None,
             show_settings: false,
             config: None,
             no_config: false,
             fuzzy: false,
             noqa: false,
             obsolete: false,
+            only_untranslated: false,
+            only_fuzzy: false,
+            pot: false,
             select: None,
             ignore: None,
             path_msgfmt: None,
@@ -370,10 +562,23 @@ mod tests {
             path_words: None,
             force_trans_file: None,
             no_trans_file: None,
+            glossary_dir: None,
             lang_id: None,
+            lang_str: None,
             langs: None,
+            spell_suggest: false,
+            spell_min_length: None,
+            spell_split_identifiers: false,
+            words_keep_underscore: false,
             short_factor: None,
             long_factor: None,
+            length_ratio_min: None,
+            length_ratio_max: None,
+            unchanged_allow: None,
+            unchanged_min_words: None,
+            context_max_words: None,
+            number_locales: None,
+            max_obsolete: None,
             severity: vec![],
             punc_ignore_ellipsis: false,
             accelerator: None,
@@ -381,11 +586,20 @@ mod tests {
             sort: args::CheckSort::default(),
             rule_stats: false,
             file_stats: false,
+            relative_to: None,
+            hyperlinks: false,
+            highlight_style: crate::diagnostic::HighlightStyle::default(),
+            diff: None,
             output: args::CheckOutputFormat::default(),
+            json_summary: false,
             quiet: false,
+            max_problems: None,
+            progress: false,
+            error_on_warning: false,
             fix: false,
             unsafe_fixes: false,
             width: None,
+            watch: false,
         }
     }
 
@@ -418,6 +632,16 @@ mod tests {
         assert!(c.langs.is_empty());
         assert!(c.severity.is_empty());
         assert!(!c.punc_ignore_ellipsis);
+        assert!(!c.spell_split_identifiers);
+        assert!(!c.words_keep_underscore);
+        assert!(c.unchanged_allow.is_empty());
+        assert_eq!(c.unchanged_min_words, 0);
+        assert_eq!(c.context_max_words, 1);
+        assert_eq!(c.number_locales, ["de", "es", "fr", "it", "nl", "pt", "ru"]);
+        assert_eq!(c.max_obsolete, 10);
+        assert!((c.length_ratio_min - 0.2).abs() < f64::EPSILON);
+        assert!((c.length_ratio_max - 3.0).abs() < f64::EPSILON);
+        assert_eq!(c.spell_min_length, 2);
         assert_eq!(c.accelerator, '&');
     }
 
@@ -495,6 +719,62 @@ punc_ignore_ellipsis = true
         assert!(c.check.unsafe_fixes);
     }
 
+    #[test]
+    fn test_config_new_reads_severity_overrides() {
+        let (_tmp, root) = tmp_dir("cfg-severity");
+        let cfg_path = root.join("poexam.toml");
+        std::fs::write(
+            &cfg_path,
+            r#"
+[check]
+severity = ["error", "warning"]
+
+[severity]
+punc-end = "info"
+brackets = "error"
+"#,
+        )
+        .expect("write config");
+        let c = Config::new(Some(&cfg_path)).expect("parse config");
+        assert_eq!(c.check.severity, vec![Severity::Error, Severity::Warning]);
+        assert_eq!(c.severity.len(), 2);
+        assert_eq!(c.severity.get("punc-end"), Some(&Severity::Info));
+        assert_eq!(c.severity.get("brackets"), Some(&Severity::Error));
+    }
+
+    #[test]
+    fn test_config_new_invalid_severity_override_returns_err() {
+        let (_tmp, root) = tmp_dir("cfg-severity-bad");
+        let cfg_path = root.join("poexam.toml");
+        std::fs::write(
+            &cfg_path,
+            "[severity]
+punc-end = \"fatal\"\n",
+        )
+        .expect("write config");
+        assert!(Config::new(Some(&cfg_path)).is_err());
+    }
+
+    #[test]
+    fn test_config_new_reads_quotes() {
+        let (_tmp, root) = tmp_dir("cfg-quotes");
+        let cfg_path = root.join("poexam.toml");
+        std::fs::write(&cfg_path, "[quotes]\nfr = \"“”\"\nnl = \"‘’\"\n").expect("write config");
+        let c = Config::new(Some(&cfg_path)).expect("parse config");
+        assert_eq!(c.quotes.len(), 2);
+        assert_eq!(c.quotes.get("fr").map(String::as_str), Some("“”"));
+        assert_eq!(c.quotes.get("nl").map(String::as_str), Some("‘’"));
+    }
+
+    #[test]
+    fn test_config_new_invalid_quotes_returns_err() {
+        let (_tmp, root) = tmp_dir("cfg-quotes-bad");
+        let cfg_path = root.join("poexam.toml");
+        std::fs::write(&cfg_path, "[quotes]\nfr = \"«\"\n").expect("write config");
+        let err = Config::new(Some(&cfg_path)).expect_err("invalid quotes");
+        assert!(err.to_string().contains("invalid `quotes.fr`"));
+    }
+
     #[test]
     fn test_config_new_missing_file_returns_err() {
         let missing = PathBuf::from("/this/path/should/not/exist/poexam.toml");
@@ -521,6 +801,13 @@ punc_ignore_ellipsis = true
         assert!(msg.contains("check.short_factor"));
         assert!(msg.contains("min: 2"));
 
+        std::fs::write(
+            &cfg_path,
+            "[check]\nlength_ratio_min = 2.0\nlength_ratio_max = 1.5\n",
+        )
+        .expect("rewrite config");
+        let err = Config::new(Some(&cfg_path)).expect_err("length ratio min above max is an error");
+        assert!(err.to_string().contains("check.length_ratio_min"));
         std::fs::write(&cfg_path, "[check]\nlong_factor = 0\n").expect("rewrite config");
         let err = Config::new(Some(&cfg_path)).expect_err("long_factor below min is an error");
         let msg = err.to_string();
@@ -555,6 +842,21 @@ punc_ignore_ellipsis = true
         assert!(cfg.check.punc_ignore_ellipsis);
     }
 
+    #[test]
+    fn test_with_args_check_only_fuzzy_implies_fuzzy() {
+        let mut args = default_check_args();
+        args.only_untranslated = true;
+        let cfg = Config::default().with_args_check(&args);
+        assert!(cfg.check.only_untranslated);
+        assert!(!cfg.check.only_fuzzy);
+        assert!(!cfg.check.fuzzy);
+        args.only_fuzzy = true;
+        let cfg = Config::default().with_args_check(&args);
+        assert!(cfg.check.only_untranslated);
+        assert!(cfg.check.only_fuzzy);
+        assert!(cfg.check.fuzzy);
+    }
+
     #[test]
     fn test_with_args_check_booleans_do_not_unset_existing() {
         // Args are additive: a `false` flag must not turn off a config-set `true`.
@@ -577,6 +879,8 @@ punc_ignore_ellipsis = true
         args.select = Some(" spelling , html-tags ".to_string());
         args.ignore = Some("urls,paths".to_string());
         args.langs = Some("en_US, fr ,de".to_string());
+        args.unchanged_allow = Some("OK, Menu".to_string());
+        args.number_locales = Some("fr, pl".to_string());
         let cfg = Config::default().with_args_check(&args);
         assert_eq!(
             cfg.check.select,
@@ -590,6 +894,14 @@ punc_ignore_ellipsis = true
             cfg.check.langs,
             vec!["en_US".to_string(), "fr".to_string(), "de".to_string()],
         );
+        assert_eq!(
+            cfg.check.unchanged_allow,
+            vec!["OK".to_string(), "Menu".to_string()],
+        );
+        assert_eq!(
+            cfg.check.number_locales,
+            vec!["fr".to_string(), "pl".to_string()],
+        );
     }
 
     #[test]
@@ -599,11 +911,13 @@ punc_ignore_ellipsis = true
         args.path_dicts = Some(PathBuf::from("/opt/share/hunspell"));
         args.path_words = Some(PathBuf::from("/opt/words"));
         args.lang_id = Some("de".to_string());
+        args.lang_str = Some("fr".to_string());
         let cfg = Config::default().with_args_check(&args);
         assert_eq!(cfg.check.path_msgfmt, PathBuf::from("/opt/bin/msgfmt"));
         assert_eq!(cfg.check.path_dicts, PathBuf::from("/opt/share/hunspell"));
         assert_eq!(cfg.check.path_words, Some(PathBuf::from("/opt/words")));
         assert_eq!(cfg.check.lang_id, "de");
+        assert_eq!(cfg.check.lang_str.as_deref(), Some("fr"));
     }
 
     #[test]
@@ -630,6 +944,7 @@ punc_ignore_ellipsis = true
                 path_words: Some(PathBuf::from("words")),
                 ..CheckConfig::default()
             },
+            ..Config::default()
         };
         let cfg = cfg.with_args_check(&default_check_args());
 
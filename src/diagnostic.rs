@@ -6,16 +6,20 @@
 
 use std::{
     borrow::Cow,
-    collections::HashSet,
+    collections::{HashMap, HashSet},
+    fmt::Write as _,
     path::{Path, PathBuf},
 };
 
 use clap::ValueEnum;
 use colored::Colorize;
+use path_absolutize::Absolutize;
+use schemars::JsonSchema;
 use serde::{
     Deserialize, Serialize,
     ser::{SerializeStruct, Serializer},
 };
+use unicode_width::UnicodeWidthChar;
 
 use crate::fix::Fix;
 use crate::po::{entry::Entry, message::Message};
@@ -35,6 +39,7 @@ const HIGHLIGHT_ON_COLOR: &str = "red";
     Hash,
     Serialize,
     Deserialize,
+    JsonSchema,
     ValueEnum,
 )]
 #[serde(rename_all = "lowercase")]
@@ -45,21 +50,55 @@ pub enum Severity {
     Error,
 }
 
-#[derive(Debug, Default)]
+/// Style used to display the highlighted parts of lines in diagnostics.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum HighlightStyle {
+    #[default]
+    /// Colors (text and background)
+    Color,
+
+    /// Carets (`^^^`) under the highlighted parts, on the following line
+    Carets,
+
+    /// Brackets around the highlighted parts (`[[text]]`)
+    Brackets,
+}
+
+#[derive(Debug, Default, JsonSchema)]
 pub struct DiagnosticLine {
+    /// Line number in the PO file (0 for an empty separator line).
     pub line_number: usize,
+    /// Column (1-based) of the keyword (`msgid`, `msgstr`, …) on the line, 0 if
+    /// unknown. For a multi-line string, this is the column of the keyword, not the
+    /// one of the string.
+    pub column: usize,
+    /// Content of the line.
     pub message: String,
+    /// Highlighted parts of the line: list of (start, end) positions (in characters
+    /// in JSON output).
     pub highlights: Vec<(usize, usize)>,
 }
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Default, Serialize, JsonSchema)]
 pub struct Diagnostic {
+    /// Path to the PO file.
     pub path: PathBuf,
+    /// Name of the rule which reported the diagnostic.
     pub rule: &'static str,
     pub severity: Severity,
     pub message: Cow<'static, str>,
+    /// Context of the entry (`msgctxt`), if any, to identify the entry when several
+    /// entries have the same `msgid`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msgctxt: Option<String>,
+    /// Lines of the entry displayed with the diagnostic.
     pub lines: Vec<DiagnosticLine>,
+    /// Misspelled words (reported only by spelling rules).
     pub misspelled_words: HashSet<String>,
+    /// Top suggestion of the dictionary for misspelled words (set only with
+    /// `--spell-suggest`).
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub spelling_suggestions: HashMap<String, String>,
     /// Optional auto-fix produced by the rule. Set only for diagnostics the rule
     /// knows how to correct deterministically. The fix runner consumes this to
     /// rewrite the source file when `--fix` is requested.
@@ -85,8 +124,9 @@ impl Serialize for DiagnosticLine {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("DiagnosticLine", 3)?;
+        let mut state = serializer.serialize_struct("DiagnosticLine", 4)?;
         state.serialize_field("line_number", &self.line_number)?;
+        state.serialize_field("column", &self.column)?;
         state.serialize_field("message", &self.message)?;
         // Convert highlights from byte positions to character positions for serialization.
         let hl: Vec<_> = self
@@ -105,8 +145,9 @@ impl Serialize for DiagnosticLine {
 }
 
 impl DiagnosticLine {
-    /// Highlight multiple substrings from `start` to `end` with the given text and background colors.
-    fn highlight_list_pos(s: &str, list_pos: &[(usize, usize)]) -> String {
+    /// Highlight multiple substrings from `start` to `end` with the given text and
+    /// background colors, or with brackets around them.
+    fn highlight_list_pos(s: &str, list_pos: &[(usize, usize)], style: HighlightStyle) -> String {
         let mut result = String::new();
         let mut pos = 0;
         for (start, end) in list_pos {
@@ -114,26 +155,75 @@ impl DiagnosticLine {
                 continue;
             }
             result.push_str(&s[pos..*start]);
-            result.push_str(
-                &s[*start..*end]
-                    .color(HIGHLIGHT_COLOR)
-                    .bold()
-                    .on_color(HIGHLIGHT_ON_COLOR)
-                    .to_string(),
-            );
+            if style == HighlightStyle::Brackets {
+                result.push_str("[[");
+                result.push_str(&s[*start..*end]);
+                result.push_str("]]");
+            } else {
+                result.push_str(
+                    &s[*start..*end]
+                        .color(HIGHLIGHT_COLOR)
+                        .bold()
+                        .on_color(HIGHLIGHT_ON_COLOR)
+                        .to_string(),
+                );
+            }
             pos = *end;
         }
         result.push_str(&s[pos..]);
         result
     }
 
-    /// Get the message with highlights applied.
-    fn message_hl_color(&self) -> Cow<'_, str> {
-        if self.highlights.is_empty() {
+    /// Get the message with highlights applied (the message is unchanged with carets,
+    /// which are displayed on the following line, see [`carets_line`](Self::carets_line)).
+    fn message_hl(&self, style: HighlightStyle) -> Cow<'_, str> {
+        if self.highlights.is_empty() || style == HighlightStyle::Carets {
             Cow::Borrowed(&self.message)
         } else {
-            Cow::Owned(Self::highlight_list_pos(&self.message, &self.highlights))
+            Cow::Owned(Self::highlight_list_pos(
+                &self.message,
+                &self.highlights,
+                style,
+            ))
+        }
+    }
+
+    /// Build the line with carets (`^`) under the highlighted parts of `line`, which
+    /// starts at byte `offset` in the message, or `None` if no part of the line is
+    /// highlighted.
+    ///
+    /// The carets are aligned with the columns of the highlighted characters: the
+    /// width of multi-byte and wide characters is used, and tabs are kept.
+    /// There is always at least one caret per highlight.
+    fn carets_line(line: &str, offset: usize, list_pos: &[(usize, usize)]) -> Option<String> {
+        let mut carets = String::new();
+        let mut pos = 0;
+        for (start, end) in list_pos {
+            // An empty highlight (e.g. missing punctuation) is displayed with one caret.
+            let empty = start == end;
+            if empty && !(offset..=offset + line.len()).contains(start) {
+                continue;
+            }
+            let start = start.saturating_sub(offset);
+            let end = (*end).min(offset + line.len()).saturating_sub(offset);
+            if start < pos || (start >= end && !empty) {
+                continue;
+            }
+            for c in line[pos..start].chars() {
+                if c == '\t' {
+                    carets.push('\t');
+                } else {
+                    carets.extend(std::iter::repeat_n(' ', c.width().unwrap_or(0)));
+                }
+            }
+            let width: usize = line[start..end]
+                .chars()
+                .map(|c| c.width().unwrap_or(0))
+                .sum();
+            carets.extend(std::iter::repeat_n('^', width.max(1)));
+            pos = end;
         }
+        (!carets.is_empty()).then_some(carets)
     }
 }
 
@@ -156,6 +246,7 @@ impl Diagnostic {
     }
 
     /// Add keywords of a PO entry to the diagnostic.
+    #[must_use]
     pub fn with_keywords(mut self, entry: &Entry) -> Self {
         for line in entry.keywords_to_po_lines() {
             self.add_line(0, &line, []);
@@ -164,37 +255,48 @@ impl Diagnostic {
     }
 
     /// Add messages of a PO entry to the diagnostic.
+    #[must_use]
     pub fn with_entry(mut self, entry: &Entry) -> Self {
-        for (line_no, line) in entry.msg_to_po_lines() {
+        let msgs = entry
+            .msgctxt
+            .iter()
+            .chain(entry.iter_ids())
+            .chain(entry.iter_strs().map(|(_, msg)| msg));
+        for ((line_no, line), msg) in entry.msg_to_po_lines().into_iter().zip(msgs) {
             self.add_line(line_no, &line, []);
+            self.set_last_column(msg.column);
         }
         self
     }
 
     /// Add one message to the diagnostic.
+    #[must_use]
     pub fn with_msg(mut self, msg: &Message) -> Self {
-        self.add_line(msg.line_number, &msg.value, []);
+        self.add_msg_line(msg, []);
         self
     }
 
     /// Add one message to the diagnostic with the given highlights.
+    #[must_use]
     pub fn with_msg_hl<I>(mut self, msg: &Message, hl: I) -> Self
     where
         I: IntoIterator<Item = (usize, usize)>,
     {
-        self.add_line(msg.line_number, &msg.value, hl);
+        self.add_msg_line(msg, hl);
         self
     }
 
     /// Add two messages (typically msgid and msgstr) to the diagnostic.
+    #[must_use]
     pub fn with_msgs(mut self, msgid: &Message, msgstr: &Message) -> Self {
-        self.add_line(msgid.line_number, &msgid.value, []);
+        self.add_msg_line(msgid, []);
         self.add_line(0, "", []);
-        self.add_line(msgstr.line_number, &msgstr.value, []);
+        self.add_msg_line(msgstr, []);
         self
     }
 
     /// Add two messages (typically msgid and msgstr) to the diagnostic with the given highlights.
+    #[must_use]
     pub fn with_msgs_hl<A, B>(
         mut self,
         msgid: &Message,
@@ -206,13 +308,14 @@ impl Diagnostic {
         A: IntoIterator<Item = (usize, usize)>,
         B: IntoIterator<Item = (usize, usize)>,
     {
-        self.add_line(msgid.line_number, &msgid.value, hl_id);
+        self.add_msg_line(msgid, hl_id);
         self.add_line(0, "", []);
-        self.add_line(msgstr.line_number, &msgstr.value, hl_str);
+        self.add_msg_line(msgstr, hl_str);
         self
     }
 
     /// Add multiple lines to the diagnostic with the given multiline string.
+    #[must_use]
     pub fn with_multiline(mut self, lines: &str) -> Self {
         if !lines.trim().is_empty() {
             for line in lines.lines() {
@@ -223,12 +326,21 @@ impl Diagnostic {
     }
 
     /// Add misspelled words to the diagnostic.
+    #[must_use]
     pub fn with_misspelled_words(mut self, misspelled_words: HashSet<&str>) -> Self {
         self.misspelled_words = misspelled_words.into_iter().map(String::from).collect();
         self
     }
 
+    /// Add suggestions for misspelled words to the diagnostic.
+    #[must_use]
+    pub fn with_spelling_suggestions(mut self, suggestions: HashMap<String, String>) -> Self {
+        self.spelling_suggestions = suggestions;
+        self
+    }
+
     /// Attach an auto-fix to the diagnostic.
+    #[must_use]
     pub fn with_fix(mut self, fix: Fix) -> Self {
         self.fix = Some(fix);
         self
@@ -237,6 +349,7 @@ impl Diagnostic {
     /// Attach an auto-fix to the diagnostic if one is provided. Convenience
     /// wrapper around [`with_fix`](Self::with_fix) that keeps builder chains
     /// flat when the caller has an `Option<Fix>` instead of a `Fix`.
+    #[must_use]
     pub fn with_optional_fix(self, fix: Option<Fix>) -> Self {
         fix.into_iter().fold(self, Self::with_fix)
     }
@@ -248,12 +361,31 @@ impl Diagnostic {
     {
         self.lines.push(DiagnosticLine {
             line_number: line,
+            column: 0,
             message: message.into(),
             highlights: highlights.into_iter().collect(),
         });
     }
 
-    /// Build the diagnostic message (append misspelled words if any).
+    /// Add a message to the diagnostic with the given highlights, keeping its line
+    /// number and column.
+    fn add_msg_line<I>(&mut self, msg: &Message, highlights: I)
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        self.add_line(msg.line_number, &msg.value, highlights);
+        self.set_last_column(msg.column);
+    }
+
+    /// Set the column of the last line added to the diagnostic.
+    fn set_last_column(&mut self, column: usize) {
+        if let Some(line) = self.lines.last_mut() {
+            line.column = column;
+        }
+    }
+
+    /// Build the diagnostic message (append misspelled words if any, with their
+    /// suggestion if any).
     pub(crate) fn build_message(&self) -> Cow<'_, str> {
         if self.misspelled_words.is_empty() {
             Cow::Borrowed(&self.message)
@@ -265,6 +397,13 @@ impl Diagnostic {
                 .map(String::as_str)
                 .collect::<Vec<&str>>();
             list_words.sort_unstable();
+            let list_words = list_words
+                .into_iter()
+                .map(|word| match self.spelling_suggestions.get(word) {
+                    Some(suggestion) => Cow::Owned(format!("{word} ({suggestion}?)")),
+                    None => Cow::Borrowed(word),
+                })
+                .collect::<Vec<_>>();
             Cow::Owned(format!("{}: {}", self.message, list_words.join(", ")))
         }
     }
@@ -273,7 +412,12 @@ impl Diagnostic {
     ///
     /// `prefix_lf_empty` is the line-continuation prefix; the caller computes it
     /// once per `format_lines` call and passes it down here.
-    fn format_line_into(out: &mut String, line: &DiagnosticLine, prefix_lf_empty: &str) {
+    fn format_line_into(
+        out: &mut String,
+        line: &DiagnosticLine,
+        prefix_lf_empty: &str,
+        highlight_style: HighlightStyle,
+    ) {
         let prefix_line: Cow<'_, str> = if line.line_number > 0 {
             Cow::Owned(format!("{:7} | ", line.line_number).cyan().to_string())
         } else {
@@ -283,7 +427,8 @@ impl Diagnostic {
             out.push_str(&prefix_line);
             return;
         }
-        for (idx, l) in line.message_hl_color().lines().enumerate() {
+        let mut offset = 0;
+        for (idx, l) in line.message_hl(highlight_style).lines().enumerate() {
             if idx == 0 {
                 out.push_str(&prefix_line);
             } else {
@@ -291,11 +436,28 @@ impl Diagnostic {
                 out.push_str(prefix_lf_empty);
             }
             out.push_str(l);
+            if highlight_style == HighlightStyle::Carets {
+                // The message is unchanged with carets: skip the line terminator to get
+                // the offset of the line in the message.
+                if idx > 0 {
+                    offset += if line.message[offset..].starts_with("\r\n") {
+                        2
+                    } else {
+                        1
+                    };
+                }
+                if let Some(carets) = DiagnosticLine::carets_line(l, offset, &line.highlights) {
+                    out.push('\n');
+                    out.push_str(prefix_lf_empty);
+                    out.push_str(&carets);
+                }
+                offset += l.len();
+            }
         }
     }
 
     /// Format the diagnostic lines with colors for display.
-    fn format_lines(&self) -> String {
+    fn format_lines(&self, highlight_style: HighlightStyle) -> String {
         if self.lines.is_empty() {
             return "\n".to_string();
         }
@@ -306,7 +468,7 @@ impl Diagnostic {
         out.push_str(&bar);
         for line in &self.lines {
             out.push('\n');
-            Self::format_line_into(&mut out, line, &prefix_lf_empty);
+            Self::format_line_into(&mut out, line, &prefix_lf_empty, highlight_style);
         }
         out.push('\n');
         out.push_str(&bar);
@@ -315,26 +477,107 @@ impl Diagnostic {
     }
 }
 
-impl std::fmt::Display for Diagnostic {
-    /// Format the `Diagnostic` for display, including file, severity, message, and context.
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// Diagnostic displayed with display options: an optional OSC-8 hyperlink on its
+/// location (`path:line`), so that terminals supporting hyperlinks can open the file
+/// with a click, and the style of highlights.
+pub(crate) struct DiagnosticDisplay<'a> {
+    diag: &'a Diagnostic,
+    /// Directory used to resolve the relative path of the diagnostic (no hyperlink
+    /// if not set).
+    link_base: Option<&'a Path>,
+    /// Style used to display the highlighted parts of lines.
+    highlight_style: HighlightStyle,
+}
+
+impl DiagnosticDisplay<'_> {
+    /// Set the style used to display the highlighted parts of lines.
+    pub(crate) const fn with_highlight_style(mut self, highlight_style: HighlightStyle) -> Self {
+        self.highlight_style = highlight_style;
+        self
+    }
+}
+
+/// Return the `file://` URL of an absolute path, with special characters percent-encoded.
+fn file_url(path: &Path) -> String {
+    let mut url = String::from("file://");
+    for byte in path.as_os_str().as_encoded_bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-._~".contains(byte) {
+            url.push(char::from(*byte));
+        } else {
+            let _ = write!(url, "%{byte:02X}");
+        }
+    }
+    url
+}
+
+impl Diagnostic {
+    /// Return the diagnostic displayed with a hyperlink to the file if `link_base` is
+    /// set (relative paths being resolved from this directory), and the default
+    /// highlight style.
+    pub(crate) const fn display<'a>(
+        &'a self,
+        link_base: Option<&'a Path>,
+    ) -> DiagnosticDisplay<'a> {
+        DiagnosticDisplay {
+            diag: self,
+            link_base,
+            highlight_style: HighlightStyle::Color,
+        }
+    }
+
+    /// Format the `Diagnostic` for display, with a hyperlink on the location if
+    /// `link_base` is set.
+    fn fmt_diag(
+        &self,
+        f: &mut std::fmt::Formatter,
+        link_base: Option<&Path>,
+        highlight_style: HighlightStyle,
+    ) -> std::fmt::Result {
         let str_first_line = self
             .lines
             .iter()
             .find(|line| line.line_number > 0)
             .map_or_else(String::new, |line| format!(":{}", line.line_number));
+        let location = format!("{}{str_first_line}", self.path.display());
+        let abs_path = link_base.and_then(|base| {
+            let abs_base = base.absolutize().ok()?;
+            self.path
+                .absolutize_from(abs_base)
+                .ok()
+                .map(Cow::into_owned)
+        });
+        let location = match abs_path {
+            Some(abs_path) => format!(
+                "\x1b]8;;{}\x1b\\{location}\x1b]8;;\x1b\\",
+                file_url(&abs_path)
+            ),
+            None => location,
+        };
         write!(
             f,
-            "{}{str_first_line}: [{}:{}] {}{}",
-            self.path.display(),
+            "{location}: [{}:{}] {}{}",
             self.severity,
             self.rule,
             self.build_message(),
-            self.format_lines(),
+            self.format_lines(highlight_style),
         )
     }
 }
 
+impl std::fmt::Display for Diagnostic {
+    /// Format the `Diagnostic` for display, including file, severity, message, and context.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.fmt_diag(f, None, HighlightStyle::default())
+    }
+}
+
+impl std::fmt::Display for DiagnosticDisplay<'_> {
+    /// Format the `Diagnostic` for display, with the display options.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.diag.fmt_diag(f, self.link_base, self.highlight_style)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
@@ -343,9 +586,9 @@ mod tests {
 
     fn entry_with_msg(line: usize, msgid: &str, msgstr: &str) -> Entry {
         let mut entry = Entry::new(line);
-        entry.msgid = Some(Message::new(line + 1, msgid, 0..0));
+        entry.msgid = Some(Message::new(line + 1, msgid, 0..0).with_column(1));
         let mut msgstr_map = BTreeMap::new();
-        msgstr_map.insert(0_u32, Message::new(line + 2, msgstr, 0..0));
+        msgstr_map.insert(0_u32, Message::new(line + 2, msgstr, 0..0).with_column(1));
         entry.msgstr = msgstr_map;
         entry
     }
@@ -392,17 +635,19 @@ mod tests {
         diag.add_line(42, "msgstr \"\"", [(8, 9)]);
         assert_eq!(diag.lines.len(), 1);
         assert_eq!(diag.lines[0].line_number, 42);
+        assert_eq!(diag.lines[0].column, 0);
         assert_eq!(diag.lines[0].message, "msgstr \"\"");
         assert_eq!(diag.lines[0].highlights, vec![(8, 9)]);
     }
 
     #[test]
     fn test_with_msg() {
-        let msg = Message::new(10, "hello", 0..0);
+        let msg = Message::new(10, "hello", 0..0).with_column(4);
         let diag =
             Diagnostic::new(Path::new("a.po"), "r", Severity::Info, String::new()).with_msg(&msg);
         assert_eq!(diag.lines.len(), 1);
         assert_eq!(diag.lines[0].line_number, 10);
+        assert_eq!(diag.lines[0].column, 4);
         assert_eq!(diag.lines[0].message, "hello");
         assert!(diag.lines[0].highlights.is_empty());
     }
@@ -417,16 +662,19 @@ mod tests {
 
     #[test]
     fn test_with_msgs_inserts_separator() {
-        let msgid = Message::new(10, "hello", 0..0);
-        let msgstr = Message::new(11, "bonjour", 0..0);
+        let msgid = Message::new(10, "hello", 0..0).with_column(1);
+        let msgstr = Message::new(11, "bonjour", 0..0).with_column(1);
         let diag = Diagnostic::new(Path::new("a.po"), "r", Severity::Info, String::new())
             .with_msgs(&msgid, &msgstr);
         assert_eq!(diag.lines.len(), 3);
         assert_eq!(diag.lines[0].line_number, 10);
+        assert_eq!(diag.lines[0].column, 1);
         assert_eq!(diag.lines[0].message, "hello");
         assert_eq!(diag.lines[1].line_number, 0);
+        assert_eq!(diag.lines[1].column, 0);
         assert_eq!(diag.lines[1].message, "");
         assert_eq!(diag.lines[2].line_number, 11);
+        assert_eq!(diag.lines[2].column, 1);
         assert_eq!(diag.lines[2].message, "bonjour");
     }
 
@@ -451,12 +699,15 @@ mod tests {
         // 2 keyword lines + 2 entry lines (msgid, msgstr).
         assert_eq!(diag.lines.len(), 4);
         assert_eq!(diag.lines[0].line_number, 0);
+        assert_eq!(diag.lines[0].column, 0);
         assert_eq!(diag.lines[0].message, "#, fuzzy");
         assert_eq!(diag.lines[1].message, "#, c-format");
         assert_eq!(diag.lines[2].message, "msgid \"hello\"");
         assert_eq!(diag.lines[2].line_number, 6);
+        assert_eq!(diag.lines[2].column, 1);
         assert_eq!(diag.lines[3].message, "msgstr \"bonjour\"");
         assert_eq!(diag.lines[3].line_number, 7);
+        assert_eq!(diag.lines[3].column, 1);
     }
 
     #[test]
@@ -516,17 +767,32 @@ mod tests {
         assert_eq!(diag.build_message(), "misspelled words: xxa, xxb, xxc");
     }
 
+    #[test]
+    fn test_build_message_misspelled_with_suggestions() {
+        let diag = Diagnostic::new(
+            Path::new("a.po"),
+            "spelling-str",
+            Severity::Info,
+            "misspelled words".to_string(),
+        )
+        .with_spelling_suggestions(HashMap::from([("xxb".to_string(), "xb".to_string())]))
+        .with_misspelled_words(HashSet::from(["xxb", "xxa"]));
+        assert_eq!(diag.build_message(), "misspelled words: xxa, xxb (xb?)");
+    }
+
     #[test]
     fn test_diagnostic_line_serialize_byte_to_char_positions() {
         // "café" = 'c'(1B) 'a'(1B) 'f'(1B) 'é'(2B): 5 bytes, 4 chars.
         // Highlight bytes (2, 5) cover "fé"; chars (2, 4) — "ca"=2 and "café"=4.
         let line = DiagnosticLine {
             line_number: 7,
+            column: 4,
             message: "café".to_string(),
             highlights: vec![(2, 5)],
         };
         let v = serde_json::to_value(&line).expect("DiagnosticLine should serialize");
         assert_eq!(v["line_number"], 7);
+        assert_eq!(v["column"], 4);
         assert_eq!(v["message"], "café");
         assert_eq!(v["highlights"], serde_json::json!([[2, 4]]));
     }
@@ -535,6 +801,7 @@ mod tests {
     fn test_diagnostic_line_serialize_no_highlights() {
         let line = DiagnosticLine {
             line_number: 3,
+            column: 1,
             message: "hello".to_string(),
             highlights: vec![],
         };
@@ -542,6 +809,21 @@ mod tests {
         assert_eq!(v["highlights"], serde_json::json!([]));
     }
 
+    #[test]
+    fn test_diagnostic_serialize_msgctxt() {
+        let mut diag = Diagnostic::new(
+            Path::new("fr.po"),
+            "blank",
+            Severity::Warning,
+            "blank translation".to_string(),
+        );
+        let v = serde_json::to_value(&diag).expect("Diagnostic should serialize");
+        assert!(v.get("msgctxt").is_none());
+        diag.msgctxt = Some("menu".to_string());
+        let v = serde_json::to_value(&diag).expect("Diagnostic should serialize");
+        assert_eq!(v["msgctxt"], "menu");
+    }
+
     #[test]
     fn test_diagnostic_display_with_lines() {
         colored::control::set_override(false);
@@ -560,6 +842,113 @@ mod tests {
         assert!(s.contains("     11 | "));
     }
 
+    #[test]
+    fn test_diagnostic_display_no_color() {
+        crate::args::ColorChoice::Never.apply();
+        let msgid = Message::new(10, "hello", 0..0);
+        let msgstr = Message::new(11, "bonjour", 0..0);
+        let diag = Diagnostic::new(Path::new("fr.po"), "r", Severity::Error, "error").with_msgs_hl(
+            &msgid,
+            [(0, 5)],
+            &msgstr,
+            [(0, 7)],
+        );
+        let out = diag.to_string();
+        assert!(out.contains("[error:r] error"));
+        assert!(out.contains("     10 | hello"));
+        assert!(!out.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_diagnostic_display_hyperlink() {
+        colored::control::set_override(false);
+        let mut diag = Diagnostic::new(
+            Path::new("po/fr 1.po"),
+            "blank",
+            Severity::Warning,
+            "blank translation",
+        );
+        diag.add_line(5, "msgstr \" \"", []);
+        let out = diag.to_string();
+        assert!(out.starts_with("po/fr 1.po:5: [warning:blank] blank translation\n"));
+        assert!(!out.contains("\x1b]8;;"));
+        let out = diag.display(Some(Path::new("/project"))).to_string();
+        assert!(out.starts_with(
+            "\x1b]8;;file:///project/po/fr%201.po\x1b\\po/fr 1.po:5\x1b]8;;\x1b\\: \
+             [warning:blank] blank translation\n"
+        ));
+        // Absolute path: the base directory is not used.
+        diag.path = PathBuf::from("/other/fr.po");
+        let out = diag.display(Some(Path::new("/project"))).to_string();
+        assert!(out.starts_with("\x1b]8;;file:///other/fr.po\x1b\\/other/fr.po:5\x1b]8;;\x1b\\: "));
+    }
+
+    /// Build a diagnostic with highlights on multi-byte characters, on a message with
+    /// two lines.
+    fn diag_multibyte_hl() -> Diagnostic {
+        let mut diag = Diagnostic::new(Path::new("fr.po"), "r", Severity::Warning, "warning");
+        // "wörld" is at bytes 7..13, "日本" at bytes 17..23.
+        diag.add_line(5, "héllo wörld\n\tx 日本 é", [(7, 13), (17, 23)]);
+        diag
+    }
+
+    #[test]
+    fn test_diagnostic_display_highlight_color() {
+        colored::control::set_override(false);
+        let diag = diag_multibyte_hl();
+        let out = diag
+            .display(None)
+            .with_highlight_style(HighlightStyle::Color)
+            .to_string();
+        assert_eq!(out, diag.to_string());
+        assert!(out.contains("      5 | héllo wörld\n        | \tx 日本 é\n        |\n"));
+    }
+
+    #[test]
+    fn test_diagnostic_display_highlight_carets() {
+        colored::control::set_override(false);
+        let out = diag_multibyte_hl()
+            .display(None)
+            .with_highlight_style(HighlightStyle::Carets)
+            .to_string();
+        assert!(out.contains(
+            "      5 | héllo wörld\n\
+             \x20       |       ^^^^^\n\
+             \x20       | \tx 日本 é\n\
+             \x20       | \t  ^^^^\n"
+        ));
+    }
+
+    #[test]
+    fn test_diagnostic_display_highlight_brackets() {
+        colored::control::set_override(false);
+        let out = diag_multibyte_hl()
+            .display(None)
+            .with_highlight_style(HighlightStyle::Brackets)
+            .to_string();
+        assert!(out.contains("      5 | héllo [[wörld]]\n        | \tx [[日本]] é\n"));
+    }
+
+    #[test]
+    fn test_carets_line() {
+        // Highlight across two lines: only the part in the line is underlined.
+        assert_eq!(
+            DiagnosticLine::carets_line("ça va", 0, &[(3, 100)]),
+            Some("  ^^^".to_string())
+        );
+        assert_eq!(
+            DiagnosticLine::carets_line("ça va", 10, &[(3, 13)]),
+            Some("^^".to_string())
+        );
+        // Empty highlight: one caret at its position.
+        assert_eq!(
+            DiagnosticLine::carets_line("ça va", 0, &[(6, 6)]),
+            Some("     ^".to_string())
+        );
+        assert_eq!(DiagnosticLine::carets_line("ça va", 10, &[(3, 3)]), None);
+        assert_eq!(DiagnosticLine::carets_line("ça va", 0, &[(20, 25)]), None);
+    }
+
     #[test]
     fn test_diagnostic_display_no_lines() {
         colored::control::set_override(false);
@@ -5,8 +5,10 @@
 //! Dictionary for spell checking in PO files.
 
 use std::{
+    collections::HashMap,
     error::Error,
     path::{Path, PathBuf},
+    sync::{Arc, Mutex, OnceLock},
 };
 
 use spellbook::Dictionary;
@@ -14,6 +16,29 @@ use spellbook::Dictionary;
 pub const DEFAULT_PATH_DICTS: &str = "/usr/share/hunspell";
 pub const DEFAULT_LANG_ID: &str = "en_US";
 
+/// Extensions of the files with words to add to a dictionary, in the order they are read.
+const WORDS_EXTENSIONS: [&str; 3] = ["dic", "txt", "json"];
+
+/// Name of the files with words to add to the dictionaries of all languages.
+const GLOBAL_WORDS_NAME: &str = "_global";
+
+/// Key of a dictionary in cache: path to dictionaries, path to words and language.
+type DictCacheKey = (PathBuf, Option<PathBuf>, String);
+
+/// Dictionary loaded (or error if it could not be loaded), shared by all files.
+type CachedDict = Arc<OnceLock<Result<Arc<Dictionary>, String>>>;
+
+/// Cache of dictionaries, so that each dictionary is loaded only once when
+/// checking multiple files.
+///
+/// The cache belongs to a single run (for example one `check` of all files): the
+/// next run creates a new cache, so that changes in the dictionaries and the files
+/// with words are taken into account.
+#[derive(Default)]
+pub struct DictCache {
+    dicts: Mutex<HashMap<DictCacheKey, CachedDict>>,
+}
+
 /// Get the dictionary with its name.
 fn get_dict_name(path: &Path, name: &str) -> Option<Dictionary> {
     if let Ok(aff) = std::fs::read_to_string(format!("{}/{name}.aff", path.to_string_lossy()))
@@ -25,57 +50,140 @@ fn get_dict_name(path: &Path, name: &str) -> Option<Dictionary> {
     }
 }
 
-/// Add words to a dictionary.
-fn add_words_to_dict(path: &Path, language: &str, dict: &mut Dictionary) {
-    if let Ok(words) =
-        std::fs::read_to_string(format!("{}/{}.dic", path.to_string_lossy(), language))
-    {
-        for word in words.lines() {
-            dict.add(word).ok();
-        }
-    } else if let Some(pos) = language.find('_')
-        && let Ok(words) = std::fs::read_to_string(format!(
-            "{}/{}.dic",
-            path.to_string_lossy(),
-            &language[..pos]
-        ))
-    {
-        for word in words.lines() {
-            dict.add(word).ok();
+/// Read the words from a file, according to its extension:
+/// - `txt`: one word per line, empty lines and lines starting with `#` are ignored
+/// - `json`: array of strings
+/// - any other extension (`dic`): one word per line.
+///
+/// Return `None` if the file can not be read, and an error with the path of the
+/// file if the JSON content is invalid.
+fn read_words(path: &Path) -> Result<Option<Vec<String>>, Box<dyn Error>> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Ok(None);
+    };
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("txt") => Ok(Some(
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(String::from)
+                .collect(),
+        )),
+        Some("json") => serde_json::from_str(&content).map(Some).map_err(|err| {
+            format!(
+                "invalid words file (path: {}): {err}, spelling rule ignored",
+                path.to_string_lossy()
+            )
+            .into()
+        }),
+        _ => Ok(Some(content.lines().map(String::from).collect())),
+    }
+}
+
+/// Read the words from all files with this name (`<name>.dic`, `<name>.txt` and
+/// `<name>.json`), merged in this order.
+///
+/// Return `None` if none of these files could be read, and an error if a file is
+/// invalid (see [`read_words`]).
+fn read_words_files(path: &Path, name: &str) -> Result<Option<Vec<String>>, Box<dyn Error>> {
+    let mut found = false;
+    let mut words = vec![];
+    for ext in WORDS_EXTENSIONS {
+        if let Some(file_words) = read_words(&path.join(format!("{name}.{ext}")))? {
+            found = true;
+            words.extend(file_words);
         }
     }
+    Ok(found.then_some(words))
+}
+
+/// Add words to a dictionary.
+///
+/// The words are read from files with the complete language (e.g. `pt_BR.dic`,
+/// `pt_BR.txt` and `pt_BR.json`), or if none exists, from files with the language
+/// without country (e.g. `pt.dic`, `pt.txt` and `pt.json`).
+fn add_words_to_dict(
+    path: &Path,
+    language: &str,
+    dict: &mut Dictionary,
+) -> Result<(), Box<dyn Error>> {
+    let words = match (read_words_files(path, language)?, language.find('_')) {
+        (Some(words), _) => Some(words),
+        (None, Some(pos)) => read_words_files(path, &language[..pos])?,
+        (None, None) => None,
+    };
+    for word in words.unwrap_or_default() {
+        dict.add(&word).ok();
+    }
+    Ok(())
 }
 
 // Get the dictionary for a language (e.g. `fr` or `pt_BR`).
 //
 // Words are added to the dictionary if path_words is set and if a file with ignored words exists
-// in this directory.
+// in this directory, for this language or for all languages (`_global.dic`, `_global.txt` or
+// `_global.json`).
 pub fn get_dict(
     path_dicts: &Path,
     path_words: Option<&PathBuf>,
     language: &str,
 ) -> Result<Dictionary, Box<dyn Error>> {
-    // First look for the dictionary with complete language (e.g. `pt_BR`).
-    if let Some(mut dict) = get_dict_name(path_dicts, language) {
-        if let Some(path) = path_words {
-            add_words_to_dict(path.as_path(), language, &mut dict);
+    // First look for the dictionary with complete language (e.g. `pt_BR`),
+    // then with language without country (e.g. `pt`).
+    let Some(mut dict) = get_dict_name(path_dicts, language).or_else(|| {
+        language
+            .find('_')
+            .and_then(|pos| get_dict_name(path_dicts, &language[..pos]))
+    }) else {
+        return Err(format!(
+            "dictionary not found for language '{language}' (path: {}), spelling rule ignored",
+            path_dicts.to_string_lossy()
+        )
+        .into());
+    };
+    if let Some(path) = path_words {
+        add_words_to_dict(path.as_path(), language, &mut dict)?;
+        for word in read_words_files(path.as_path(), GLOBAL_WORDS_NAME)?.unwrap_or_default() {
+            dict.add(&word).ok();
         }
-        return Ok(dict);
     }
-    // Then look for the dictionary with language without country (e.g. `pt`).
-    if let Some(pos) = language.find('_')
-        && let Some(mut dict) = get_dict_name(path_dicts, &language[..pos])
-    {
-        if let Some(path) = path_words {
-            add_words_to_dict(path.as_path(), language, &mut dict);
-        }
-        return Ok(dict);
+    Ok(dict)
+}
+
+impl DictCache {
+    /// Get the dictionary for a language (see [`get_dict`]), loaded only once and
+    /// then shared by all files checked with this cache.
+    ///
+    /// If several threads ask for the same dictionary at the same time, it is loaded
+    /// by one of them while the others wait for it.
+    pub fn get(
+        &self,
+        path_dicts: &Path,
+        path_words: Option<&PathBuf>,
+        language: &str,
+    ) -> Result<Arc<Dictionary>, Box<dyn Error>> {
+        let key = (
+            path_dicts.to_path_buf(),
+            path_words.cloned(),
+            language.to_string(),
+        );
+        let cached = Arc::clone(
+            self.dicts
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .entry(key)
+                .or_default(),
+        );
+        cached
+            .get_or_init(|| {
+                get_dict(path_dicts, path_words, language)
+                    .map(Arc::new)
+                    .map_err(|err| err.to_string())
+            })
+            .clone()
+            .map_err(Into::into)
     }
-    Err(format!(
-        "dictionary not found for language '{language}' (path: {}), spelling rule ignored",
-        path_dicts.to_string_lossy()
-    )
-    .into())
 }
 
 #[cfg(test)]
@@ -141,7 +249,7 @@ mod tests {
 
         let tmp_words = tmp_dir("words-full-words");
         std::fs::write(tmp_words.path().join("en_US.dic"), "zzcustom\n").expect("write words file");
-        add_words_to_dict(tmp_words.path(), "en_US", &mut dict);
+        add_words_to_dict(tmp_words.path(), "en_US", &mut dict).expect("add words");
         assert!(dict.check("zzcustom"));
     }
 
@@ -155,7 +263,7 @@ mod tests {
         let tmp_words = tmp_dir("words-fallback-words");
         // Only the language-root file exists; `pt_BR.dic` is absent.
         std::fs::write(tmp_words.path().join("pt.dic"), "zzbrword\n").expect("write words file");
-        add_words_to_dict(tmp_words.path(), "pt_BR", &mut dict);
+        add_words_to_dict(tmp_words.path(), "pt_BR", &mut dict).expect("add words");
         assert!(dict.check("zzbrword"));
     }
 
@@ -167,12 +275,98 @@ mod tests {
 
         let tmp_words = tmp_dir("words-none-words");
         // Empty directory: no `en_US.dic` nor `en.dic`.
-        add_words_to_dict(tmp_words.path(), "en_US", &mut dict);
+        add_words_to_dict(tmp_words.path(), "en_US", &mut dict).expect("add words");
         // Original word is still recognized; nothing else got added.
         assert!(dict.check("hello"));
         assert!(!dict.check("zznothingadded"));
     }
 
+    #[test]
+    fn test_read_words_formats() {
+        let tmp = tmp_dir("read-words");
+        let dic = tmp.path().join("fr.dic");
+        let txt = tmp.path().join("fr.txt");
+        let json = tmp.path().join("fr.json");
+        let invalid_json = tmp.path().join("de.json");
+        std::fs::write(&dic, "alpha\nbeta\n").expect("write .dic");
+        std::fs::write(&txt, "# comment\n\n  gamma  \ndelta\n").expect("write .txt");
+        std::fs::write(&json, r#"["epsilon", "zeta"]"#).expect("write .json");
+        std::fs::write(&invalid_json, r#"{"word": "eta"}"#).expect("write .json");
+        assert_eq!(
+            read_words(&dic).unwrap(),
+            Some(vec!["alpha".into(), "beta".into()])
+        );
+        assert_eq!(
+            read_words(&txt).unwrap(),
+            Some(vec!["gamma".into(), "delta".into()])
+        );
+        assert_eq!(
+            read_words(&json).unwrap(),
+            Some(vec!["epsilon".into(), "zeta".into()])
+        );
+        let err = read_words(&invalid_json).expect_err("invalid JSON is an error");
+        assert!(err.to_string().contains("de.json"));
+        assert_eq!(read_words(&tmp.path().join("missing.txt")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_add_words_to_dict_merges_all_formats() {
+        let tmp_dicts = tmp_dir("words-merge-dict");
+        write_dict(tmp_dicts.path(), "pt", &["base"]);
+        let mut dict = get_dict_name(tmp_dicts.path(), "pt").expect("base dict");
+
+        let tmp_words = tmp_dir("words-merge-words");
+        std::fs::write(tmp_words.path().join("pt_BR.dic"), "zzdic\n").expect("write .dic");
+        std::fs::write(tmp_words.path().join("pt_BR.txt"), "# glossary\nzztxt\n")
+            .expect("write .txt");
+        std::fs::write(tmp_words.path().join("pt_BR.json"), r#"["zzjson"]"#).expect("write .json");
+        // Ignored: files with the complete language exist.
+        std::fs::write(tmp_words.path().join("pt.txt"), "zzroot\n").expect("write .txt");
+        add_words_to_dict(tmp_words.path(), "pt_BR", &mut dict).expect("add words");
+        assert!(dict.check("zzdic"));
+        assert!(dict.check("zztxt"));
+        assert!(dict.check("zzjson"));
+        assert!(!dict.check("zzroot"));
+        assert!(!dict.check("glossary"));
+    }
+
+    #[test]
+    fn test_add_words_to_dict_falls_back_to_lang_root_json() {
+        let tmp_dicts = tmp_dir("words-fallback-json-dict");
+        write_dict(tmp_dicts.path(), "pt", &["base"]);
+        let mut dict = get_dict_name(tmp_dicts.path(), "pt").expect("base dict");
+
+        let tmp_words = tmp_dir("words-fallback-json-words");
+        std::fs::write(tmp_words.path().join("pt.json"), r#"["zzrootjson"]"#).expect("write .json");
+        add_words_to_dict(tmp_words.path(), "pt_BR", &mut dict).expect("add words");
+        assert!(dict.check("zzrootjson"));
+    }
+
+    #[test]
+    fn test_dict_cache_loaded_once() {
+        let tmp = tmp_dir("cached");
+        write_dict(tmp.path(), "en_US", &["hello"]);
+        let cache = DictCache::default();
+        let dict1 = cache.get(tmp.path(), None, "en_US").expect("dictionary");
+        // The dictionary files are removed: the dictionary is not loaded again.
+        std::fs::remove_file(tmp.path().join("en_US.dic")).expect("remove .dic");
+        let dict2 = cache.get(tmp.path(), None, "en_US").expect("dictionary");
+        assert!(Arc::ptr_eq(&dict1, &dict2));
+        assert!(dict2.check("hello"));
+        // Errors are cached as well.
+        let err1 = cache.get(tmp.path(), None, "fr").unwrap_err();
+        write_dict(tmp.path(), "fr", &["bonjour"]);
+        let err2 = cache.get(tmp.path(), None, "fr").unwrap_err();
+        assert_eq!(err1.to_string(), err2.to_string());
+        // Different words: another dictionary.
+        let dict3 = cache.get(tmp.path(), Some(&tmp.path().join("words")), "en_US");
+        assert!(dict3.is_err());
+        // A new cache (next run) loads the dictionaries again.
+        let cache = DictCache::default();
+        assert!(cache.get(tmp.path(), None, "en_US").is_err());
+        assert!(cache.get(tmp.path(), None, "fr").is_ok());
+    }
+
     #[test]
     fn test_get_dict_finds_full_language() {
         let tmp = tmp_dir("get-full");
@@ -234,6 +428,18 @@ mod tests {
         assert!(dict.check("zzextra"));
     }
 
+    #[test]
+    fn test_get_dict_invalid_words_file() {
+        let tmp_dicts = tmp_dir("invalid-words-dicts");
+        write_dict(tmp_dicts.path(), "en_US", &["seed"]);
+        let tmp_words = tmp_dir("invalid-words-words");
+        std::fs::write(tmp_words.path().join("en_US.json"), "[\"zzword\",").expect("write .json");
+        let words_path = PathBuf::from(tmp_words.path());
+        let err = get_dict(tmp_dicts.path(), Some(&words_path), "en_US")
+            .expect_err("invalid words file is an error");
+        assert!(err.to_string().contains("en_US.json"));
+    }
+
     #[test]
     fn test_get_dict_augments_with_path_words_via_fallback() {
         // Dictionary loaded via the root-language fallback should still get augmented.
@@ -250,4 +456,29 @@ mod tests {
         assert!(dict.check("seed"));
         assert!(dict.check("zzbrextra"));
     }
+
+    #[test]
+    fn test_get_dict_adds_global_words_to_all_languages() {
+        let tmp_dicts = tmp_dir("global-dicts");
+        write_dict(tmp_dicts.path(), "en_US", &["hello"]);
+        write_dict(tmp_dicts.path(), "fr", &["bonjour"]);
+
+        let tmp_words = tmp_dir("global-words");
+        std::fs::write(tmp_words.path().join("_global.dic"), "poexam\n").expect("write words file");
+        std::fs::write(tmp_words.path().join("_global.json"), r#"["msgid"]"#)
+            .expect("write words file");
+        std::fs::write(tmp_words.path().join("fr.dic"), "zzfrword\n").expect("write words file");
+
+        let words_path = PathBuf::from(tmp_words.path());
+        let dict_id = get_dict(tmp_dicts.path(), Some(&words_path), "en_US").expect("dict");
+        let dict_str = get_dict(tmp_dicts.path(), Some(&words_path), "fr").expect("dict");
+        for dict in [&dict_id, &dict_str] {
+            assert!(dict.check("poexam"));
+            assert!(dict.check("msgid"));
+            assert!(!dict.check("zzmisspelled"));
+        }
+        // Words of a language are not global.
+        assert!(dict_str.check("zzfrword"));
+        assert!(!dict_id.check("zzfrword"));
+    }
 }
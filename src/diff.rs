@@ -0,0 +1,251 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Lines changed in a unified diff, used to report only diagnostics on changed lines.
+
+use std::{
+    collections::HashMap,
+    io::Read,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::diagnostic::Diagnostic;
+
+/// Lines added or modified in a unified diff: path of the new file → ranges of
+/// line numbers (inclusive).
+#[derive(Debug, Default)]
+pub struct ChangedLines {
+    files: HashMap<PathBuf, Vec<(usize, usize)>>,
+}
+
+impl ChangedLines {
+    /// Read a unified diff from a file, or from standard input if the path is `-`.
+    pub fn from_path(path: &Path) -> std::io::Result<Self> {
+        let mut content = String::new();
+        if path == Path::new("-") {
+            std::io::stdin().read_to_string(&mut content)?;
+        } else {
+            content = std::fs::read_to_string(path)?;
+        }
+        Ok(Self::parse(&content))
+    }
+
+    /// Parse a unified diff (as displayed by `git diff` or `diff -u`).
+    ///
+    /// Only the added lines (`+`) are kept, with their line number in the new file;
+    /// removed and context lines are ignored.
+    ///
+    /// A hunk ends when the number of old and new lines declared in its header have
+    /// been read; a malformed hunk with more lines than declared is ended early.
+    pub fn parse(diff: &str) -> Self {
+        let mut changed = Self::default();
+        let mut path: Option<PathBuf> = None;
+        let mut line_number = 0;
+        let mut remaining_old: usize = 0;
+        let mut remaining_new: usize = 0;
+        for line in diff.lines() {
+            if remaining_old > 0 || remaining_new > 0 {
+                match line.chars().next() {
+                    Some('+') => {
+                        if let Some(path) = &path {
+                            changed.add_line(path, line_number);
+                        }
+                        line_number += 1;
+                        remaining_new = remaining_new.saturating_sub(1);
+                    }
+                    Some('-') => remaining_old = remaining_old.saturating_sub(1),
+                    Some('\\') => {}
+                    _ => {
+                        line_number += 1;
+                        remaining_old = remaining_old.saturating_sub(1);
+                        remaining_new = remaining_new.saturating_sub(1);
+                    }
+                }
+            } else if let Some(new_path) = line.strip_prefix("+++ ") {
+                path = parse_diff_path(new_path);
+            } else if let Some(range) = line.strip_prefix("@@ ")
+                && let Some((old, new)) = parse_hunk_header(range)
+            {
+                line_number = new.0;
+                remaining_old = old.1;
+                remaining_new = new.1;
+            }
+        }
+        changed
+    }
+
+    /// Add a changed line for a file, extending the last range if the line follows it.
+    fn add_line(&mut self, path: &Path, line_number: usize) {
+        let ranges = self.files.entry(path.to_path_buf()).or_default();
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == line_number => *end = line_number,
+            _ => ranges.push((line_number, line_number)),
+        }
+    }
+
+    /// Return the changed line ranges of a file: the diff path and the file path
+    /// match if one ends with the other (e.g. `po/fr.po` and `./project/po/fr.po`).
+    fn file_ranges(&self, path: &Path) -> Option<&[(usize, usize)]> {
+        let path = normalize_path(path);
+        self.files
+            .iter()
+            .find(|(diff_path, _)| path.ends_with(diff_path) || diff_path.ends_with(&path))
+            .map(|(_, ranges)| ranges.as_slice())
+    }
+
+    /// Return `true` if any line of the diagnostic is a changed line (for example
+    /// the msgstr of an entry when the diagnostic starts on its msgid).
+    ///
+    /// A diagnostic without line number (e.g. an error reading the file) is kept
+    /// if the file has changed lines.
+    pub fn contains_diagnostic(&self, diag: &Diagnostic) -> bool {
+        let Some(ranges) = self.file_ranges(&diag.path) else {
+            return false;
+        };
+        let mut line_numbers = diag
+            .lines
+            .iter()
+            .map(|line| line.line_number)
+            .filter(|line_number| *line_number > 0)
+            .peekable();
+        line_numbers.peek().is_none()
+            || line_numbers.any(|line_number| {
+                ranges
+                    .iter()
+                    .any(|(start, end)| (*start..=*end).contains(&line_number))
+            })
+    }
+}
+
+/// Parse the path of a file in the header of a unified diff (`+++ b/po/fr.po`),
+/// `None` for a deleted file (`/dev/null`).
+fn parse_diff_path(s: &str) -> Option<PathBuf> {
+    // Optional timestamp after a tab (`diff -u`).
+    let s = s.split('\t').next().unwrap_or_default().trim_end();
+    if s == "/dev/null" {
+        return None;
+    }
+    let s = s.strip_prefix("b/").unwrap_or(s);
+    Some(normalize_path(Path::new(s)))
+}
+
+/// Parse a hunk header (after `@@ `): `-1,5 +1,6 @@`, returning the start line and
+/// number of lines of the old and new files.
+fn parse_hunk_header(s: &str) -> Option<((usize, usize), (usize, usize))> {
+    let mut items = s.split_whitespace();
+    let old = parse_hunk_range(items.next()?.strip_prefix('-')?)?;
+    let new = parse_hunk_range(items.next()?.strip_prefix('+')?)?;
+    Some((old, new))
+}
+
+/// Parse a range of a hunk header: `start,count` or `start` (count is 1).
+fn parse_hunk_range(s: &str) -> Option<(usize, usize)> {
+    match s.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((s.parse().ok()?, 1)),
+    }
+}
+
+/// Remove the `.` components of a path (`./po/fr.po` → `po/fr.po`).
+fn normalize_path(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| *component != Component::CurDir)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::Severity;
+
+    const DIFF: &str = "\
+diff --git a/po/fr.po b/po/fr.po
+index 1234567..89abcde 100644
+--- a/po/fr.po
++++ b/po/fr.po
+@@ -10,5 +10,7 @@ msgstr \"\"
+
+ msgid \"one\"
+-msgstr \"un\"
++msgstr \"une\"
++
++msgid \"two\"
+ msgstr \"deux\"
+
+@@ -40 +41 @@
+-msgstr \"trois\"
++msgstr \"Trois\"
+diff --git a/po/de.po b/po/de.po
+deleted file mode 100644
+--- a/po/de.po
++++ /dev/null
+@@ -1,2 +0,0 @@
+-msgid \"one\"
+-msgstr \"eins\"
+";
+
+    fn diag(path: &str, line_numbers: &[usize]) -> Diagnostic {
+        let mut diag = Diagnostic::new(Path::new(path), "test", Severity::Error, "msg");
+        for line_number in line_numbers {
+            diag.add_line(*line_number, "msgstr \"x\"", []);
+        }
+        diag
+    }
+
+    #[test]
+    fn test_parse() {
+        let changed = ChangedLines::parse(DIFF);
+        assert_eq!(changed.files.len(), 1);
+        assert_eq!(
+            changed.files.get(Path::new("po/fr.po")),
+            Some(&vec![(12, 14), (41, 41)])
+        );
+        assert!(ChangedLines::parse("").files.is_empty());
+    }
+
+    #[test]
+    fn test_parse_malformed_hunk() {
+        // More removed lines than declared in the hunk header: the hunk ends when
+        // both counters reach 0, and the next file is still parsed.
+        let changed = ChangedLines::parse(
+            "\
+--- a/po/fr.po
++++ b/po/fr.po
+@@ -1,0 +1,2 @@
+-y
+-z
++a
++b
+--- a/po/de.po
++++ b/po/de.po
+@@ -5 +5 @@
+-x
++w
+",
+        );
+        assert_eq!(
+            changed.files.get(Path::new("po/fr.po")),
+            Some(&vec![(1, 2)])
+        );
+        assert_eq!(
+            changed.files.get(Path::new("po/de.po")),
+            Some(&vec![(5, 5)])
+        );
+    }
+
+    #[test]
+    fn test_contains_diagnostic() {
+        let changed = ChangedLines::parse(DIFF);
+        assert!(changed.contains_diagnostic(&diag("po/fr.po", &[12])));
+        assert!(changed.contains_diagnostic(&diag("./po/fr.po", &[14])));
+        assert!(changed.contains_diagnostic(&diag("project/po/fr.po", &[41])));
+        assert!(changed.contains_diagnostic(&diag("po/fr.po", &[11, 0, 12])));
+        assert!(changed.contains_diagnostic(&diag("po/fr.po", &[])));
+        assert!(!changed.contains_diagnostic(&diag("po/fr.po", &[11])));
+        assert!(!changed.contains_diagnostic(&diag("po/fr.po", &[15, 0, 16])));
+        assert!(!changed.contains_diagnostic(&diag("po/fr.po", &[40])));
+        assert!(!changed.contains_diagnostic(&diag("po/de.po", &[1])));
+        assert!(!changed.contains_diagnostic(&diag("po/de.po", &[])));
+    }
+}
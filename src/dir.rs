@@ -5,16 +5,33 @@
 //! Directory utilities.
 
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 use colored::Colorize;
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use ignore::WalkBuilder;
+use path_absolutize::Absolutize;
 
-/// Recursively find all gettext files (matching the `*.po` pattern) under the given paths.
+/// Return `true` if the path matches one of the exclude patterns.
 ///
-/// The .gitignore rules are respected: ignored files are skipped.
-pub fn find_po_files(paths: &[PathBuf]) -> HashSet<PathBuf> {
+/// The patterns are matched against the path relative to the root it was found under.
+fn is_excluded(path: &Path, roots: &[PathBuf], exclude: &GlobSet) -> bool {
+    let relative = roots
+        .iter()
+        .find_map(|root| path.strip_prefix(root).ok())
+        .unwrap_or(path);
+    exclude.is_match(relative)
+}
+
+/// Recursively find all gettext files (matching the `*.po` pattern, and `*.pot` if
+/// `templates` is `true`) under the given paths.
+///
+/// The .gitignore rules are respected: ignored files are skipped, as well as files
+/// and directories matching one of the `exclude` glob patterns (paths given
+/// explicitly are never excluded).
+pub fn find_po_files(paths: &[PathBuf], exclude: &[Glob], templates: bool) -> HashSet<PathBuf> {
     let all_paths: Vec<PathBuf> = if paths.is_empty() {
         vec![PathBuf::from(".")]
     } else {
@@ -28,6 +45,16 @@ pub fn find_po_files(paths: &[PathBuf]) -> HashSet<PathBuf> {
 
     builder.follow_links(false);
 
+    if !exclude.is_empty() {
+        let mut globs = GlobSetBuilder::new();
+        for glob in exclude {
+            globs.add(glob.clone());
+        }
+        let globs = globs.build().unwrap_or_else(|_| GlobSet::empty());
+        let roots = all_paths.clone();
+        builder.filter_entry(move |dirent| !is_excluded(dirent.path(), &roots, &globs));
+    }
+
     let files = Arc::new(Mutex::new(HashSet::new()));
     builder.build_parallel().run(|| {
         let files = Arc::clone(&files);
@@ -35,7 +62,7 @@ pub fn find_po_files(paths: &[PathBuf]) -> HashSet<PathBuf> {
             match entry {
                 Ok(dirent) => {
                     if dirent.file_type().is_some_and(|ft| ft.is_file())
-                        && dirent.path().extension().is_some_and(|ext| ext == "po")
+                        && is_po_file(dirent.path(), templates)
                     {
                         let mut files = files.lock().unwrap();
                         files.insert(
@@ -57,10 +84,91 @@ pub fn find_po_files(paths: &[PathBuf]) -> HashSet<PathBuf> {
     files.lock().unwrap().clone()
 }
 
+/// Return `true` if the path is a gettext file (`*.po`, and `*.pot` if `templates`
+/// is `true`).
+fn is_po_file(path: &Path, templates: bool) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext == "po" || (templates && ext == "pot"))
+}
+
+/// Read the gettext files listed in `files_from` (one path per line, `-` for standard
+/// input), for example the files changed in a CI job.
+///
+/// Blank lines and files that are not gettext files are ignored, as well as files
+/// matching one of the `exclude` glob patterns. The directories are not searched.
+pub fn read_po_files_from(
+    files_from: &Path,
+    exclude: &[Glob],
+    templates: bool,
+) -> Result<HashSet<PathBuf>, std::io::Error> {
+    let reader: Box<dyn BufRead> = if files_from == Path::new("-") {
+        Box::new(std::io::stdin().lock())
+    } else {
+        Box::new(BufReader::new(std::fs::File::open(files_from)?))
+    };
+    let mut globs = GlobSetBuilder::new();
+    for glob in exclude {
+        globs.add(glob.clone());
+    }
+    let globs = globs.build().unwrap_or_else(|_| GlobSet::empty());
+    let mut files = HashSet::new();
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let path = Path::new(line);
+        let path = path.strip_prefix("./").unwrap_or(path);
+        if is_po_file(path, templates) && !is_excluded(path, &[], &globs) {
+            files.insert(path.to_path_buf());
+        }
+    }
+    Ok(files)
+}
+
+/// Get the gettext files to process: the files listed in `files_from` if set (see
+/// [`read_po_files_from`]), otherwise the files found under `paths` (see
+/// [`find_po_files`]).
+///
+/// An error is displayed and `None` is returned if the list of files can not be read.
+pub fn get_po_files(
+    paths: &[PathBuf],
+    files_from: Option<&Path>,
+    exclude: &[Glob],
+    templates: bool,
+) -> Option<HashSet<PathBuf>> {
+    let Some(files_from) = files_from else {
+        return Some(find_po_files(paths, exclude, templates));
+    };
+    match read_po_files_from(files_from, exclude, templates) {
+        Ok(files) => Some(files),
+        Err(err) => {
+            eprintln!(
+                "{}: could not read list of files (path: {}): {err}",
+                "Error".red(),
+                files_from.display()
+            );
+            None
+        }
+    }
+}
+
+/// Return `path` relative to the directory `base`.
+///
+/// If `path` is not inside `base`, its absolute path is returned.
+pub fn relative_path(path: &Path, base: &Path) -> PathBuf {
+    let (Ok(abs_path), Ok(abs_base)) = (path.absolutize(), base.absolutize()) else {
+        return path.to_path_buf();
+    };
+    match abs_path.strip_prefix(&abs_base) {
+        Ok(relative) if !relative.as_os_str().is_empty() => relative.to_path_buf(),
+        _ => abs_path.to_path_buf(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
-
     use super::*;
 
     fn tmp_dir(label: &str) -> tempfile::TempDir {
@@ -77,7 +185,7 @@ mod tests {
     #[test]
     fn test_empty_dir_returns_empty_set() {
         let tmp = tmp_dir("empty");
-        let found = find_po_files(&[tmp.path().to_path_buf()]);
+        let found = find_po_files(&[tmp.path().to_path_buf()], &[], false);
         assert!(found.is_empty());
     }
 
@@ -86,7 +194,7 @@ mod tests {
         let tmp = tmp_dir("single");
         let po = tmp.path().join("fr.po");
         touch(&po);
-        let found = find_po_files(&[tmp.path().to_path_buf()]);
+        let found = find_po_files(&[tmp.path().to_path_buf()], &[], false);
         assert_eq!(found.len(), 1);
         assert!(found.contains(&po));
     }
@@ -99,10 +207,22 @@ mod tests {
         touch(&tmp.path().join("a.pot"));
         touch(&tmp.path().join("a.txt"));
         touch(&tmp.path().join("notes.md"));
-        let found = find_po_files(&[tmp.path().to_path_buf()]);
+        let found = find_po_files(&[tmp.path().to_path_buf()], &[], false);
         assert_eq!(found, std::iter::once(po).collect::<HashSet<_>>());
     }
 
+    #[test]
+    fn test_pot_extension_returned_with_templates() {
+        let tmp = tmp_dir("ext-templates");
+        let po = tmp.path().join("a.po");
+        let pot = tmp.path().join("a.pot");
+        touch(&po);
+        touch(&pot);
+        touch(&tmp.path().join("a.txt"));
+        let found = find_po_files(&[tmp.path().to_path_buf()], &[], true);
+        assert_eq!(found, [po, pot].into_iter().collect::<HashSet<_>>());
+    }
+
     #[test]
     fn test_recursive_search() {
         let tmp = tmp_dir("recursive");
@@ -110,7 +230,7 @@ mod tests {
         let nested = tmp.path().join("sub/deep/nested.po");
         touch(&a);
         touch(&nested);
-        let found = find_po_files(&[tmp.path().to_path_buf()]);
+        let found = find_po_files(&[tmp.path().to_path_buf()], &[], false);
         assert!(found.contains(&a));
         assert!(found.contains(&nested));
         assert_eq!(found.len(), 2);
@@ -124,7 +244,11 @@ mod tests {
         let b = tmp_b.path().join("b.po");
         touch(&a);
         touch(&b);
-        let found = find_po_files(&[tmp_a.path().to_path_buf(), tmp_b.path().to_path_buf()]);
+        let found = find_po_files(
+            &[tmp_a.path().to_path_buf(), tmp_b.path().to_path_buf()],
+            &[],
+            false,
+        );
         assert!(found.contains(&a));
         assert!(found.contains(&b));
         assert_eq!(found.len(), 2);
@@ -145,8 +269,127 @@ mod tests {
         // .gitignore in the walk root excludes the subtree.
         std::fs::write(tmp.path().join(".gitignore"), "ignored/\n").expect("write .gitignore");
 
-        let found = find_po_files(&[tmp.path().to_path_buf()]);
+        let found = find_po_files(&[tmp.path().to_path_buf()], &[], false);
         assert!(found.contains(&visible));
         assert!(!found.contains(&ignored));
     }
+
+    #[test]
+    fn test_exclude_patterns_skip_matching_files() {
+        let tmp = tmp_dir("exclude");
+        let kept = tmp.path().join("po/fr.po");
+        let built = tmp.path().join("docs/_build/locale/fr.po");
+        let other = tmp.path().join("tmp/de.po");
+        touch(&kept);
+        touch(&built);
+        touch(&other);
+        let exclude = vec![
+            Glob::new("**/_build/**/*.po").expect("valid glob"),
+            Glob::new("tmp/*").expect("valid glob"),
+        ];
+        let found = find_po_files(&[tmp.path().to_path_buf()], &exclude, false);
+        assert_eq!(found, std::iter::once(kept).collect::<HashSet<_>>());
+    }
+
+    #[test]
+    fn test_relative_path_inside_base() {
+        let base = Path::new("/project");
+        assert_eq!(
+            relative_path(Path::new("/project/po/fr.po"), base),
+            PathBuf::from("po/fr.po")
+        );
+        assert_eq!(
+            relative_path(Path::new("/project/po/../fr.po"), base),
+            PathBuf::from("fr.po")
+        );
+        // Relative paths are resolved from the current directory.
+        let cwd = std::env::current_dir().expect("current dir");
+        assert_eq!(
+            relative_path(Path::new("po/fr.po"), Path::new(".")),
+            PathBuf::from("po/fr.po")
+        );
+        assert_eq!(
+            relative_path(Path::new("./po/fr.po"), &cwd),
+            PathBuf::from("po/fr.po")
+        );
+        assert_eq!(
+            relative_path(&cwd.join("po/fr.po"), Path::new("po")),
+            PathBuf::from("fr.po")
+        );
+    }
+
+    #[test]
+    fn test_relative_path_outside_base() {
+        let base = Path::new("/project/po");
+        assert_eq!(
+            relative_path(Path::new("/other/fr.po"), base),
+            PathBuf::from("/other/fr.po")
+        );
+        assert_eq!(
+            relative_path(Path::new("/project/po/../fr.po"), base),
+            PathBuf::from("/project/fr.po")
+        );
+        assert_eq!(
+            relative_path(Path::new("/project/po"), base),
+            PathBuf::from("/project/po")
+        );
+        let cwd = std::env::current_dir().expect("current dir");
+        assert_eq!(
+            relative_path(Path::new("fr.po"), Path::new("/nonexistent/dir")),
+            cwd.join("fr.po")
+        );
+    }
+
+    #[test]
+    fn test_exclude_pattern_relative_to_root() {
+        let tmp = tmp_dir("exclude-root");
+        let po = tmp.path().join("fr.po");
+        touch(&po);
+        // The pattern is matched against the path relative to the root, so the
+        // absolute path of the temp dir does not have to match.
+        let exclude = vec![Glob::new("fr.po").expect("valid glob")];
+        assert!(find_po_files(&[tmp.path().to_path_buf()], &exclude, false).is_empty());
+        // A file given on command line is never excluded.
+        let exclude = vec![Glob::new("**/fr.po").expect("valid glob")];
+        assert_eq!(
+            find_po_files(std::slice::from_ref(&po), &exclude, false).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_read_po_files_from() {
+        let tmp = tmp_dir("files-from");
+        let fr = tmp.path().join("po/fr.po");
+        let de = tmp.path().join("po/de.po");
+        let build = tmp.path().join("_build/fr.po");
+        touch(&fr);
+        touch(&de);
+        touch(&build);
+        // Only the files listed are returned, the directory is not searched.
+        let manifest = tmp.path().join("files.txt");
+        std::fs::write(
+            &manifest,
+            format!(
+                "{}\n\n{}\n{}\n{}\n",
+                fr.display(),
+                tmp.path().join("po/messages.pot").display(),
+                tmp.path().join("README.md").display(),
+                build.display(),
+            ),
+        )
+        .expect("write manifest");
+        let exclude = vec![Glob::new("**/_build/**").expect("valid glob")];
+        let found = read_po_files_from(&manifest, &exclude, false).expect("read manifest");
+        assert_eq!(found, std::iter::once(fr.clone()).collect::<HashSet<_>>());
+        let found = read_po_files_from(&manifest, &[], true).expect("read manifest");
+        assert_eq!(
+            found,
+            [fr, tmp.path().join("po/messages.pot"), build]
+                .into_iter()
+                .collect::<HashSet<_>>()
+        );
+        assert!(read_po_files_from(&tmp.path().join("missing.txt"), &[], false).is_err());
+        assert!(get_po_files(&[], Some(&tmp.path().join("missing.txt")), &[], false).is_none());
+    }
 }
@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `JUnit` XML output.
+
+use std::fmt::Write;
+
+use crate::checker::CheckFileResult;
+use crate::diagnostic::Diagnostic;
+
+/// Escape a string to be used in XML text or attribute value.
+///
+/// Control characters that are not allowed in XML 1.0 are replaced by U+FFFD.
+fn xml_escape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            '\t' | '\n' | '\r' => result.push(c),
+            c if c.is_control() && c < ' ' => result.push('\u{FFFD}'),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Get the line number of a diagnostic (first line with a non-zero line number).
+fn diag_line_number(diag: &Diagnostic) -> usize {
+    diag.lines
+        .iter()
+        .find(|l| l.line_number > 0)
+        .map_or(0, |l| l.line_number)
+}
+
+/// Build a `JUnit` XML document from check results.
+///
+/// Each checked file is a test case, and each diagnostic is a failure of the test
+/// case; files without diagnostics are passing test cases.
+pub fn build_junit(result: &[CheckFileResult]) -> String {
+    let tests = result.len();
+    let failures = result.iter().filter(|f| !f.diagnostics.is_empty()).count();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"{name}\" tests=\"{tests}\" failures=\"{failures}\">",
+        name = env!("CARGO_PKG_NAME"),
+    );
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"{name}\" tests=\"{tests}\" failures=\"{failures}\">",
+        name = env!("CARGO_PKG_NAME"),
+    );
+    for file in result {
+        let path = xml_escape(&file.path.display().to_string());
+        if file.diagnostics.is_empty() {
+            let _ = writeln!(xml, "    <testcase name=\"{path}\" classname=\"{path}\"/>");
+            continue;
+        }
+        let _ = writeln!(xml, "    <testcase name=\"{path}\" classname=\"{path}\">");
+        for diag in &file.diagnostics {
+            let message = xml_escape(&diag.build_message());
+            let _ = writeln!(
+                xml,
+                "      <failure message=\"[{rule}] {message}\" type=\"{severity:?}\">\
+                 {path}:{line}: [{rule}] {message}</failure>",
+                rule = diag.rule,
+                severity = diag.severity,
+                line = diag_line_number(diag),
+            );
+        }
+        xml.push_str("    </testcase>\n");
+    }
+    xml.push_str("  </testsuite>\n");
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+    use crate::diagnostic::Severity;
+    use crate::po::message::Message;
+
+    fn file_result(path: &str, diagnostics: Vec<Diagnostic>) -> CheckFileResult {
+        CheckFileResult {
+            path: PathBuf::from(path),
+            diagnostics,
+            ..CheckFileResult::default()
+        }
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(xml_escape(""), "");
+        assert_eq!(xml_escape("test"), "test");
+        assert_eq!(
+            xml_escape("<a href=\"x\">'&'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&apos;&amp;&apos;&lt;/a&gt;"
+        );
+        assert_eq!(xml_escape("a\tb\u{1}c"), "a\tb\u{FFFD}c");
+    }
+
+    #[test]
+    fn test_build_junit_empty() {
+        let xml = build_junit(&[]);
+        let doc = roxmltree::Document::parse(&xml).expect("valid XML");
+        let root = doc.root_element();
+        assert_eq!(root.tag_name().name(), "testsuites");
+        assert_eq!(root.attribute("tests"), Some("0"));
+        assert_eq!(root.attribute("failures"), Some("0"));
+    }
+
+    #[test]
+    fn test_build_junit_roundtrip() {
+        let diag1 = Diagnostic::new(
+            Path::new("po/fr.po"),
+            "brackets",
+            Severity::Warning,
+            "missing brackets '<>' (1 / 0)",
+        )
+        .with_msgs(
+            &Message::new(12, "test <x>", 0..0),
+            &Message::new(13, "test", 0..0),
+        );
+        let diag2 = Diagnostic::new(
+            Path::new("po/fr.po"),
+            "compilation",
+            Severity::Error,
+            "msgfmt error & \"details\"",
+        );
+        let result = vec![
+            file_result("po/de.po", vec![]),
+            file_result("po/fr.po", vec![diag1, diag2]),
+        ];
+        let xml = build_junit(&result);
+        let doc = roxmltree::Document::parse(&xml).expect("valid XML");
+        let root = doc.root_element();
+        assert_eq!(root.attribute("tests"), Some("2"));
+        assert_eq!(root.attribute("failures"), Some("1"));
+        let suite = root
+            .children()
+            .find(|n| n.has_tag_name("testsuite"))
+            .expect("testsuite");
+        let cases: Vec<_> = suite
+            .children()
+            .filter(|n| n.has_tag_name("testcase"))
+            .collect();
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[0].attribute("name"), Some("po/de.po"));
+        assert_eq!(
+            cases[0]
+                .children()
+                .filter(roxmltree::Node::is_element)
+                .count(),
+            0
+        );
+        assert_eq!(cases[1].attribute("name"), Some("po/fr.po"));
+        let failures: Vec<_> = cases[1]
+            .children()
+            .filter(|n| n.has_tag_name("failure"))
+            .collect();
+        assert_eq!(failures.len(), 2);
+        assert_eq!(
+            failures[0].attribute("message"),
+            Some("[brackets] missing brackets '<>' (1 / 0)")
+        );
+        assert_eq!(failures[0].attribute("type"), Some("Warning"));
+        assert_eq!(
+            failures[0].text(),
+            Some("po/fr.po:12: [brackets] missing brackets '<>' (1 / 0)")
+        );
+        assert_eq!(
+            failures[1].attribute("message"),
+            Some("[compilation] msgfmt error & \"details\"")
+        );
+        assert_eq!(failures[1].attribute("type"), Some("Error"));
+    }
+}
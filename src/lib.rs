@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Poexam library: check gettext PO content without running the `poexam` binary.
+//!
+//! The [`check_bytes`] function checks in-memory PO content with the given
+//! [`Config`] (the options of the `check` command in [`CheckConfig`], the severity
+//! overrides and the quotes, as read from a `poexam.toml` file by [`Config::new`]) and
+//! returns the list of [`Diagnostic`] found, without printing anything:
+//!
+//! ```
+//! use poexam::{Config, Severity, check_bytes};
+//!
+//! let content = b"msgid \"Hello\"\nmsgstr \"Bonjour \"\n";
+//! let mut config = Config::default();
+//! let diagnostics = check_bytes(content, &config);
+//! assert_eq!(diagnostics.len(), 1);
+//! assert_eq!(diagnostics[0].rule, "whitespace-end");
+//! assert_eq!(diagnostics[0].severity, Severity::Info);
+//!
+//! config.severity.insert("whitespace-end".to_string(), Severity::Error);
+//! assert_eq!(check_bytes(content, &config)[0].severity, Severity::Error);
+//! ```
+//!
+//! The PO parser is available too: [`Parser`] iterates on the [`Entry`] items of PO
+//! content, and [`Parser::from_path`] reads a PO file from disk.
+
+mod analyze;
+mod args;
+mod checker;
+mod config;
+mod diagnostic;
+mod dict;
+mod diff;
+mod dir;
+mod fix;
+mod junit;
+mod lsp;
+mod po;
+mod progress;
+mod result;
+mod rules;
+mod sarif;
+mod schema;
+mod stats;
+mod table;
+mod watch;
+
+use std::path::Path;
+
+use clap::Parser as _;
+
+use crate::analyze::run_analyze;
+use crate::args::{Cli, Command};
+use crate::checker::{run_check, run_fix};
+use crate::lsp::run_lsp;
+use crate::rules::rule::run_rules;
+use crate::schema::run_schema;
+use crate::stats::run_stats;
+
+pub use crate::config::{CheckConfig, Config};
+pub use crate::diagnostic::{Diagnostic, DiagnosticLine, Severity};
+pub use crate::po::entry::Entry;
+pub use crate::po::message::Message;
+pub use crate::po::parser::{OwnedParser, Parser};
+
+/// Check the PO content `data` with the given config and return the list of
+/// diagnostics found.
+///
+/// Nothing is printed: the diagnostics are returned in the order they are found, with an
+/// empty path. The rules are selected with `select` and `ignore` in `config.check`, and
+/// the severity overrides in `config.severity` are applied, as in the `check` command;
+/// an unknown rule is reported as a single `rules-error` diagnostic.
+#[must_use]
+pub fn check_bytes(data: &[u8], config: &Config) -> Vec<Diagnostic> {
+    checker::check_bytes(data, Path::new(""), config.clone())
+}
+
+/// Parse the command-line arguments, run the sub-command and return the exit code
+/// (used by the `poexam` binary).
+#[doc(hidden)]
+#[must_use]
+pub fn run() -> i32 {
+    let args = Cli::parse();
+    args.color.apply();
+    args::apply_jobs(args.jobs);
+    match &args.command {
+        Command::Check(args) => run_check(args),
+        Command::Fix(args) => run_fix(args),
+        Command::Rules(args) => run_rules(args),
+        Command::Stats(args) => run_stats(args),
+        Command::Analyze(args) => run_analyze(args),
+        Command::Lsp(args) => run_lsp(args),
+        Command::Schema(args) => run_schema(args),
+    }
+}
@@ -365,15 +365,18 @@ mod tests {
             rule: "blank",
             severity: PoSeverity::Warning,
             message: "blank translation".into(),
+            msgctxt: None,
             lines: lines
                 .iter()
                 .map(|(number, message)| DiagnosticLine {
                     line_number: *number,
+                    column: 0,
                     message: (*message).to_string(),
                     highlights: vec![],
                 })
                 .collect(),
             misspelled_words: std::collections::HashSet::new(),
+            spelling_suggestions: std::collections::HashMap::new(),
             fix: None,
         }
     }
@@ -9,19 +9,30 @@
 //! The following sub-commands are available:
 //!
 //! - [`check`](#check-files): check and fix files
+//! - [`fix`](#fix-files): fix whitespace and newlines in files
 //! - [`rules`](#rules): display rules used to check files
 //! - [`stats`](#stats): display statistics about files
+//! - [`analyze`](#analyze): display words and characters of each entry
 //! - [`lsp`](#lsp): run the language server for editor integration
+//! - [`schema`](#schema): display the JSON Schema of diagnostics
 //!
 //! # Check files
 //!
 //! The `check` command checks all gettext files (*.po) given on command-line or found
 //! in the provided directories.
 //!
-//! The .gitignore rules are respected: ignored files are skipped.
+//! The .gitignore rules are respected: ignored files are skipped. Additional files can
+//! be skipped with `--exclude` glob patterns (for example `--exclude "**/_build/**"`).
 //!
 //! The `check` command can also fix some issues in-place, and it can generate a SARIF report for CI jobs.
 //!
+//! # Fix files
+//!
+//! The `fix` command fixes leading/trailing whitespace and newlines in gettext files
+//! (*.po), using the auto-fixes of rules `whitespace-start`, `whitespace-end` and
+//! `newlines`. Files are rewritten in place, or displayed on standard output with
+//! `--dry-run`.
+//!
 //! # Rules
 //!
 //! The `rules` command displays the rules used to check files.
@@ -33,41 +44,22 @@
 //! The `stats` command displays statistics about gettext files (*.po) and can compute
 //! detailed statistics with the number of entries, words and characters.
 //!
+//! # Analyze
+//!
+//! The `analyze` command displays, as JSON, the number of words and characters of the
+//! source string of each entry in gettext files (*.po), for example to estimate the
+//! cost of a translation.
+//!
 //! # LSP
 //!
 //! The `lsp` command runs a Language Server Protocol server over stdin/stdout, so editors
 //! can show poexam diagnostics in real time while editing PO files.
-
-mod args;
-mod checker;
-mod config;
-mod diagnostic;
-mod dict;
-mod dir;
-mod fix;
-mod lsp;
-mod po;
-mod result;
-mod rules;
-mod sarif;
-mod stats;
-mod table;
-
-use clap::Parser;
-
-use crate::args::{Cli, Command};
-use crate::checker::run_check;
-use crate::lsp::run_lsp;
-use crate::rules::rule::run_rules;
-use crate::stats::run_stats;
+//!
+//! # Schema
+//!
+//! The `schema` command displays the JSON Schema of the diagnostics displayed by the
+//! `check` command with `--output json`.
 
 fn main() {
-    let args = Cli::parse();
-    let rc = match &args.command {
-        Command::Check(args) => run_check(args),
-        Command::Rules(args) => run_rules(args),
-        Command::Stats(args) => run_stats(args),
-        Command::Lsp(args) => run_lsp(args),
-    };
-    std::process::exit(rc);
+    std::process::exit(poexam::run());
 }
@@ -15,7 +15,13 @@ use crate::{po::escape::EscapePoExt, po::format::language::Language, po::message
 #[allow(clippy::struct_excessive_bools)]
 pub struct Entry {
     pub line_number: usize,
+    /// Translator comments (`#` lines), without the `#` and the following space.
+    pub comments: Vec<String>,
+    /// Extracted comments (`#.` lines), without the `#.` and the following space.
+    pub extracted_comments: Vec<String>,
     pub keywords: Vec<String>,
+    /// Source code references (`#:` comments), for example `src/main.rs:42`.
+    pub references: Vec<String>,
     pub fuzzy: bool,
     pub obsolete: bool,
     pub noqa: bool,
@@ -23,6 +29,14 @@ pub struct Entry {
     pub nowrap: bool,
     pub format_language: Language,
     pub encoding_error: bool,
+    /// A `msgstr[N]` line has an index that is not a valid number (e.g. `msgstr[]`,
+    /// `msgstr[x]` or a number too large); the line is ignored.
+    pub malformed_plural_index: bool,
+    /// Previous message context, message id and plural message id (`#| msgctxt`,
+    /// `#| msgid` and `#| msgid_plural`), written by gettext for fuzzy entries.
+    pub previous_msgctxt: Option<Message>,
+    pub previous_msgid: Option<Message>,
+    pub previous_msgid_plural: Option<Message>,
     pub msgctxt: Option<Message>,
     pub msgid: Option<Message>,
     pub msgid_plural: Option<Message>,
@@ -37,7 +51,10 @@ pub struct Entry {
 impl PartialEq for Entry {
     fn eq(&self, other: &Self) -> bool {
         self.line_number == other.line_number
+            && self.comments == other.comments
+            && self.extracted_comments == other.extracted_comments
             && self.keywords == other.keywords
+            && self.references == other.references
             && self.fuzzy == other.fuzzy
             && self.obsolete == other.obsolete
             && self.noqa == other.noqa
@@ -45,6 +62,10 @@ impl PartialEq for Entry {
             && self.nowrap == other.nowrap
             && self.format_language == other.format_language
             && self.encoding_error == other.encoding_error
+            && self.malformed_plural_index == other.malformed_plural_index
+            && self.previous_msgctxt == other.previous_msgctxt
+            && self.previous_msgid == other.previous_msgid
+            && self.previous_msgid_plural == other.previous_msgid_plural
             && self.msgctxt == other.msgctxt
             && self.msgid == other.msgid
             && self.msgid_plural == other.msgid_plural
@@ -56,6 +77,7 @@ impl Eq for Entry {}
 
 impl Entry {
     /// Create a new PO entry with the line number and default values.
+    #[must_use]
     pub fn new(line_number: usize) -> Self {
         Self {
             line_number,
@@ -92,6 +114,7 @@ impl Entry {
     }
 
     /// Return `true` if this entry is the header entry (`msgid` is set and is an empty string).
+    #[must_use]
     pub const fn is_header(&self) -> bool {
         match &self.msgid {
             Some(msg) => msg.value.is_empty(),
@@ -100,12 +123,14 @@ impl Entry {
     }
 
     /// Return `true` if this entry has a plural form (`msgid_plural` is set).
+    #[must_use]
     pub const fn has_plural_form(&self) -> bool {
         self.msgid_plural.is_some()
     }
 
     /// Return `true` if this entry has at least one non-empty translation string
     /// (even if the entry is marked as fuzzy).
+    #[must_use]
     pub fn is_translated(&self) -> bool {
         for msg in self.msgstr.values() {
             if !msg.value.is_empty() {
@@ -135,6 +160,16 @@ impl Entry {
 
     /// Escapes all string fields in this entry using the provided escape function.
     pub fn escape_strings(&mut self) {
+        for msg in [
+            &mut self.previous_msgctxt,
+            &mut self.previous_msgid,
+            &mut self.previous_msgid_plural,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            msg.escape();
+        }
         if let Some(ref mut msg) = self.msgctxt {
             msg.escape();
         }
@@ -144,15 +179,23 @@ impl Entry {
         if let Some(ref mut msg) = self.msgid_plural {
             msg.escape();
         }
-        let mut idx: u32 = 0;
-        while let Some(msg) = self.msgstr.get_mut(&idx) {
+        for msg in self.msgstr.values_mut() {
             msg.escape();
-            idx += 1;
         }
     }
 
     /// Unescape all string fields in this entry using the provided unescape function.
     pub fn unescape_strings(&mut self) {
+        for msg in [
+            &mut self.previous_msgctxt,
+            &mut self.previous_msgid,
+            &mut self.previous_msgid_plural,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            msg.unescape();
+        }
         if let Some(ref mut msg) = self.msgctxt {
             msg.unescape();
         }
@@ -162,14 +205,13 @@ impl Entry {
         if let Some(ref mut msg) = self.msgid_plural {
             msg.unescape();
         }
-        let mut idx: u32 = 0;
-        while let Some(msg) = self.msgstr.get_mut(&idx) {
+        for msg in self.msgstr.values_mut() {
             msg.unescape();
-            idx += 1;
         }
     }
 
     /// Convert the keywords of this entry back to PO file lines.
+    #[must_use]
     pub fn keywords_to_po_lines(&self) -> Vec<String> {
         self.keywords
             .iter()
@@ -177,7 +219,48 @@ impl Entry {
             .collect()
     }
 
+    /// Convert this entry back to a PO block: translator comments, extracted
+    /// comments, references, flags, previous messages and messages, in the order
+    /// used by gettext tools.
+    ///
+    /// Each line ends with a newline; strings are not wrapped (one line per message).
+    #[must_use]
+    pub fn to_po_block(&self) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        lines.extend(
+            self.comments
+                .iter()
+                .map(|comment| comment_to_po_line("#", comment)),
+        );
+        lines.extend(
+            self.extracted_comments
+                .iter()
+                .map(|comment| comment_to_po_line("#.", comment)),
+        );
+        if !self.references.is_empty() {
+            lines.push(format!("#: {}", self.references.join(" ")));
+        }
+        if !self.keywords.is_empty() {
+            lines.push(format!("#, {}", self.keywords.join(", ")));
+        }
+        let prefix = if self.obsolete { "#~|" } else { "#|" };
+        for (keyword, msg) in [
+            ("msgctxt", &self.previous_msgctxt),
+            ("msgid", &self.previous_msgid),
+            ("msgid_plural", &self.previous_msgid_plural),
+        ] {
+            if let Some(msg) = msg {
+                lines.push(format!("{prefix} {keyword} \"{}\"", msg.value.escape_po()));
+            }
+        }
+        lines.extend(self.msg_to_po_lines().into_iter().map(|(_, line)| line));
+        let mut block = lines.join("\n");
+        block.push('\n');
+        block
+    }
+
     /// Convert the messages of this entry back to PO file lines.
+    #[must_use]
     pub fn msg_to_po_lines(&self) -> Vec<(usize, String)> {
         let mut lines = Vec::with_capacity(5);
         let prefix = if self.obsolete { "#~ " } else { "" };
@@ -199,9 +282,8 @@ impl Entry {
                 format!("{prefix}msgid_plural \"{}\"", msg.value.escape_po()),
             ));
         }
-        let mut idx: u32 = 0;
-        while let Some(msg) = self.msgstr.get(&idx) {
-            if self.has_plural_form() || self.msgstr.len() > 1 {
+        for (idx, msg) in &self.msgstr {
+            if self.has_plural_form() || self.msgstr.len() > 1 || *idx > 0 {
                 lines.push((
                     msg.line_number,
                     format!("{prefix}msgstr[{idx}] \"{}\"", msg.value.escape_po()),
@@ -212,12 +294,21 @@ impl Entry {
                     format!("{prefix}msgstr \"{}\"", msg.value.escape_po()),
                 ));
             }
-            idx += 1;
         }
         lines
     }
 }
 
+/// Convert a comment back to a PO line, with the prefix (`#` or `#.`) followed by a
+/// space, unless the comment is empty or starts with a tab (`#\t…`).
+fn comment_to_po_line(prefix: &str, comment: &str) -> String {
+    if comment.is_empty() || comment.starts_with('\t') {
+        format!("{prefix}{comment}")
+    } else {
+        format!("{prefix} {comment}")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -352,6 +443,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_po_block() {
+        let mut entry = get_test_entry();
+        entry.comments = vec!["Translator comment".to_string(), String::new()];
+        entry.extracted_comments = vec!["Extracted comment".to_string()];
+        entry.references = vec!["src/main.rs:42".to_string(), "src/lib.rs:7".to_string()];
+        entry.keywords = vec!["fuzzy".to_string(), "c-format".to_string()];
+        entry.previous_msgid = Some(Message::new(0, "old \"file\"", 0..0));
+        assert_eq!(
+            entry.to_po_block(),
+            "# Translator comment\n\
+             #\n\
+             #. Extracted comment\n\
+             #: src/main.rs:42 src/lib.rs:7\n\
+             #, fuzzy, c-format\n\
+             #| msgid \"old \\\"file\\\"\"\n\
+             msgctxt \"a file\\n\"\n\
+             msgid \"file\\n\"\n\
+             msgid_plural \"files\\n\"\n\
+             msgstr[0] \"fichier\\n\"\n\
+             msgstr[1] \"fichiers\\n\"\n"
+        );
+    }
+
+    #[test]
+    fn test_msg_to_po_lines_index_gap() {
+        let mut entry = Entry::new(1);
+        entry.msgid = Some(Message::new(1, "file", 0..0));
+        entry.msgstr.insert(0, Message::new(2, "fichier\\n", 0..0));
+        entry.msgstr.insert(2, Message::new(3, "fichiers\\n", 0..0));
+        entry.unescape_strings();
+        assert_eq!(
+            entry.msgstr.get(&2),
+            Some(&Message::new(3, "fichiers\n", 0..0))
+        );
+        assert_eq!(
+            entry.msg_to_po_lines(),
+            vec![
+                (1, "msgid \"file\"".to_string()),
+                (2, "msgstr[0] \"fichier\\n\"".to_string()),
+                (3, "msgstr[2] \"fichiers\\n\"".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn test_keywords_to_po_lines() {
         let mut entry = get_test_entry();
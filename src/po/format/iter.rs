@@ -54,6 +54,7 @@ pub struct FormatWordPos<'a> {
     len: usize,
     pos: usize,
     fmt: Language,
+    keep_underscore: bool,
 }
 
 impl<'a> FormatWordPos<'a> {
@@ -63,8 +64,15 @@ impl<'a> FormatWordPos<'a> {
             len: s.len(),
             pos: 0,
             fmt: language,
+            keep_underscore: false,
         }
     }
+
+    /// Keep underscores in words, so that `snake_case` is one word instead of two.
+    pub const fn with_underscore(mut self, keep_underscore: bool) -> Self {
+        self.keep_underscore = keep_underscore;
+        self
+    }
 }
 
 /// Iterator returning words of a string, according to the given language, skipping
@@ -92,7 +100,9 @@ impl<'a> Iterator for FormatWordPos<'a> {
                 start_apostrophe = true;
             }
             if c.is_alphanumeric()
-                || (idx_start.is_some() && (c == '-' || c == '\'' || c == '’') || (c == 'ʼ'))
+                || (idx_start.is_some()
+                    && (c == '-' || c == '\'' || c == '’' || (self.keep_underscore && c == '_'))
+                    || (c == 'ʼ'))
             {
                 if idx_start.is_none() {
                     idx_start = Some(self.pos);
@@ -209,6 +219,13 @@ impl<'a> Iterator for FormatAcronymPos<'a> {
     }
 }
 
+/// Names of the HTML entities skipped by [`FormatAcceleratorPos`] when the marker
+/// is `&` (numeric entities like `&#38;` are never accelerators).
+const HTML_ENTITIES: [&str; 15] = [
+    "amp", "apos", "copy", "euro", "gt", "hellip", "laquo", "lt", "mdash", "nbsp", "ndash", "quot",
+    "raquo", "reg", "trade",
+];
+
 pub struct FormatAcceleratorPos<'a> {
     s: &'a str,
     len: usize,
@@ -227,6 +244,17 @@ impl<'a> FormatAcceleratorPos<'a> {
             marker,
         }
     }
+
+    /// Return the length of the HTML entity name followed by `;` at the beginning of
+    /// `s` (the string after the `&`), if it is a known entity.
+    fn html_entity_len(s: &str) -> Option<usize> {
+        // Entity names are short: no need to scan the whole string.
+        let end = s.bytes().take(8).position(|b| b == b';')?;
+        HTML_ENTITIES
+            .binary_search(&&s[..end])
+            .is_ok()
+            .then_some(end + 1)
+    }
 }
 
 /// Iterator returning keyboard accelerator markers of a string, according to the
@@ -236,7 +264,8 @@ impl<'a> FormatAcceleratorPos<'a> {
 /// alphanumeric character. A doubled marker (e.g. `&&`) is an escaped literal and
 /// is not an accelerator: both characters are skipped. A trailing marker, or a
 /// marker followed by whitespace or punctuation, is treated as a literal and
-/// ignored (this avoids false positives on prose such as "Drag & drop"). Only the
+/// ignored (this avoids false positives on prose such as "Drag & drop"). With the
+/// marker `&`, a known HTML entity (e.g. `&amp;` or `&nbsp;`) is skipped. Only the
 /// marker character is returned, not the accelerated character, so its span is
 /// always one character wide.
 ///
@@ -253,6 +282,13 @@ impl<'a> Iterator for FormatAcceleratorPos<'a> {
             }
             if c == self.marker {
                 let start = self.pos;
+                // HTML entity (e.g. `&amp;`): skip it.
+                if self.marker == '&'
+                    && let Some(len) = Self::html_entity_len(&self.s[new_pos..])
+                {
+                    self.pos = new_pos + len;
+                    continue;
+                }
                 match self.s[new_pos..].chars().next() {
                     // Doubled marker is an escaped literal: skip both characters.
                     Some(next) if next == self.marker => {
@@ -122,6 +122,49 @@ pub fn fmt_strip_index(fmt: &str) -> Cow<'_, str> {
     Cow::Owned(result)
 }
 
+/// Type of the argument expected by a C conversion specifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormatType {
+    Int,
+    Uint,
+    Float,
+    String,
+    Pointer,
+    Char,
+}
+
+impl std::fmt::Display for FormatType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Int => write!(f, "int"),
+            Self::Uint => write!(f, "uint"),
+            Self::Float => write!(f, "float"),
+            Self::String => write!(f, "string"),
+            Self::Pointer => write!(f, "pointer"),
+            Self::Char => write!(f, "char"),
+        }
+    }
+}
+
+/// Get the type of the argument expected by a format string (the length modifiers
+/// are ignored), `None` if the conversion specifier is unknown.
+///
+/// For example, for format `"%3$ld"`, this function returns `FormatType::Int`.
+pub fn fmt_type(fmt: &str) -> Option<FormatType> {
+    if !fmt.starts_with('%') {
+        return None;
+    }
+    match fmt.chars().next_back()? {
+        'd' | 'i' => Some(FormatType::Int),
+        'u' | 'o' | 'x' | 'X' => Some(FormatType::Uint),
+        'f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A' => Some(FormatType::Float),
+        's' | 'S' => Some(FormatType::String),
+        'p' | 'n' => Some(FormatType::Pointer),
+        'c' | 'C' => Some(FormatType::Char),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,6 +203,24 @@ mod tests {
         assert_eq!(fmt_strip_index("%42$05s"), "%05s");
     }
 
+    #[test]
+    fn test_type() {
+        assert_eq!(fmt_type(""), None);
+        assert_eq!(fmt_type("test"), None);
+        assert_eq!(fmt_type("%"), None);
+        assert_eq!(fmt_type("%k"), None);
+        assert_eq!(fmt_type("%d"), Some(FormatType::Int));
+        assert_eq!(fmt_type("%3$lli"), Some(FormatType::Int));
+        assert_eq!(fmt_type("%zu"), Some(FormatType::Uint));
+        assert_eq!(fmt_type("%08X"), Some(FormatType::Uint));
+        assert_eq!(fmt_type("%.2f"), Some(FormatType::Float));
+        assert_eq!(fmt_type("%Lg"), Some(FormatType::Float));
+        assert_eq!(fmt_type("%-10s"), Some(FormatType::String));
+        assert_eq!(fmt_type("%p"), Some(FormatType::Pointer));
+        assert_eq!(fmt_type("%c"), Some(FormatType::Char));
+        assert_eq!(FormatType::Uint.to_string(), "uint");
+    }
+
     #[test]
     fn test_strip_formats() {
         assert_eq!(strip_formats("", Language::C), "");
@@ -88,6 +88,13 @@ mod tests {
                 .collect::<Vec<_>>(),
             vec![("_", 0, 1)]
         );
+        // Known HTML entities are skipped, unknown ones are accelerators.
+        assert_eq!(
+            FormatAcceleratorPos::new("&Save &amp; &quit&nbsp;now &foo;", Language::Null, '&')
+                .map(|m| (m.s, m.start, m.end))
+                .collect::<Vec<_>>(),
+            vec![("&", 0, 1), ("&", 12, 13), ("&", 27, 28)]
+        );
     }
 
     #[test]
@@ -101,6 +108,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_word_pos_keep_underscore() {
+        assert_eq!(
+            FormatWordPos::new("set _max_count_ now", Language::Null)
+                .map(|m| (m.s, m.start, m.end))
+                .collect::<Vec<_>>(),
+            vec![
+                ("set", 0, 3),
+                ("max", 5, 8),
+                ("count", 9, 14),
+                ("now", 16, 19)
+            ]
+        );
+        assert_eq!(
+            FormatWordPos::new("set _max_count_ now", Language::Null)
+                .with_underscore(true)
+                .map(|m| (m.s, m.start, m.end))
+                .collect::<Vec<_>>(),
+            vec![("set", 0, 3), ("max_count_", 5, 15), ("now", 16, 19)]
+        );
+    }
+
     #[test]
     fn test_acronym_pos() {
         assert!(FormatAcronymPos::new("", Language::Null).next().is_none());
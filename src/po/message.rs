@@ -14,6 +14,10 @@ use crate::po::escape::EscapePoExt;
 pub struct Message {
     pub line_number: usize,
     pub value: String,
+    /// Column (1-based, in bytes) of the keyword (`msgid`, `msgstr`, …) in the first
+    /// line of the message, 0 if unknown. For a multi-line string, this is the column
+    /// of the keyword, not the one of the string (which starts on the next line).
+    pub column: usize,
     /// Byte range of the whole message block (keyword line + continuation lines)
     /// in the original file bytes. Used by the auto-fix writer to splice a
     /// freshly emitted block back into the file.
@@ -35,10 +39,18 @@ impl Message {
         Self {
             line_number,
             value: value.as_ref().to_string(),
+            column: 0,
             byte_range,
         }
     }
 
+    /// Set the column of the keyword in the first line of the message.
+    #[must_use]
+    pub const fn with_column(mut self, column: usize) -> Self {
+        self.column = column;
+        self
+    }
+
     /// Escape special characters in the value (to be written in a PO file).
     pub fn escape(&mut self) {
         self.value = self.value.escape_po();
@@ -64,7 +76,7 @@ mod tests {
         let mut msgid = Message::new(8, "test\nline 2", 0..0);
         assert_eq!(
             format!("{msgid:?}"),
-            "Message { line_number: 8, value: \"test\\nline 2\", byte_range: 0..0 }"
+            "Message { line_number: 8, value: \"test\\nline 2\", column: 0, byte_range: 0..0 }"
         );
         msgid.escape();
         assert_eq!(msgid.value, "test\\nline 2");
@@ -5,6 +5,9 @@
 //! PO file parser.
 
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
 
 use crate::{po::entry::Entry, po::format::language::Language, po::message::Message};
 use encoding_rs::Encoding;
@@ -13,12 +16,38 @@ use encoding_rs::Encoding;
 enum Field {
     #[default]
     Comment,
+    PreviousCtxt,
+    PreviousId,
+    PreviousIdPlural,
     Ctxt,
     Id,
     IdPlural,
     Str(u32),
 }
 
+/// Content of a PO file read from disk, used to create a [`Parser`] that borrows it.
+///
+/// It is returned by [`Parser::from_path`]: the content must outlive the parser, so
+/// the file is read once and the parser is created with [`OwnedParser::parser`].
+#[derive(Debug, Default)]
+pub struct OwnedParser {
+    data: Vec<u8>,
+}
+
+impl OwnedParser {
+    /// Return the content of the file.
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Create a new `Parser` on the content of the file.
+    #[must_use]
+    pub fn parser(&self) -> Parser<'_> {
+        Parser::new(&self.data)
+    }
+}
+
 #[derive(Default)]
 pub struct Parser<'a> {
     // Data and some general info parsed in the header.
@@ -28,7 +57,14 @@ pub struct Parser<'a> {
     language_code: String,
     country: String,
     encoding: Option<&'static Encoding>,
+    /// Charset declared in the header which is not recognized (the strings are
+    /// decoded as UTF-8).
+    unknown_charset: Option<String>,
     nplurals: u32,
+    plural_expr: String,
+    /// Fields of the header, with lowercase names and trimmed values.
+    header_fields: HashMap<String, String>,
+    has_bom: bool,
     // Internal state of the parser.
     offset: usize,
     line_offset_start: usize,
@@ -40,38 +76,139 @@ pub struct Parser<'a> {
 
 impl<'d> Parser<'d> {
     /// Create a new `Parser` from the given byte slice.
+    ///
+    /// A leading UTF-8 byte-order mark (BOM) is skipped: byte offsets of entries
+    /// are still relative to the start of the data (including the BOM).
+    #[must_use]
     pub fn new(data: &'d [u8]) -> Self {
+        let has_bom = data.starts_with(b"\xEF\xBB\xBF");
         Self {
             data,
             data_len: data.len(),
+            has_bom,
+            offset: if has_bom { 3 } else { 0 },
+            line_number: 1,
+            next_line_number: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Read the PO file at `path`, to iterate on its entries.
+    ///
+    /// The header fields are available on the parser once the header entry has been
+    /// read:
+    ///
+    /// ```
+    /// use poexam::Parser;
+    ///
+    /// let file = Parser::from_path("examples/fr.po".as_ref())?;
+    /// let mut parser = file.parser();
+    /// let mut count = 0;
+    /// for entry in parser.by_ref() {
+    ///     if !entry.is_header() && entry.is_translated() {
+    ///         count += 1;
+    ///     }
+    /// }
+    /// assert!(count > 0);
+    /// assert_eq!(parser.header_field("Language"), Some("fr"));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can not be read.
+    pub fn from_path(path: &Path) -> std::io::Result<OwnedParser> {
+        Ok(OwnedParser {
+            data: std::fs::read(path)?,
+        })
+    }
+
+    /// Parse again the entry at the given byte range (for example the `byte_range` of
+    /// an entry returned by the parser), decoded with the encoding of the file.
+    ///
+    /// The line numbers of the entry returned are not meaningful.
+    #[must_use]
+    pub fn parse_entry_at(&self, byte_range: Range<usize>) -> Option<Entry> {
+        Parser {
+            data: self.data,
+            data_len: byte_range.end.min(self.data_len),
+            encoding: self.encoding,
+            offset: byte_range.start,
             line_number: 1,
             next_line_number: 1,
             ..Default::default()
         }
+        .next()
+    }
+
+    /// Return the length of the data, in bytes.
+    #[must_use]
+    pub const fn data_len(&self) -> usize {
+        self.data_len
+    }
+
+    /// Return `true` if the data starts with a UTF-8 byte-order mark (BOM).
+    #[must_use]
+    pub const fn has_bom(&self) -> bool {
+        self.has_bom
+    }
+
+    /// Return `true` if the charset declared in the header is UTF-8 (or if there is
+    /// no charset).
+    ///
+    /// An unknown charset is not UTF-8 (see [`Parser::unknown_charset`]).
+    #[must_use]
+    pub const fn is_utf8(&self) -> bool {
+        self.encoding.is_none() && self.unknown_charset.is_none()
+    }
+
+    /// Return the charset declared in the header if it is not recognized.
+    #[must_use]
+    pub fn unknown_charset(&self) -> Option<&str> {
+        self.unknown_charset.as_deref()
     }
 
     /// Return the encoding name.
+    #[must_use]
     pub fn encoding_name(&self) -> &'static str {
         self.encoding
             .map_or_else(|| encoding_rs::UTF_8.name(), |enc| enc.name())
     }
 
+    #[must_use]
     pub fn language(&self) -> &str {
         &self.language
     }
 
+    #[must_use]
     pub fn language_code(&self) -> &str {
         &self.language_code
     }
+    #[must_use]
     pub fn country(&self) -> &str {
         &self.country
     }
 
     /// Return the number of plurals defined in the header.
+    #[must_use]
     pub const fn nplurals(&self) -> u32 {
         self.nplurals
     }
 
+    /// Return the plural expression defined in the header (`plural=` in `Plural-Forms`),
+    /// empty if not defined.
+    #[must_use]
+    pub fn plural_expr(&self) -> &str {
+        &self.plural_expr
+    }
+
+    /// Return the value of a field in the header (the name is case-insensitive).
+    pub fn header_field(&self, name: &str) -> Option<&str> {
+        self.header_fields
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
     /// Return the next line from the input data, updating the parser's location.
     fn next_line(&mut self) -> Option<&'d [u8]> {
         if self.offset >= self.data_len {
@@ -86,6 +223,12 @@ impl<'d> Parser<'d> {
         Some(&self.data[start..end])
     }
 
+    /// Return the column (1-based, in bytes) of `line`, a slice of the line that was
+    /// just read by `next_line` (for example the keyword after the `#~ ` prefix).
+    fn column(&self, line: &[u8]) -> usize {
+        line.as_ptr().addr() - self.data.as_ptr().addr() - self.line_offset_start + 1
+    }
+
     /// End offset of the line that was just read by `next_line` (clamped to `data_len`
     /// so the trailing-no-newline case stays in bounds).
     const fn line_end_offset(&self) -> usize {
@@ -113,6 +256,10 @@ impl<'d> Parser<'d> {
         for line in msg.value.split('\n') {
             let (keyword, value) = line.split_once(':').unwrap_or(("", ""));
             let keyword = keyword.trim();
+            if !keyword.is_empty() {
+                self.header_fields
+                    .insert(keyword.to_ascii_lowercase(), value.trim().to_string());
+            }
             if keyword.eq_ignore_ascii_case("language") {
                 self.language = value.trim().to_string();
                 if let Some(pos) = value.find('_') {
@@ -135,6 +282,10 @@ impl<'d> Parser<'d> {
                 if encoding.is_some_and(|e| e != encoding_rs::UTF_8) {
                     self.encoding = encoding;
                 }
+                // The placeholder `CHARSET` is used in templates (`*.pot`).
+                if encoding.is_none() && !charset.is_empty() && charset != "CHARSET" {
+                    self.unknown_charset = Some(charset.to_string());
+                }
             } else if keyword.eq_ignore_ascii_case("plural-forms")
                 && let Some(pos) = value.find("nplurals=")
             {
@@ -145,6 +296,12 @@ impl<'d> Parser<'d> {
                 if let Ok(nplurals) = value_nplurals[..end].parse::<u32>() {
                     self.nplurals = nplurals;
                 }
+                if let Some(expr) = value.split(';').find_map(|param| {
+                    let (key, expr) = param.split_once('=')?;
+                    (key.trim() == "plural").then(|| expr.trim())
+                }) {
+                    self.plural_expr = expr.to_string();
+                }
             }
         }
     }
@@ -176,6 +333,22 @@ impl<'d> Parser<'d> {
         }
     }
 
+    /// Decode bytes of a comment or reference with the encoding of the file (invalid
+    /// bytes are replaced).
+    fn decode(&self, bytes: &[u8]) -> String {
+        match self.encoding {
+            Some(encoding) => encoding.decode(bytes).0.into_owned(),
+            None => String::from_utf8_lossy(bytes).into_owned(),
+        }
+    }
+
+    /// Get the text of a comment (after the `#` marker), without the leading space
+    /// and the end of line, but with trailing whitespace kept as written.
+    fn comment_text(&self, comment: &[u8]) -> String {
+        let comment = comment.strip_prefix(b" ").unwrap_or(comment);
+        self.decode(comment.strip_suffix(b"\r").unwrap_or(comment))
+    }
+
     /// Extract a string value from a line, and decode if necessary (not UTF-8).
     fn extract_string(&mut self, line: &'d [u8]) -> Cow<'d, str> {
         let Some(start) = memchr::memchr(b'"', line) else {
@@ -206,17 +379,25 @@ impl<'d> Parser<'d> {
         }
     }
 
+    /// Create a new `Message` starting on the current line (keyword and string).
+    fn new_message(&mut self, line: &'d [u8]) -> Message {
+        Message::new(
+            self.line_number,
+            self.extract_string(line),
+            self.line_offset_start..self.line_end_offset(),
+        )
+        .with_column(self.column(line))
+    }
+
     /// Parse a message line and update the corresponding field in the `Entry`.
     ///
     /// The line can be a `msgctxt`, `msgid`, `msgid_plural`, `msgstr`, or a continued string.
     fn parse_message(&mut self, line: &'d [u8], entry: &mut Entry) {
-        let line_start = self.line_offset_start;
         let line_end = self.line_end_offset();
         match line {
             [b'"', ..] => {
                 let value = self.extract_string(line);
                 match self.field {
-                    Field::Comment => {}
                     Field::Ctxt => {
                         entry.append_msgctxt(value);
                         if let Some(msg) = entry.msgctxt.as_mut() {
@@ -241,15 +422,13 @@ impl<'d> Parser<'d> {
                             msg.byte_range.end = line_end;
                         }
                     }
+                    // Comment or previous message: nothing to append.
+                    _ => {}
                 }
             }
             [b'm', b's', b'g', b'c', b't', b'x', b't', ..] => {
                 self.field = Field::Ctxt;
-                entry.msgctxt = Some(Message::new(
-                    self.line_number,
-                    self.extract_string(line),
-                    line_start..line_end,
-                ));
+                entry.msgctxt = Some(self.new_message(line));
             }
             [
                 b'm',
@@ -267,19 +446,11 @@ impl<'d> Parser<'d> {
                 ..,
             ] => {
                 self.field = Field::IdPlural;
-                entry.msgid_plural = Some(Message::new(
-                    self.line_number,
-                    self.extract_string(line),
-                    line_start..line_end,
-                ));
+                entry.msgid_plural = Some(self.new_message(line));
             }
             [b'm', b's', b'g', b'i', b'd', ..] => {
                 self.field = Field::Id;
-                entry.msgid = Some(Message::new(
-                    self.line_number,
-                    self.extract_string(line),
-                    line_start..line_end,
-                ));
+                entry.msgid = Some(self.new_message(line));
             }
             [b'm', b's', b'g', b's', b't', b'r', b'[', ..] => {
                 if let Some(idx_end) = memchr::memchr(b']', line)
@@ -287,30 +458,52 @@ impl<'d> Parser<'d> {
                     && let Ok(idx) = str_idx.parse::<u32>()
                 {
                     self.field = Field::Str(idx);
-                    entry.msgstr.insert(
-                        idx,
-                        Message::new(
-                            self.line_number,
-                            self.extract_string(line),
-                            line_start..line_end,
-                        ),
-                    );
+                    entry.msgstr.insert(idx, self.new_message(line));
+                } else {
+                    // Malformed index: the continued strings are ignored as well.
+                    self.field = Field::Comment;
+                    entry.malformed_plural_index = true;
                 }
             }
             [b'm', b's', b'g', b's', b't', b'r', ..] => {
                 self.field = Field::Str(0);
-                entry.msgstr.insert(
-                    0,
-                    Message::new(
-                        self.line_number,
-                        self.extract_string(line),
-                        line_start..line_end,
-                    ),
-                );
+                entry.msgstr.insert(0, self.new_message(line));
             }
             _ => {}
         }
     }
+
+    /// Parse a previous message line (`#| ...`) and update the corresponding field in
+    /// the `Entry`.
+    ///
+    /// The line can be a `msgctxt`, `msgid`, `msgid_plural`, or a continued string.
+    fn parse_previous_message(&mut self, line: &'d [u8], entry: &mut Entry) {
+        let line_end = self.line_end_offset();
+        let (field, msg) = if line.starts_with(b"\"") {
+            let value = self.extract_string(line);
+            let msg = match self.field {
+                Field::PreviousCtxt => entry.previous_msgctxt.as_mut(),
+                Field::PreviousId => entry.previous_msgid.as_mut(),
+                Field::PreviousIdPlural => entry.previous_msgid_plural.as_mut(),
+                _ => None,
+            };
+            if let Some(msg) = msg {
+                msg.value.push_str(&value);
+                msg.byte_range.end = line_end;
+            }
+            return;
+        } else if line.starts_with(b"msgctxt") {
+            (Field::PreviousCtxt, &mut entry.previous_msgctxt)
+        } else if line.starts_with(b"msgid_plural") {
+            (Field::PreviousIdPlural, &mut entry.previous_msgid_plural)
+        } else if line.starts_with(b"msgid") {
+            (Field::PreviousId, &mut entry.previous_msgid)
+        } else {
+            return;
+        };
+        self.field = field;
+        *msg = Some(self.new_message(line));
+    }
 }
 
 /// Implement the `Iterator` trait for `Parser`, yielding `Entry` items.
@@ -346,21 +539,49 @@ impl Iterator for Parser<'_> {
                 [b'#', b',' | b'=', keywords @ ..] => {
                     Parser::parse_keywords(keywords, &mut entry);
                 }
+                // Source code references.
+                [b'#', b':', references @ ..] => {
+                    entry.references.extend(
+                        references
+                            .split(u8::is_ascii_whitespace)
+                            .filter(|r| !r.is_empty())
+                            .map(|r| self.decode(r)),
+                    );
+                }
+                // Previous message (start or continued), for fuzzy entries.
+                [b'#', b'|', msg @ ..] => {
+                    self.parse_previous_message(msg.trim_ascii_start(), &mut entry);
+                }
+                // Previous message (start or continued) of an obsolete fuzzy entry.
+                [b'#', b'~', b'|', msg @ ..] => {
+                    entry.obsolete = true;
+                    self.parse_previous_message(msg.trim_ascii_start(), &mut entry);
+                }
                 // Obsolete entry with a message (start or continued).
                 [b'#', b'~', b' ', msg @ ..] => {
                     entry.obsolete = true;
                     self.parse_message(msg, &mut entry);
                 }
+                // Extracted comment.
+                [b'#', b'.', comment @ ..] => {
+                    entry.extracted_comments.push(self.comment_text(comment));
+                }
                 // Flag "noqa:xxx" in a comment (with rules).
                 [b'#', b' ', b'n', b'o', b'q', b'a', b':', rules @ ..] => {
                     entry.noqa_rules = rules
                         .split(|&b| b == b';')
                         .map(|r| String::from_utf8_lossy(r.trim_ascii()).into_owned())
                         .collect();
+                    entry.comments.push(self.comment_text(&line[1..]));
                 }
                 // Flag "noqa" in a comment.
                 [b'#', b' ', b'n', b'o', b'q', b'a', ..] => {
                     entry.noqa = true;
+                    entry.comments.push(self.comment_text(&line[1..]));
+                }
+                // Translator comment.
+                [b'#'] | [b'#', b' ' | b'\t', ..] => {
+                    entry.comments.push(self.comment_text(&line[1..]));
                 }
                 // Message line (start or continued).
                 [b'm' | b'"', ..] => {
@@ -392,6 +613,22 @@ mod tests {
         assert!(Parser::new(b"").next().is_none());
     }
 
+    #[test]
+    fn parse_from_path() {
+        let dir = tempfile::TempDir::with_prefix("poexam-parser-").expect("create temp dir");
+        let path = dir.path().join("fr.po");
+        assert!(Parser::from_path(&path).is_err());
+        let content = "msgid \"\"\nmsgstr \"Language: fr\\n\"\n\nmsgid \"a\"\nmsgstr \"b\"\n";
+        std::fs::write(&path, content).expect("write file");
+        let file = Parser::from_path(&path).expect("read file");
+        let mut parser = file.parser();
+        let entries: Vec<Entry> = parser.by_ref().collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].msgstr[&0].value, "b");
+        assert_eq!(parser.language(), "fr");
+        assert_eq!(file.data(), content.as_bytes());
+    }
+
     #[test]
     fn parse_header() {
         let content = r#"# Main comment
@@ -432,6 +669,16 @@ msgstr "test\n"
         assert_eq!(parser.language_code, "fr");
         assert_eq!(parser.country, "");
         assert!(parser.encoding.is_none());
+        assert_eq!(parser.plural_expr(), "(n > 1)");
+        assert_eq!(
+            parser.header_field("project-id-version"),
+            Some("my_project")
+        );
+        assert_eq!(
+            parser.header_field("Plural-Forms"),
+            Some("nplurals=2; plural=(n > 1);")
+        );
+        assert!(parser.header_field("Content-Type").is_none());
 
         let content = r#"# Main comment
 msgid ""
@@ -561,6 +808,28 @@ msgstr "testé"
         );
     }
 
+    #[test]
+    fn parse_entry_at_iso8859() {
+        let content = r#"
+msgid ""
+msgstr "Content-Type: text/plain; charset=ISO-8859-15\n"
+
+msgctxt "ctxt"
+msgid "testé"
+msgstr "testé"
+"#;
+        let content_iso = encoding_rs::ISO_8859_15.encode(content).0;
+        let mut parser = Parser::new(content_iso.as_ref());
+        let entries = parser.by_ref().collect::<Vec<Entry>>();
+        let entry = parser
+            .parse_entry_at(entries[1].byte_range.clone())
+            .expect("entry");
+        assert_eq!(entry.msgctxt.map(|m| m.value), Some("ctxt".to_string()));
+        assert_eq!(entry.msgid.map(|m| m.value), Some("testé".to_string()));
+        assert_eq!(entry.byte_range, entries[1].byte_range);
+        assert!(!entry.encoding_error);
+    }
+
     #[test]
     fn parse_entry_with_context() {
         let content = r#"
@@ -663,6 +932,34 @@ msgstr[1] "fichiers"
         );
     }
 
+    #[test]
+    fn parse_utf8_bom() {
+        let content = b"\xEF\xBB\xBF# Comment
+msgid \"\"
+msgstr \"\"
+\"Language: fr\\n\"
+\"Content-Type: text/plain; charset=ISO-8859-1\\n\"
+
+msgid \"tested\"
+msgstr \"test\xE9\"
+";
+        let mut parser = Parser::new(content);
+        let entries = parser.by_ref().collect::<Vec<Entry>>();
+        assert!(parser.has_bom());
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].is_header());
+        assert_eq!(entries[0].comments, vec!["Comment"]);
+        assert_eq!(entries[0].line_number, 1);
+        assert_eq!(entries[0].byte_range.start, 3);
+        assert_eq!(parser.language(), "fr");
+        assert_eq!(parser.language_code(), "fr");
+        assert_eq!(parser.encoding_name(), "windows-1252");
+        assert_eq!(
+            entries[1].msgstr.get(&0),
+            Some(Message::new(8, "testé", 0..0)).as_ref()
+        );
+    }
+
     #[test]
     fn parse_comments() {
         let content = r#"
@@ -691,6 +988,7 @@ msgstr "bonjour, %s"
         assert!(entries[0].noqa);
         assert!(entries[0].nowrap);
         assert_eq!(entries[0].noqa_rules, vec!["blank", "pipes"]);
+        assert_eq!(entries[0].references, vec!["src/main.rs:42"]);
         assert_eq!(entries[0].format_language, Language::C);
         assert!(!entries[0].encoding_error);
         assert!(entries[0].msgctxt.is_none());
@@ -750,6 +1048,256 @@ msgstr "bonjour, %s"
         );
     }
 
+    #[test]
+    fn parse_references() {
+        let content = r#"#: src/main.rs:42 src/lib.rs:7
+#:   src/other.rs:3
+msgid ""
+msgstr "Language: fr\n"
+
+#: src/main.rs:10
+msgid "hello"
+msgstr "bonjour"
+
+msgid "world"
+msgstr "monde"
+"#;
+        let mut parser = Parser::new(content.as_bytes());
+        let entries = parser.by_ref().collect::<Vec<Entry>>();
+        assert_eq!(entries.len(), 3);
+        assert!(entries[0].is_header());
+        assert_eq!(entries[0].line_number, 1);
+        assert_eq!(
+            entries[0].references,
+            vec!["src/main.rs:42", "src/lib.rs:7", "src/other.rs:3"]
+        );
+        assert_eq!(parser.language(), "fr");
+        assert_eq!(entries[1].line_number, 6);
+        assert_eq!(entries[1].references, vec!["src/main.rs:10"]);
+        assert!(entries[2].references.is_empty());
+    }
+
+    #[test]
+    fn parse_references_iso8859() {
+        let content = r#"msgid ""
+msgstr "Content-Type: text/plain; charset=ISO-8859-15\n"
+
+#: src/café.c:12
+msgid "hello"
+msgstr "bonjour"
+"#;
+        let content_iso = encoding_rs::ISO_8859_15.encode(content).0;
+        let entries = Parser::new(content_iso.as_ref()).collect::<Vec<Entry>>();
+        assert_eq!(entries[1].references, vec!["src/café.c:12"]);
+    }
+
+    #[test]
+    fn parse_and_rebuild_comments() {
+        let content = r#"# Translator comment
+#
+# noqa:blank
+#. Extracted comment
+#: src/main.rs:42 src/lib.rs:7
+#, fuzzy, c-format, no-wrap
+#| msgid "old %s"
+msgid "hello, %s"
+msgstr "bonjour, %s"
+"#;
+        let mut parser = Parser::new(content.as_bytes());
+        let entries = parser.by_ref().collect::<Vec<Entry>>();
+        assert_eq!(
+            entries[0].comments,
+            vec!["Translator comment", "", "noqa:blank"]
+        );
+        assert_eq!(entries[0].extracted_comments, vec!["Extracted comment"]);
+        assert_eq!(entries[0].noqa_rules, vec!["blank"]);
+        assert_eq!(entries[0].to_po_block(), content);
+        // Obsolete entry.
+        let content = r#"# Translator comment
+#~ msgid "hello"
+#~ msgstr "bonjour"
+"#;
+        let mut parser = Parser::new(content.as_bytes());
+        let entries = parser.by_ref().collect::<Vec<Entry>>();
+        assert!(entries[0].obsolete);
+        assert_eq!(entries[0].to_po_block(), content);
+        // Comments separated by a tab.
+        let content =
+            "#\tTranslator comment\n#.\tExtracted comment\nmsgid \"hello\"\nmsgstr \"bonjour\"\n";
+        let entries = Parser::new(content.as_bytes()).collect::<Vec<Entry>>();
+        assert_eq!(entries[0].comments, vec!["\tTranslator comment"]);
+        assert_eq!(entries[0].to_po_block(), content);
+        // Trailing whitespace is kept, and comments are decoded with the charset.
+        let content = "msgid \"\"\nmsgstr \"Content-Type: text/plain; charset=ISO-8859-15\\n\"\n\n\
+            # Commentaire traduit  \n#. Extrait \t\nmsgid \"hello\"\nmsgstr \"bonjour\"\n";
+        let content = content.replace("traduit", "traduit à vérifier");
+        let content_iso = encoding_rs::ISO_8859_15.encode(&content).0;
+        let entries = Parser::new(content_iso.as_ref()).collect::<Vec<Entry>>();
+        assert_eq!(
+            entries[1].comments,
+            vec!["Commentaire traduit à vérifier  "]
+        );
+        assert_eq!(entries[1].extracted_comments, vec!["Extrait \t"]);
+        assert_eq!(
+            entries[1].to_po_block(),
+            content.split_once("\n\n").unwrap().1
+        );
+    }
+
+    #[test]
+    fn parse_obsolete_with_context() {
+        let content = r#"
+msgctxt "x"
+msgid "y"
+msgstr "z"
+
+#~ msgctxt "x"
+#~ msgid "y"
+#~ msgstr ""
+#~ "old "
+#~ "z"
+
+#~ msgid "y"
+#~ msgstr "no context"
+"#;
+        let mut parser = Parser::new(content.as_bytes());
+        let entries = parser.by_ref().collect::<Vec<Entry>>();
+        assert_eq!(entries.len(), 3);
+        assert!(!entries[0].obsolete);
+        assert_eq!(entries[0].msgctxt, Some(Message::new(2, "x", 0..0)));
+        assert!(entries[1].obsolete);
+        assert_eq!(entries[1].line_number, 6);
+        assert_eq!(entries[1].msgctxt, Some(Message::new(6, "x", 0..0)));
+        assert_eq!(entries[1].msgid, Some(Message::new(7, "y", 0..0)));
+        assert_eq!(
+            entries[1].msgstr.get(&0),
+            Some(Message::new(8, "old z", 0..0)).as_ref()
+        );
+        assert!(entries[2].obsolete);
+        assert!(entries[2].msgctxt.is_none());
+        assert_eq!(entries[2].msgid, Some(Message::new(12, "y", 0..0)));
+    }
+
+    #[test]
+    fn parse_message_columns() {
+        let content = "\u{feff}msgid \"a\"\nmsgstr \"b\"\n\n#|  msgid \"c\"\nmsgid \"d\"\nmsgstr \"\"\n\"e\"\n\n#~ msgctxt \"f\"\n#~ msgid \"g\"\n#~ msgstr[0] \"h\"\n";
+        let entries: Vec<Entry> = Parser::new(content.as_bytes()).collect();
+        assert_eq!(entries.len(), 3);
+        // The BOM is not counted in the column.
+        assert_eq!(entries[0].msgid.as_ref().map(|m| m.column), Some(1));
+        assert_eq!(entries[0].msgstr[&0].column, 1);
+        assert_eq!(
+            entries[1].previous_msgid.as_ref().map(|m| m.column),
+            Some(5)
+        );
+        assert_eq!(entries[1].msgid.as_ref().map(|m| m.column), Some(1));
+        // Multi-line string: column of the keyword.
+        assert_eq!(entries[1].msgstr[&0].column, 1);
+        assert_eq!(entries[2].msgctxt.as_ref().map(|m| m.column), Some(4));
+        assert_eq!(entries[2].msgid.as_ref().map(|m| m.column), Some(4));
+        assert_eq!(entries[2].msgstr[&0].column, 4);
+    }
+
+    #[test]
+    fn parse_obsolete_previous_messages() {
+        let content = r#"
+#, fuzzy
+#~| msgctxt "old context"
+#~| msgid "old "
+#~| "file"
+#~ msgctxt "context"
+#~ msgid "file"
+#~ msgstr ""
+#~ "fichier"
+
+msgid "hello"
+msgstr "bonjour"
+"#;
+        let mut parser = Parser::new(content.as_bytes());
+        let entries = parser.by_ref().collect::<Vec<Entry>>();
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].obsolete);
+        assert!(entries[0].fuzzy);
+        assert_eq!(
+            entries[0].previous_msgctxt,
+            Some(Message::new(3, "old context", 0..0))
+        );
+        assert_eq!(
+            entries[0].previous_msgid,
+            Some(Message::new(4, "old file", 0..0))
+        );
+        assert_eq!(entries[0].msgctxt, Some(Message::new(6, "context", 0..0)));
+        assert_eq!(entries[0].msgid, Some(Message::new(7, "file", 0..0)));
+        assert_eq!(entries[0].msgstr[&0], Message::new(8, "fichier", 0..0));
+        assert_eq!(
+            entries[0].to_po_block(),
+            "#, fuzzy\n\
+             #~| msgctxt \"old context\"\n\
+             #~| msgid \"old file\"\n\
+             #~ msgctxt \"context\"\n\
+             #~ msgid \"file\"\n\
+             #~ msgstr \"fichier\"\n"
+        );
+        assert!(!entries[1].obsolete);
+        assert!(entries[1].previous_msgid.is_none());
+        assert_eq!(entries[1].msgid, Some(Message::new(11, "hello", 0..0)));
+        assert_eq!(entries[1].msgstr[&0], Message::new(12, "bonjour", 0..0));
+    }
+
+    #[test]
+    fn parse_previous_messages() {
+        let content = r#"
+#, fuzzy
+#| msgctxt "old context"
+#| msgid "old "
+#| "file"
+#| msgid_plural "old files"
+msgctxt "context"
+msgid "file"
+msgid_plural "files"
+msgstr[0] "fichier"
+msgstr[1] "fichiers"
+
+#, fuzzy
+#| msgid "hello"
+msgid "hello, %s"
+msgstr "bonjour"
+"#;
+        let mut parser = Parser::new(content.as_bytes());
+        let entries = parser.by_ref().collect::<Vec<Entry>>();
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].fuzzy);
+        assert_eq!(
+            entries[0].previous_msgctxt,
+            Some(Message::new(3, "old context", 0..0))
+        );
+        assert_eq!(
+            entries[0].previous_msgid,
+            Some(Message::new(4, "old file", 0..0))
+        );
+        assert_eq!(
+            entries[0].previous_msgid_plural,
+            Some(Message::new(6, "old files", 0..0))
+        );
+        assert_eq!(entries[0].msgctxt, Some(Message::new(7, "context", 0..0)));
+        assert_eq!(entries[0].msgid, Some(Message::new(8, "file", 0..0)));
+        assert_eq!(
+            entries[0].msgid_plural,
+            Some(Message::new(9, "files", 0..0))
+        );
+        assert!(entries[1].previous_msgctxt.is_none());
+        assert_eq!(
+            entries[1].previous_msgid,
+            Some(Message::new(14, "hello", 0..0))
+        );
+        assert!(entries[1].previous_msgid_plural.is_none());
+        assert_eq!(entries[1].msgid, Some(Message::new(15, "hello, %s", 0..0)));
+        assert_eq!(
+            entries[1].msgstr.get(&0),
+            Some(Message::new(16, "bonjour", 0..0)).as_ref()
+        );
+    }
+
     #[test]
     fn byte_range_identity_roundtrip() {
         // Parsing then writing with no replacements must yield byte-identical output.
@@ -856,4 +1404,58 @@ msgstr ""
             Some(Message::new(5, "bonjour le monde", 0..0)).as_ref()
         );
     }
+
+    #[test]
+    fn parse_escaped_quotes() {
+        // The last line has a trailing space after the closing quote.
+        let content = r#"
+msgid "a \"b\" c"
+msgstr "say \"hi\""
+
+msgid ""
+"\"quoted\""
+msgstr "\"cité\"" 
+"#;
+        let mut parser = Parser::new(content.as_bytes());
+        let entries = parser.by_ref().collect::<Vec<Entry>>();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].msgid, Some(Message::new(2, "a \"b\" c", 0..0)));
+        assert_eq!(
+            entries[0].msgstr.get(&0),
+            Some(Message::new(3, "say \"hi\"", 0..0)).as_ref()
+        );
+        assert_eq!(entries[1].msgid, Some(Message::new(5, "\"quoted\"", 0..0)));
+        assert_eq!(
+            entries[1].msgstr.get(&0),
+            Some(Message::new(7, "\"cité\"", 0..0)).as_ref()
+        );
+    }
+
+    #[test]
+    fn parse_malformed_plural_index() {
+        for line in ["msgstr[]", "msgstr[x]", "msgstr[99999999999]", "msgstr[0"] {
+            let content = format!(
+                r#"
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+{line} "%d fichiers"
+"suite"
+"#
+            );
+            let mut parser = Parser::new(content.as_bytes());
+            let entries = parser.by_ref().collect::<Vec<Entry>>();
+            assert_eq!(entries.len(), 1, "{line}");
+            assert!(entries[0].malformed_plural_index, "{line}");
+            assert_eq!(entries[0].msgstr.len(), 1, "{line}");
+            assert_eq!(
+                entries[0].msgstr.get(&0),
+                Some(Message::new(4, "%d fichier", 0..0)).as_ref(),
+                "{line}"
+            );
+        }
+        let mut parser = Parser::new(b"msgid \"a\"\nmsgstr[1] \"b\"\n");
+        let entries = parser.by_ref().collect::<Vec<Entry>>();
+        assert!(!entries[0].malformed_plural_index);
+    }
 }
@@ -0,0 +1,358 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Parser and evaluator of the plural expression in header (`plural=` in `Plural-Forms`).
+//!
+//! The grammar is the C subset used by gettext: variable `n`, unsigned integers,
+//! operators `! * / % + - < <= > >= == != && ||`, the conditional operator `?:`
+//! and parentheses.
+
+/// Binary operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinOp {
+    Mul,
+    Div,
+    Rem,
+    Add,
+    Sub,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+    And,
+    Or,
+}
+
+/// Plural expression.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PluralExpr {
+    N,
+    Num(u64),
+    Not(Box<PluralExpr>),
+    Binary(BinOp, Box<PluralExpr>, Box<PluralExpr>),
+    Cond(Box<PluralExpr>, Box<PluralExpr>, Box<PluralExpr>),
+}
+
+/// Precedence levels of binary operators, from the lowest to the highest.
+const BINARY_OPS: [&[(&str, BinOp)]; 6] = [
+    &[("||", BinOp::Or)],
+    &[("&&", BinOp::And)],
+    &[("==", BinOp::Eq), ("!=", BinOp::Ne)],
+    &[
+        ("<=", BinOp::Le),
+        (">=", BinOp::Ge),
+        ("<", BinOp::Lt),
+        (">", BinOp::Gt),
+    ],
+    &[("+", BinOp::Add), ("-", BinOp::Sub)],
+    &[("*", BinOp::Mul), ("/", BinOp::Div), ("%", BinOp::Rem)],
+];
+
+/// Maximum nesting depth of an expression (parentheses, `!` and `?:`), to prevent
+/// a stack overflow when parsing or evaluating a malicious expression.
+const MAX_DEPTH: usize = 100;
+
+/// Maximum number of binary operators in an expression: a long chain of operators
+/// builds a deep tree, which is evaluated recursively.
+const MAX_BINARY_OPS: usize = 1000;
+
+struct ExprParser<'a> {
+    expr: &'a str,
+    pos: usize,
+    /// Current nesting depth.
+    depth: usize,
+    /// Number of binary operators parsed.
+    binary_ops: usize,
+}
+
+impl ExprParser<'_> {
+    /// Skip whitespace and return the remaining input.
+    fn rest(&mut self) -> &str {
+        let rest = &self.expr[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+        &self.expr[self.pos..]
+    }
+
+    /// Consume the token if the remaining input starts with it.
+    fn eat(&mut self, token: &str) -> bool {
+        let rest = self.rest();
+        // Do not take `!` from `!=` or `<`/`>` from `<=`/`>=`.
+        if rest.starts_with(token) && !(token.len() == 1 && rest[1..].starts_with('=')) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Build an error for the current position.
+    fn error(&mut self) -> String {
+        match self.rest().chars().next() {
+            Some(c) => format!("unexpected character '{c}' at position {}", self.pos + 1),
+            None => "unexpected end of expression".to_string(),
+        }
+    }
+
+    /// Parse a nested expression with the function `parse`, checking the nesting
+    /// depth.
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<PluralExpr, String>,
+    ) -> Result<PluralExpr, String> {
+        if self.depth >= MAX_DEPTH {
+            return Err("expression too deeply nested".to_string());
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Parse a conditional expression: `cond ? expr : expr`.
+    fn parse_cond(&mut self) -> Result<PluralExpr, String> {
+        self.nested(Self::parse_cond_inner)
+    }
+
+    /// Parse a conditional expression, without checking the nesting depth.
+    fn parse_cond_inner(&mut self) -> Result<PluralExpr, String> {
+        let cond = self.parse_binary(0)?;
+        if !self.eat("?") {
+            return Ok(cond);
+        }
+        let if_true = self.parse_cond()?;
+        if !self.eat(":") {
+            return Err(self.error());
+        }
+        let if_false = self.parse_cond()?;
+        Ok(PluralExpr::Cond(
+            Box::new(cond),
+            Box::new(if_true),
+            Box::new(if_false),
+        ))
+    }
+
+    /// Parse a binary expression with operators of the given precedence level (or higher).
+    fn parse_binary(&mut self, level: usize) -> Result<PluralExpr, String> {
+        if level >= BINARY_OPS.len() {
+            return self.parse_unary();
+        }
+        let mut left = self.parse_binary(level + 1)?;
+        'outer: loop {
+            for (token, op) in BINARY_OPS[level] {
+                if self.eat(token) {
+                    self.binary_ops += 1;
+                    if self.binary_ops > MAX_BINARY_OPS {
+                        return Err("expression too long".to_string());
+                    }
+                    let right = self.parse_binary(level + 1)?;
+                    left = PluralExpr::Binary(*op, Box::new(left), Box::new(right));
+                    continue 'outer;
+                }
+            }
+            return Ok(left);
+        }
+    }
+
+    /// Parse a unary expression: `!expr`, `(expr)`, `n` or a number.
+    fn parse_unary(&mut self) -> Result<PluralExpr, String> {
+        if self.eat("!") {
+            return Ok(PluralExpr::Not(Box::new(self.nested(Self::parse_unary)?)));
+        }
+        if self.eat("(") {
+            let expr = self.parse_cond()?;
+            if !self.eat(")") {
+                return Err(self.error());
+            }
+            return Ok(expr);
+        }
+        if self.eat("n") {
+            return Ok(PluralExpr::N);
+        }
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error());
+        }
+        let value = rest[..len]
+            .parse::<u64>()
+            .map_err(|_| format!("invalid number '{}'", &rest[..len]))?;
+        self.pos += len;
+        Ok(PluralExpr::Num(value))
+    }
+}
+
+impl PluralExpr {
+    /// Parse a plural expression, for example: `(n != 1)`.
+    pub fn parse(expr: &str) -> Result<Self, String> {
+        let mut parser = ExprParser {
+            expr,
+            pos: 0,
+            depth: 0,
+            binary_ops: 0,
+        };
+        let result = parser.parse_cond()?;
+        if parser.rest().is_empty() {
+            Ok(result)
+        } else {
+            Err(parser.error())
+        }
+    }
+
+    /// Evaluate the expression for the given number `n`.
+    ///
+    /// Returns `None` in case of division by zero.
+    pub fn eval(&self, n: u64) -> Option<u64> {
+        match self {
+            Self::N => Some(n),
+            Self::Num(value) => Some(*value),
+            Self::Not(expr) => Some(u64::from(expr.eval(n)? == 0)),
+            Self::Cond(cond, if_true, if_false) => {
+                if cond.eval(n)? == 0 {
+                    if_false.eval(n)
+                } else {
+                    if_true.eval(n)
+                }
+            }
+            Self::Binary(op, left, right) => {
+                let left = left.eval(n)?;
+                // Short-circuit evaluation, like in C.
+                match op {
+                    BinOp::And if left == 0 => return Some(0),
+                    BinOp::Or if left != 0 => return Some(1),
+                    _ => {}
+                }
+                let right = right.eval(n)?;
+                Some(match op {
+                    BinOp::Mul => left.wrapping_mul(right),
+                    BinOp::Div => left.checked_div(right)?,
+                    BinOp::Rem => left.checked_rem(right)?,
+                    BinOp::Add => left.wrapping_add(right),
+                    BinOp::Sub => left.wrapping_sub(right),
+                    BinOp::Lt => u64::from(left < right),
+                    BinOp::Le => u64::from(left <= right),
+                    BinOp::Gt => u64::from(left > right),
+                    BinOp::Ge => u64::from(left >= right),
+                    BinOp::Eq => u64::from(left == right),
+                    BinOp::Ne => u64::from(left != right),
+                    BinOp::And | BinOp::Or => u64::from(right != 0),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(expr: &str, n: u64) -> Option<u64> {
+        PluralExpr::parse(expr).unwrap().eval(n)
+    }
+
+    #[test]
+    fn test_parse_simple() {
+        assert_eq!(PluralExpr::parse("0"), Ok(PluralExpr::Num(0)));
+        assert_eq!(PluralExpr::parse(" n "), Ok(PluralExpr::N));
+        assert_eq!(
+            PluralExpr::parse("(n != 1)"),
+            Ok(PluralExpr::Binary(
+                BinOp::Ne,
+                Box::new(PluralExpr::N),
+                Box::new(PluralExpr::Num(1))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            PluralExpr::parse(""),
+            Err("unexpected end of expression".to_string())
+        );
+        assert_eq!(
+            PluralExpr::parse("(n != 1"),
+            Err("unexpected end of expression".to_string())
+        );
+        assert_eq!(
+            PluralExpr::parse("n > 1)"),
+            Err("unexpected character ')' at position 6".to_string())
+        );
+        assert_eq!(
+            PluralExpr::parse("n = 1"),
+            Err("unexpected character '=' at position 3".to_string())
+        );
+        assert_eq!(
+            PluralExpr::parse("n ? 1"),
+            Err("unexpected end of expression".to_string())
+        );
+        assert_eq!(
+            PluralExpr::parse("x"),
+            Err("unexpected character 'x' at position 1".to_string())
+        );
+        assert!(PluralExpr::parse("99999999999999999999999").is_err());
+    }
+
+    #[test]
+    fn test_parse_too_deep() {
+        let expr = format!("{}n{}", "(".repeat(5000), ")".repeat(5000));
+        assert_eq!(
+            PluralExpr::parse(&expr),
+            Err("expression too deeply nested".to_string())
+        );
+        let expr = format!("{}n", "!".repeat(5000));
+        assert_eq!(
+            PluralExpr::parse(&expr),
+            Err("expression too deeply nested".to_string())
+        );
+        let expr = format!("{}0{}", "n ? 1 : (".repeat(5000), ")".repeat(5000));
+        assert_eq!(
+            PluralExpr::parse(&expr),
+            Err("expression too deeply nested".to_string())
+        );
+        let expr = format!("{}n{}", "(".repeat(50), ")".repeat(50));
+        assert_eq!(PluralExpr::parse(&expr), Ok(PluralExpr::N));
+        let expr = "n + ".repeat(100_000) + "n";
+        assert_eq!(
+            PluralExpr::parse(&expr),
+            Err("expression too long".to_string())
+        );
+    }
+
+    #[test]
+    fn test_eval() {
+        assert_eq!(eval("0", 5), Some(0));
+        assert_eq!(eval("n != 1", 1), Some(0));
+        assert_eq!(eval("n != 1", 2), Some(1));
+        assert_eq!(eval("n > 1", 0), Some(0));
+        assert_eq!(eval("!n", 0), Some(1));
+        assert_eq!(eval("2 + 3 * 4 - 1", 0), Some(13));
+        assert_eq!(eval("(2 + 3) * 4 % 7", 0), Some(6));
+        assert_eq!(eval("n / 0", 1), None);
+        assert_eq!(eval("n % 0", 1), None);
+        assert_eq!(eval("n == 0 || 1 / n", 0), Some(1));
+        assert_eq!(eval("n != 0 && 1 / n", 0), Some(0));
+        assert_eq!(eval("n == 1 ? 0 : n == 2 ? 1 : 2", 2), Some(1));
+        assert_eq!(eval("n == 1 ? 0 : n == 2 ? 1 : 2", 3), Some(2));
+    }
+
+    #[test]
+    fn test_eval_russian() {
+        let expr = PluralExpr::parse(
+            "(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2)",
+        )
+        .unwrap();
+        assert_eq!(expr.eval(1), Some(0));
+        assert_eq!(expr.eval(21), Some(0));
+        assert_eq!(expr.eval(11), Some(2));
+        assert_eq!(expr.eval(2), Some(1));
+        assert_eq!(expr.eval(24), Some(1));
+        assert_eq!(expr.eval(12), Some(2));
+        assert_eq!(expr.eval(5), Some(2));
+        assert_eq!(expr.eval(0), Some(2));
+    }
+}
@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Progress bar displayed on standard error while files are processed.
+
+use std::io::{IsTerminal, Write as _};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Width of the progress bar (number of chars).
+const BAR_WIDTH: usize = 30;
+
+/// Minimum delay between two refreshes of the progress bar.
+const REFRESH_DELAY: Duration = Duration::from_millis(100);
+
+/// Progress of files processed in parallel, displayed on standard error.
+///
+/// The progress bar is displayed only if enabled and if standard error is a terminal,
+/// so that standard output (e.g. JSON) is never affected.
+pub struct Progress {
+    total: usize,
+    done: AtomicUsize,
+    enabled: bool,
+    /// Time of the last refresh of the progress bar.
+    last_draw: Mutex<Option<Instant>>,
+}
+
+impl Progress {
+    /// Create a progress for `total` files; nothing is displayed if `enabled` is
+    /// `false` or if standard error is not a terminal.
+    pub fn new(total: usize, enabled: bool) -> Self {
+        Self {
+            total,
+            done: AtomicUsize::new(0),
+            enabled: enabled && std::io::stderr().is_terminal(),
+            last_draw: Mutex::new(None),
+        }
+    }
+
+    /// Increment the number of files processed and refresh the progress bar (at most
+    /// every 100 milliseconds, and always for the last file).
+    pub fn inc(&self) {
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        if !self.enabled {
+            return;
+        }
+        let Ok(mut last_draw) = self.last_draw.try_lock() else {
+            return;
+        };
+        if done < self.total && last_draw.is_some_and(|last| last.elapsed() < REFRESH_DELAY) {
+            return;
+        }
+        *last_draw = Some(Instant::now());
+        let mut stderr = std::io::stderr().lock();
+        let _ = write!(stderr, "\r{}", progress_line(done, self.total));
+        let _ = stderr.flush();
+    }
+
+    /// Clear the progress bar, so that the final summary is displayed as usual.
+    pub fn finish(&self) {
+        if self.enabled {
+            let mut stderr = std::io::stderr().lock();
+            let _ = write!(stderr, "\r\x1b[2K");
+            let _ = stderr.flush();
+        }
+    }
+}
+
+/// Build the progress line: the bar followed by the number of files processed.
+fn progress_line(done: usize, total: usize) -> String {
+    let filled = (done * BAR_WIDTH).checked_div(total).unwrap_or(BAR_WIDTH);
+    format!(
+        "[{}{}] {done}/{total} files",
+        "█".repeat(filled),
+        "░".repeat(BAR_WIDTH - filled),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_progress_line() {
+        assert_eq!(
+            progress_line(0, 4),
+            format!("[{}] 0/4 files", "░".repeat(BAR_WIDTH))
+        );
+        assert_eq!(
+            progress_line(2, 4),
+            format!("[{}{}] 2/4 files", "█".repeat(15), "░".repeat(15))
+        );
+        assert_eq!(
+            progress_line(0, 0),
+            format!("[{}] 0/0 files", "█".repeat(BAR_WIDTH))
+        );
+    }
+
+    #[test]
+    fn test_progress_inc_parallel() {
+        let progress = Progress::new(1000, false);
+        (0..1000).into_par_iter().for_each(|_| progress.inc());
+        progress.finish();
+        assert_eq!(progress.done.load(Ordering::Relaxed), 1000);
+    }
+}
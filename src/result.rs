@@ -6,15 +6,55 @@
 
 use std::{
     collections::{BTreeMap, HashSet},
+    fmt::Write,
+    io::{BufWriter, IsTerminal, Write as _},
     path::{Path, PathBuf},
     time::Duration,
 };
 
+use serde::{Deserialize, Serialize};
+
 use crate::diagnostic::{Diagnostic, Severity};
+use crate::junit;
 use crate::sarif;
 use crate::{args, rules::rule::Rules};
 use crate::{checker::CheckFileResult, config::Config};
 
+/// Summary of the check, displayed in JSON output with `--json-summary`.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct CheckSummary {
+    files_checked: usize,
+    files_with_errors: usize,
+    errors: usize,
+    warnings: usize,
+    info: usize,
+    elapsed_ms: u64,
+}
+
+impl CheckSummary {
+    /// Count the files checked and the diagnostics by severity.
+    fn new(result: &[CheckFileResult], elapsed: &Duration) -> Self {
+        let mut summary = Self {
+            files_checked: result.len(),
+            elapsed_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+            ..Self::default()
+        };
+        for file in result {
+            if !file.diagnostics.is_empty() {
+                summary.files_with_errors += 1;
+            }
+            for diag in &file.diagnostics {
+                match diag.severity {
+                    Severity::Info => summary.info += 1,
+                    Severity::Warning => summary.warnings += 1,
+                    Severity::Error => summary.errors += 1,
+                }
+            }
+        }
+        summary
+    }
+}
+
 /// Display the settings used to check a file.
 fn display_settings(path: &Path, config: &Config, rules: &Rules) {
     println!("Settings for file: {}", path.display());
@@ -89,13 +129,24 @@ fn display_diagnostics_human(result: &[CheckFileResult], args: &args::CheckArgs)
             });
         }
     }
+    // Hyperlinks are used only in a terminal; the relative paths of diagnostics are
+    // relative to the `--relative-to` directory.
+    let link_base = (args.hyperlinks && std::io::stdout().is_terminal()).then(|| {
+        args.relative_to
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("."))
+    });
     for (diag, file_unsafe_fixes) in diags {
         // `Diagnostic`'s Display impl already ends each diagnostic with a
         // newline-terminated `|` bar, so use `print!` here to keep the optional
         // "Note: no fix available." line attached to that bar without an empty
         // line between them. The trailing `println!()` re-creates the blank
         // separator before the next diagnostic.
-        print!("{diag}");
+        print!(
+            "{}",
+            diag.display(link_base.as_deref())
+                .with_highlight_style(args.highlight_style)
+        );
         if args.fix {
             if diag.fix.as_ref().is_some_and(|f| !f.safe) && !file_unsafe_fixes {
                 println!("Note: unsafe fix available, use --unsafe-fixes to apply it.");
@@ -143,10 +194,64 @@ fn display_file_stats(file_errors: &[(PathBuf, usize, usize, usize)]) {
     }
 }
 
-/// Display diagnostics in JSON format.
-fn display_diagnostics_json(result: &[CheckFileResult], _args: &args::CheckArgs) {
-    let diags: Vec<&Diagnostic> = result.iter().flat_map(|x| &x.diagnostics).collect();
-    println!("{}", serde_json::to_string(&diags).unwrap_or_default());
+/// Write the JSON output: an array of diagnostics, or an object with the summary
+/// and the diagnostics if `summary` is set.
+///
+/// The diagnostics are serialized one by one, so the whole output is never built
+/// in memory.
+fn write_json(
+    writer: &mut impl std::io::Write,
+    result: &[CheckFileResult],
+    summary: Option<&CheckSummary>,
+) -> std::io::Result<()> {
+    if let Some(summary) = summary {
+        writer.write_all(b"{\"summary\":")?;
+        serde_json::to_writer(&mut *writer, summary)?;
+        writer.write_all(b",\"diagnostics\":")?;
+    }
+    writer.write_all(b"[")?;
+    for (index, diag) in result.iter().flat_map(|x| &x.diagnostics).enumerate() {
+        if index > 0 {
+            writer.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut *writer, diag)?;
+    }
+    writer.write_all(b"]")?;
+    if summary.is_some() {
+        writer.write_all(b"}")?;
+    }
+    writer.write_all(b"\n")
+}
+
+/// Write the diagnostics in NDJSON format (newline-delimited JSON): one JSON object
+/// per line.
+fn write_ndjson(
+    writer: &mut impl std::io::Write,
+    result: &[CheckFileResult],
+) -> std::io::Result<()> {
+    for diag in result.iter().flat_map(|x| &x.diagnostics) {
+        serde_json::to_writer(&mut *writer, diag)?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Display diagnostics in JSON format on `out`.
+fn display_diagnostics_json(
+    out: &mut impl std::io::Write,
+    result: &[CheckFileResult],
+    args: &args::CheckArgs,
+    summary: &CheckSummary,
+) {
+    let mut writer = BufWriter::new(out);
+    let _ = write_json(&mut writer, result, args.json_summary.then_some(summary))
+        .and_then(|()| writer.flush());
+}
+
+/// Display diagnostics in NDJSON format on `out`.
+fn display_diagnostics_ndjson(out: &mut impl std::io::Write, result: &[CheckFileResult]) {
+    let mut writer = BufWriter::new(out);
+    let _ = write_ndjson(&mut writer, result).and_then(|()| writer.flush());
 }
 
 /// Display diagnostics in SARIF format.
@@ -155,6 +260,97 @@ fn display_diagnostics_sarif(result: &[CheckFileResult]) {
     println!("{}", serde_json::to_string(&sarif_log).unwrap_or_default());
 }
 
+/// Display diagnostics in `JUnit` XML format.
+fn display_diagnostics_junit(result: &[CheckFileResult]) {
+    print!("{}", junit::build_junit(result));
+}
+
+/// Escape the data of a GitHub Actions workflow command.
+fn github_escape_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a GitHub Actions workflow command.
+fn github_escape_property(s: &str) -> String {
+    github_escape_data(s)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+/// Format a diagnostic as a GitHub Actions workflow command (annotation).
+fn github_annotation(diag: &Diagnostic) -> String {
+    let command = match diag.severity {
+        Severity::Info => "notice",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    };
+    let line = diag
+        .lines
+        .iter()
+        .find(|l| l.line_number > 0)
+        .map_or(1, |l| l.line_number);
+    format!(
+        "::{command} file={},line={line},title={}::{}",
+        github_escape_property(&diag.path.display().to_string()),
+        github_escape_property(diag.rule),
+        github_escape_data(&diag.build_message()),
+    )
+}
+
+/// Display diagnostics as GitHub Actions workflow commands.
+fn display_diagnostics_github(result: &[CheckFileResult]) {
+    for diag in result.iter().flat_map(|x| &x.diagnostics) {
+        println!("{}", github_annotation(diag));
+    }
+}
+
+/// Build a TAP (Test Anything Protocol) version 13 stream: each file is a test,
+/// and diagnostics are reported in a YAML block after the failing test.
+fn build_tap(result: &[CheckFileResult]) -> String {
+    let mut tap = format!("TAP version 13\n1..{}\n", result.len());
+    for (index, file) in result.iter().enumerate() {
+        let status = if file.diagnostics.is_empty() {
+            "ok"
+        } else {
+            "not ok"
+        };
+        let _ = writeln!(tap, "{status} {} - {}", index + 1, file.path.display());
+        if file.diagnostics.is_empty() {
+            continue;
+        }
+        tap.push_str("  ---\n  diagnostics:\n");
+        for diag in &file.diagnostics {
+            let severity = match diag.severity {
+                Severity::Info => "info",
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+            };
+            let line = diag
+                .lines
+                .iter()
+                .find(|l| l.line_number > 0)
+                .map_or(0, |l| l.line_number);
+            let _ = writeln!(tap, "    - rule: {}", diag.rule);
+            let _ = writeln!(tap, "      severity: {severity}");
+            let _ = writeln!(tap, "      line: {line}");
+            let _ = writeln!(
+                tap,
+                "      message: {}",
+                serde_json::to_string(&diag.build_message()).unwrap_or_default()
+            );
+        }
+        tap.push_str("  ...\n");
+    }
+    tap
+}
+
+/// Display diagnostics in TAP format.
+fn display_diagnostics_tap(result: &[CheckFileResult]) {
+    print!("{}", build_tap(result));
+}
+
 /// Display misspelled words.
 fn display_misspelled_words(result: &[CheckFileResult], _args: &args::CheckArgs) {
     let hash_misspelled_words: HashSet<_> = result
@@ -213,51 +409,42 @@ fn display_fix_summary(result: &[CheckFileResult], elapsed: &Duration) {
 }
 
 /// Display the result of the checks and return the appropriate exit code.
+///
+/// The diagnostics in JSON and NDJSON formats are written on `out` (standard output,
+/// or a buffer in tests).
 #[allow(clippy::too_many_lines)]
 pub fn display_result(
+    out: &mut impl std::io::Write,
     result: &[CheckFileResult],
     args: &args::CheckArgs,
     elapsed: &Duration,
 ) -> i32 {
-    let mut files_checked = 0;
-    let mut files_with_errors = 0;
-    let mut count_info = 0;
-    let mut count_warnings = 0;
-    let mut count_errors = 0;
+    let summary = CheckSummary::new(result, elapsed);
+    let CheckSummary {
+        files_checked,
+        files_with_errors,
+        errors: count_errors,
+        warnings: count_warnings,
+        info: count_info,
+        ..
+    } = summary;
     let mut file_errors: Vec<(PathBuf, usize, usize, usize)> = Vec::new();
     for file in result {
         if args.show_settings && !args.quiet {
             display_settings(file.path.as_path(), &file.config, &file.rules);
         }
-        let mut count_file_info = 0;
-        let mut count_file_warnings = 0;
-        let mut count_file_errors = 0;
-        files_checked += 1;
-        if !file.diagnostics.is_empty() {
-            files_with_errors += 1;
-            for diag in &file.diagnostics {
-                match diag.severity {
-                    Severity::Info => {
-                        count_info += 1;
-                        count_file_info += 1;
-                    }
-                    Severity::Warning => {
-                        count_warnings += 1;
-                        count_file_warnings += 1;
-                    }
-                    Severity::Error => {
-                        count_errors += 1;
-                        count_file_errors += 1;
-                    }
-                }
-            }
-        }
         if args.file_stats {
+            let count = |severity: Severity| {
+                file.diagnostics
+                    .iter()
+                    .filter(|diag| diag.severity == severity)
+                    .count()
+            };
             file_errors.push((
                 file.path.clone(),
-                count_file_info,
-                count_file_warnings,
-                count_file_errors,
+                count(Severity::Info),
+                count(Severity::Warning),
+                count(Severity::Error),
             ));
         }
     }
@@ -277,7 +464,12 @@ pub fn display_result(
             }
             args::CheckOutputFormat::Json => {
                 if !args.no_errors {
-                    display_diagnostics_json(result, args);
+                    display_diagnostics_json(out, result, args, &summary);
+                }
+            }
+            args::CheckOutputFormat::Ndjson => {
+                if !args.no_errors {
+                    display_diagnostics_ndjson(out, result);
                 }
             }
             args::CheckOutputFormat::Sarif => {
@@ -285,6 +477,21 @@ pub fn display_result(
                     display_diagnostics_sarif(result);
                 }
             }
+            args::CheckOutputFormat::Github => {
+                if !args.no_errors {
+                    display_diagnostics_github(result);
+                }
+            }
+            args::CheckOutputFormat::Tap => {
+                if !args.no_errors {
+                    display_diagnostics_tap(result);
+                }
+            }
+            args::CheckOutputFormat::Junit => {
+                if !args.no_errors {
+                    display_diagnostics_junit(result);
+                }
+            }
             args::CheckOutputFormat::Misspelled => {
                 if !args.no_errors {
                     display_misspelled_words(result, args);
@@ -292,9 +499,18 @@ pub fn display_result(
             }
         }
     }
+    let count_problems = count_errors + count_warnings + count_info;
+    let exit_code = i32::from(match args.max_problems {
+        Some(max_problems) => {
+            count_problems > max_problems
+                || (args.error_on_warning && count_errors + count_warnings > 0)
+        }
+        None if args.error_on_warning => count_errors + count_warnings > 0,
+        None => count_problems > 0,
+    });
     if args.fix && !args.quiet && args.output == args::CheckOutputFormat::Human {
         display_fix_summary(result, elapsed);
-        return i32::from(files_with_errors != 0);
+        return exit_code;
     }
     if files_with_errors == 0 {
         if !args.quiet && args.output == args::CheckOutputFormat::Human {
@@ -321,7 +537,7 @@ pub fn display_result(
         if args.output == args::CheckOutputFormat::Misspelled {
             return 0;
         }
-        1
+        exit_code
     }
 }
 
@@ -329,16 +545,22 @@ pub fn display_result(
 mod tests {
     use super::*;
     use crate::diagnostic::Diagnostic;
+    use crate::po::message::Message;
 
     fn default_check_args() -> args::CheckArgs {
         args::CheckArgs {
             files: vec![],
+            exclude: vec![],
+            files_from: None,
             show_settings: false,
             config: None,
             no_config: false,
             fuzzy: false,
             noqa: false,
             obsolete: false,
+            only_untranslated: false,
+            only_fuzzy: false,
+            pot: false,
             select: None,
             ignore: None,
             path_msgfmt: None,
@@ -346,10 +568,23 @@ mod tests {
             path_words: None,
             force_trans_file: None,
             no_trans_file: None,
+            glossary_dir: None,
             lang_id: None,
+            lang_str: None,
             langs: None,
+            spell_suggest: false,
+            spell_min_length: None,
+            spell_split_identifiers: false,
+            words_keep_underscore: false,
             short_factor: None,
             long_factor: None,
+            length_ratio_min: None,
+            length_ratio_max: None,
+            unchanged_allow: None,
+            unchanged_min_words: None,
+            context_max_words: None,
+            number_locales: None,
+            max_obsolete: None,
             severity: vec![],
             punc_ignore_ellipsis: false,
             accelerator: None,
@@ -357,11 +592,20 @@ mod tests {
             sort: args::CheckSort::default(),
             rule_stats: false,
             file_stats: false,
+            relative_to: None,
+            hyperlinks: false,
+            highlight_style: crate::diagnostic::HighlightStyle::default(),
+            diff: None,
             output: args::CheckOutputFormat::default(),
+            json_summary: false,
             quiet: false,
+            max_problems: None,
+            progress: false,
+            error_on_warning: false,
             fix: false,
             unsafe_fixes: false,
             width: None,
+            watch: false,
         }
     }
 
@@ -380,7 +624,7 @@ mod tests {
     #[test]
     fn test_display_result_no_files_returns_zero() {
         let args = default_check_args();
-        let code = display_result(&[], &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &[], &args, &Duration::from_millis(0));
         assert_eq!(code, 0);
     }
 
@@ -388,7 +632,7 @@ mod tests {
     fn test_display_result_all_clean_returns_zero() {
         let args = default_check_args();
         let result = vec![file_result("a.po", vec![]), file_result("b.po", vec![])];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 0);
     }
 
@@ -396,7 +640,7 @@ mod tests {
     fn test_display_result_info_diagnostic_returns_one() {
         let args = default_check_args();
         let result = vec![file_result("a.po", vec![diag("brackets", Severity::Info)])];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 1);
     }
 
@@ -404,7 +648,7 @@ mod tests {
     fn test_display_result_warning_diagnostic_returns_one() {
         let args = default_check_args();
         let result = vec![file_result("a.po", vec![diag("blank", Severity::Warning)])];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 1);
     }
 
@@ -412,7 +656,7 @@ mod tests {
     fn test_display_result_error_diagnostic_returns_one() {
         let args = default_check_args();
         let result = vec![file_result("a.po", vec![diag("escapes", Severity::Error)])];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 1);
     }
 
@@ -425,7 +669,7 @@ mod tests {
             "a.po",
             vec![diag("spelling-str", Severity::Info)],
         )];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 0);
     }
 
@@ -434,7 +678,7 @@ mod tests {
         let mut args = default_check_args();
         args.output = args::CheckOutputFormat::Misspelled;
         let result = vec![file_result("a.po", vec![])];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 0);
     }
 
@@ -443,7 +687,7 @@ mod tests {
         let mut args = default_check_args();
         args.quiet = true;
         let result = vec![file_result("a.po", vec![diag("escapes", Severity::Error)])];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 1);
     }
 
@@ -452,7 +696,7 @@ mod tests {
         let mut args = default_check_args();
         args.no_errors = true;
         let result = vec![file_result("a.po", vec![diag("blank", Severity::Warning)])];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 1);
     }
 
@@ -461,8 +705,86 @@ mod tests {
         let mut args = default_check_args();
         args.output = args::CheckOutputFormat::Json;
         let result = vec![file_result("a.po", vec![diag("escapes", Severity::Error)])];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let mut output = vec![];
+        let code = display_result(&mut output, &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 1);
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(json[0]["rule"], "escapes");
+        // NDJSON output.
+        args.output = args::CheckOutputFormat::Ndjson;
+        let mut output = vec![];
+        let code = display_result(&mut output, &result, &args, &Duration::from_millis(0));
+        assert_eq!(code, 1);
+        assert_eq!(String::from_utf8(output).unwrap().lines().count(), 1);
+    }
+
+    #[test]
+    fn test_build_json_summary() {
+        let result = vec![
+            file_result(
+                "a.po",
+                vec![
+                    diag("escapes", Severity::Error),
+                    diag("brackets", Severity::Info),
+                    diag("brackets", Severity::Info),
+                ],
+            ),
+            file_result("b.po", vec![]),
+            file_result("c.po", vec![diag("blank", Severity::Warning)]),
+        ];
+        let summary = CheckSummary::new(&result, &Duration::from_millis(42));
+        let mut output = vec![];
+        write_json(&mut output, &result, Some(&summary)).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        let summary: CheckSummary = serde_json::from_value(json["summary"].clone()).unwrap();
+        assert_eq!(
+            summary,
+            CheckSummary {
+                files_checked: 3,
+                files_with_errors: 2,
+                errors: 1,
+                warnings: 1,
+                info: 2,
+                elapsed_ms: 42,
+            }
+        );
+        assert_eq!(json["diagnostics"].as_array().unwrap().len(), 4);
+        assert_eq!(json["diagnostics"][0]["rule"], "escapes");
+        // Without summary: array of diagnostics.
+        let mut output = vec![];
+        write_json(&mut output, &result, None).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 4);
+        // No diagnostics: empty array.
+        let mut output = vec![];
+        write_json(&mut output, &[file_result("b.po", vec![])], None).unwrap();
+        assert_eq!(output, b"[]\n");
+    }
+
+    #[test]
+    fn test_write_ndjson() {
+        let result = vec![
+            file_result(
+                "a.po",
+                vec![
+                    diag("escapes", Severity::Error),
+                    diag("brackets", Severity::Info),
+                ],
+            ),
+            file_result("b.po", vec![]),
+            file_result("c.po", vec![diag("blank", Severity::Warning)]),
+        ];
+        let mut output = vec![];
+        write_ndjson(&mut output, &result).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for (line, rule) in lines.iter().zip(["escapes", "brackets", "blank"]) {
+            let json: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert!(json.is_object());
+            assert_eq!(json["rule"], rule);
+        }
+        assert!(output.ends_with('\n'));
     }
 
     #[test]
@@ -470,10 +792,178 @@ mod tests {
         let mut args = default_check_args();
         args.output = args::CheckOutputFormat::Sarif;
         let result = vec![file_result("a.po", vec![diag("escapes", Severity::Error)])];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
+        assert_eq!(code, 1);
+    }
+
+    #[test]
+    fn test_display_result_github_output_returns_one_on_errors() {
+        let mut args = default_check_args();
+        args.output = args::CheckOutputFormat::Github;
+        let result = vec![file_result("a.po", vec![diag("brackets", Severity::Error)])];
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
+        assert_eq!(code, 1);
+    }
+
+    #[test]
+    fn test_display_result_junit_output_returns_one_on_errors() {
+        let mut args = default_check_args();
+        args.output = args::CheckOutputFormat::Junit;
+        let result = vec![file_result("a.po", vec![diag("brackets", Severity::Error)])];
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
+        assert_eq!(code, 1);
+    }
+
+    #[test]
+    fn test_display_result_tap_output_returns_one_on_errors() {
+        let mut args = default_check_args();
+        args.output = args::CheckOutputFormat::Tap;
+        let result = vec![file_result("a.po", vec![diag("brackets", Severity::Error)])];
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 1);
     }
 
+    #[test]
+    fn test_display_result_max_problems() {
+        let mut args = default_check_args();
+        args.quiet = true;
+        args.max_problems = Some(2);
+        let result = vec![
+            file_result("a.po", vec![diag("brackets", Severity::Error)]),
+            file_result("b.po", vec![diag("brackets", Severity::Warning)]),
+        ];
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            0
+        );
+        args.max_problems = Some(1);
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            1
+        );
+        args.max_problems = Some(0);
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            1
+        );
+    }
+
+    #[test]
+    fn test_display_result_error_on_warning() {
+        let mut args = default_check_args();
+        args.quiet = true;
+        args.max_problems = Some(5);
+        let result = vec![file_result("a.po", vec![diag("brackets", Severity::Info)])];
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            0
+        );
+        args.error_on_warning = true;
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            0
+        );
+        let result = vec![file_result(
+            "a.po",
+            vec![diag("brackets", Severity::Warning)],
+        )];
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            1
+        );
+        args.error_on_warning = false;
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            0
+        );
+        let result = vec![file_result("a.po", vec![diag("brackets", Severity::Error)])];
+        args.error_on_warning = true;
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            1
+        );
+    }
+
+    #[test]
+    fn test_display_result_error_on_warning_without_max_problems() {
+        let mut args = default_check_args();
+        args.quiet = true;
+        let result = vec![file_result("a.po", vec![diag("brackets", Severity::Info)])];
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            1
+        );
+        args.error_on_warning = true;
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            0
+        );
+        let result = vec![file_result(
+            "a.po",
+            vec![diag("brackets", Severity::Warning)],
+        )];
+        assert_eq!(
+            display_result(&mut vec![], &result, &args, &Duration::from_millis(0)),
+            1
+        );
+    }
+
+    #[test]
+    fn test_build_tap() {
+        assert_eq!(build_tap(&[]), "TAP version 13\n1..0\n");
+        let result = vec![
+            file_result("a.po", vec![]),
+            file_result("b.po", vec![diag("brackets", Severity::Warning)]),
+            file_result("c.po", vec![]),
+        ];
+        let tap = build_tap(&result);
+        let lines: Vec<&str> = tap.lines().collect();
+        assert_eq!(lines[0], "TAP version 13");
+        assert_eq!(lines[1], "1..3");
+        assert_eq!(lines[2], "ok 1 - a.po");
+        assert_eq!(lines[3], "not ok 2 - b.po");
+        assert_eq!(lines[4], "  ---");
+        assert_eq!(lines[5], "  diagnostics:");
+        assert_eq!(lines[6], "    - rule: brackets");
+        assert_eq!(lines[7], "      severity: warning");
+        assert!(lines[9].starts_with("      message: \""));
+        assert_eq!(lines[10], "  ...");
+        assert_eq!(lines[11], "ok 3 - c.po");
+        assert_eq!(
+            lines
+                .iter()
+                .filter(|l| l.starts_with("ok ") || l.starts_with("not ok "))
+                .count(),
+            result.len()
+        );
+    }
+
+    #[test]
+    fn test_github_annotation() {
+        let diag = Diagnostic::new(
+            Path::new("po/fr,1.po"),
+            "brackets",
+            Severity::Warning,
+            "100% wrong\r\nbrackets: (1 / 0)".to_string(),
+        )
+        .with_msgs(
+            &Message::new(12, "test (x)", 0..0),
+            &Message::new(13, "test", 0..0),
+        );
+        assert_eq!(
+            github_annotation(&diag),
+            "::warning file=po/fr%2C1.po,line=12,title=brackets::100%25 wrong%0D%0Abrackets: (1 / 0)"
+        );
+        // Severity mapping and fallback to line 1 without line.
+        let diag = Diagnostic::new(Path::new("fr.po"), "compilation", Severity::Error, "error");
+        assert_eq!(
+            github_annotation(&diag),
+            "::error file=fr.po,line=1,title=compilation::error"
+        );
+        let diag = Diagnostic::new(Path::new("fr.po"), "fuzzy", Severity::Info, "fuzzy entry");
+        assert!(github_annotation(&diag).starts_with("::notice file=fr.po,line=1,"));
+    }
+
     #[test]
     fn test_display_result_with_rule_and_file_stats_flags() {
         // Just verifying that turning the stats-printing flags on doesn't change the
@@ -491,7 +981,7 @@ mod tests {
             ),
             file_result("b.po", vec![diag("brackets", Severity::Info)]),
         ];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 1);
     }
 
@@ -506,7 +996,7 @@ mod tests {
                 diag("escapes", Severity::Error),
             ],
         )];
-        let code = display_result(&result, &args, &Duration::from_millis(0));
+        let code = display_result(&mut vec![], &result, &args, &Duration::from_millis(0));
         assert_eq!(code, 1);
     }
 }
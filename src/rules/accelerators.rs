@@ -30,11 +30,16 @@ impl RuleChecker for AcceleratorsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check for missing or extra keyboard accelerators in the translation.
     ///
     /// An accelerator is the marker character (`&` by default, configurable with
     /// the `accelerator` option) immediately followed by an alphanumeric
-    /// character; the doubled marker `&&` is an escaped literal ampersand. The
+    /// character; the doubled marker `&&` is an escaped literal ampersand and
+    /// HTML entities like `&amp;` or `&nbsp;` are not accelerators. The
     /// rule only compares the *number* of accelerators, not the letter they
     /// target, because the accelerated letter legitimately differs per language
     /// (e.g. `&File` → `&Fichier`).
@@ -77,7 +82,7 @@ impl RuleChecker for AcceleratorsRule {
                 format!("extra accelerators '{marker}' ({id_count} / {str_count})")
             }
         };
-        self.new_diag(checker, Severity::Warning, msg)
+        self.new_diag(checker, self.severity(), msg)
             .map(|d| {
                 d.with_msgs_hl(
                     msgid,
@@ -157,6 +162,17 @@ msgstr "Poisson && frites"
         assert!(diags.is_empty());
     }
 
+    #[test]
+    fn test_accelerators_html_entity_ok() {
+        let diags = check_accelerators(
+            r#"
+msgid "&Save &amp; quit"
+msgstr "&Enregistrer et quitter"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
     #[test]
     fn test_accelerators_error_noqa() {
         let diags = check_accelerators(
@@ -15,6 +15,13 @@ use crate::po::format::iter::FormatAcronymPos;
 use crate::po::message::Message;
 use crate::rules::rule::RuleChecker;
 
+/// Acronyms that are commonly translated (units, time of day, organizations…):
+/// they are ignored in the source, so their absence in the translation is not
+/// reported (sorted for binary search).
+const TRANSLATED_ACRONYMS: [&str; 14] = [
+    "AM", "EU", "FAQ", "GB", "ID", "KB", "MB", "OK", "PB", "PM", "TB", "UK", "UN", "USA",
+];
+
 pub struct AcronymsRule;
 
 impl RuleChecker for AcronymsRule {
@@ -34,9 +41,16 @@ impl RuleChecker for AcronymsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check that every acronym (all-uppercase word of length ≥ 2) found in
     /// the source string also appears verbatim in the translation.
     ///
+    /// Some acronyms that are commonly translated are ignored, for example `OK`, `ID`,
+    /// `FAQ`, units like `KB`, `MB`, `GB` (`Ko`, `Mo`, `Go` in French) or `AM`/`PM`.
+    ///
     /// When the `force-trans` rule is enabled and a `force-trans-file` is
     /// configured, source acronyms whose lowercase form is listed in that file
     /// are ignored here, since they MUST be translated and would be flagged by
@@ -55,7 +69,7 @@ impl RuleChecker for AcronymsRule {
     /// ```
     ///
     /// Diagnostics reported:
-    /// - [`warning`](Severity::Warning): `acronym '…' must not be translated`
+    /// - [`info`](Severity::Info): `acronym '…' must not be translated`
     fn check_msg(
         &self,
         checker: &Checker,
@@ -68,6 +82,9 @@ impl RuleChecker for AcronymsRule {
         let force_words = checker.force_trans_words.as_ref();
         let mut id_acronyms: HashSet<String> = HashSet::new();
         for word in FormatAcronymPos::new(&msgid.value, entry.format_language) {
+            if TRANSLATED_ACRONYMS.binary_search(&word.s).is_ok() {
+                continue;
+            }
             if force_words.is_some_and(|words| words.contains(&word.s.to_lowercase())) {
                 continue;
             }
@@ -98,7 +115,7 @@ impl RuleChecker for AcronymsRule {
             diags.extend(
                 self.new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!("acronym '{acronym}' must not be translated"),
                 )
                 .map(|d| d.with_msgs_hl(msgid, id_hl, msgstr, [])),
@@ -172,7 +189,7 @@ msgstr "Utiliser le protocole"
 "#,
         );
         assert_eq!(diags.len(), 1);
-        assert_eq!(diags[0].severity, Severity::Warning);
+        assert_eq!(diags[0].severity, Severity::Info);
         assert_eq!(diags[0].message, "acronym 'HTTP' must not be translated");
     }
 
@@ -212,6 +229,32 @@ msgstr "premier protocole, second protocole"
         assert_eq!(id_line.highlights.len(), 2);
     }
 
+    #[test]
+    fn test_translated_acronyms_sorted() {
+        let mut sorted = TRANSLATED_ACRONYMS;
+        sorted.sort_unstable();
+        assert_eq!(sorted, TRANSLATED_ACRONYMS);
+    }
+
+    #[test]
+    fn test_commonly_translated_acronyms_are_ignored() {
+        let diags = check_acronyms(
+            r#"
+msgid "Click OK to download 2 GB"
+msgstr "Cliquez sur Valider pour télécharger 2 Go"
+"#,
+        );
+        assert!(diags.is_empty());
+        let diags = check_acronyms(
+            r#"
+msgid "Click OK to download 2 GB via HTTP"
+msgstr "Cliquez sur Valider pour télécharger 2 Go"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "acronym 'HTTP' must not be translated");
+    }
+
     #[test]
     fn test_short_uppercase_word_is_not_an_acronym() {
         // Single-character uppercase words are not acronyms (length < 2).
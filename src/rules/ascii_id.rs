@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `ascii-id` rule: check non-ASCII characters in source.
+
+use std::fmt::Write as _;
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::format::iter::FormatPos;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+pub struct AsciiIdRule;
+
+impl RuleChecker for AsciiIdRule {
+    fn name(&self) -> &'static str {
+        "ascii-id"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for non-ASCII characters in source string."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn is_source_only(&self) -> bool {
+        true
+    }
+
+    fn checks_untranslated(&self) -> bool {
+        true
+    }
+
+    /// Check for non-ASCII characters in the source string, for projects using
+    /// ASCII-only source strings.
+    ///
+    /// A non-ASCII character often indicates a source string mistakenly localized,
+    /// or a smart quote that should be a straight quote. The characters inside
+    /// format strings are ignored.
+    ///
+    /// The source (`msgid`) and the plural source (`msgid_plural`) are checked, with
+    /// at most one diagnostic for each of them, including in untranslated entries.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "Open “file”"
+    /// msgstr "Ouvrir « fichier »"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "Open \"file\""
+    /// msgstr "Ouvrir « fichier »"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `non-ASCII characters in source: '…' (U+…)`
+    fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
+        entry
+            .msgid
+            .iter()
+            .chain(entry.msgid_plural.iter())
+            .filter_map(|msgid| self.check_source(checker, entry, msgid))
+            .collect()
+    }
+}
+
+impl AsciiIdRule {
+    /// Check a source string (`msgid` or `msgid_plural`) of the entry.
+    fn check_source(
+        &self,
+        checker: &Checker,
+        entry: &Entry,
+        msgid: &Message,
+    ) -> Option<Diagnostic> {
+        if msgid.value.is_ascii() {
+            return None;
+        }
+        let fmt_pos: Vec<_> = FormatPos::new(&msgid.value, entry.format_language)
+            .map(|m| m.start..m.end)
+            .collect();
+        let non_ascii: Vec<(usize, char)> = msgid
+            .value
+            .char_indices()
+            .filter(|(idx, c)| !c.is_ascii() && !fmt_pos.iter().any(|r| r.contains(idx)))
+            .collect();
+        if non_ascii.is_empty() {
+            return None;
+        }
+        let mut chars = String::new();
+        let mut seen = vec![];
+        for (_, c) in &non_ascii {
+            if seen.contains(c) {
+                continue;
+            }
+            seen.push(*c);
+            if !chars.is_empty() {
+                chars.push_str(", ");
+            }
+            let _ = write!(chars, "'{c}' (U+{:04X})", u32::from(*c));
+        }
+        self.new_diag(
+            checker,
+            self.severity(),
+            format!("non-ASCII characters in source: {chars}"),
+        )
+        .map(|d| {
+            let hl = non_ascii.iter().map(|(idx, c)| (*idx, idx + c.len_utf8()));
+            match entry.msgstr.get(&0) {
+                Some(msgstr) => d.with_msgs_hl(msgid, hl, msgstr, []),
+                None => d.with_msg_hl(msgid, hl),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_ascii_id(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(AsciiIdRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_ascii_id_ok() {
+        let diags = check_ascii_id(
+            r#"
+msgid "Open \"file\": %s"
+msgstr "Ouvrir « fichier » : %s"
+
+msgid "Untranslated"
+msgstr ""
+
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[1] "%d fichiers"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_ascii_id_noqa() {
+        let diags = check_ascii_id(
+            r#"
+#, noqa:ascii-id
+msgid "café"
+msgstr "café"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_ascii_id_plural_and_untranslated() {
+        let diags = check_ascii_id(
+            r#"
+msgid ""
+msgstr "Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+msgid "%d café"
+msgid_plural "%d cafés"
+msgstr[0] "%d kawiarnia"
+msgstr[1] "%d kawiarnie"
+msgstr[2] "%d kawiarni"
+
+msgid "thé"
+msgstr ""
+"#,
+        );
+        assert_eq!(diags.len(), 3);
+        assert_eq!(diags[0].lines[0].message, "%d café");
+        assert_eq!(diags[1].lines[0].message, "%d cafés");
+        assert_eq!(diags[2].lines[0].message, "thé");
+    }
+
+    #[test]
+    fn test_ascii_id_error() {
+        let diags = check_ascii_id(
+            r#"
+msgid "café"
+msgstr "café"
+
+msgid "Open “file” or “folder”"
+msgstr "Ouvrir « fichier » ou « dossier »"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "non-ASCII characters in source: 'é' (U+00E9)");
+        assert_eq!(diag.lines[0].highlights, vec![(3, 5)]);
+        let diag = &diags[1];
+        assert_eq!(
+            diag.message,
+            "non-ASCII characters in source: '“' (U+201C), '”' (U+201D)"
+        );
+        assert_eq!(
+            diag.lines[0].highlights,
+            vec![(5, 8), (12, 15), (19, 22), (28, 31)]
+        );
+    }
+}
@@ -29,6 +29,10 @@ impl RuleChecker for BlankRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check for blank translation (only whitespace).
     ///
     /// As the translation is not empty, it is used and it does not contain the appropriate
@@ -59,7 +63,7 @@ impl RuleChecker for BlankRule {
             && !msgstr.value.is_empty()
             && msgstr.value.trim().is_empty()
         {
-            self.new_diag(checker, Severity::Warning, "blank translation")
+            self.new_diag(checker, self.severity(), "blank translation")
                 .map(|d| d.with_msgs_hl(msgid, [], msgstr, [(0, msgstr.value.len())]))
                 .into_iter()
                 .collect()
@@ -0,0 +1,204 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `blank-lines` rule: check inconsistent blank lines inside
+//! multiline strings.
+
+use std::ops::Range;
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+pub struct BlankLinesRule;
+
+impl RuleChecker for BlankLinesRule {
+    fn name(&self) -> &'static str {
+        "blank-lines"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for inconsistent number of blank lines inside multiline strings."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check for inconsistent number of internal blank lines between source and translation.
+    ///
+    /// A blank line is a line feed (`\n`) immediately following another one, so a run
+    /// of N consecutive line feeds counts as N - 1 blank lines. Runs at the beginning
+    /// or at the end of the string are ignored (they are checked by the `newlines`
+    /// rule).
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "First paragraph.\n\nSecond paragraph."
+    /// msgstr "Premier paragraphe.\nSecond paragraphe.\n"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "First paragraph.\n\nSecond paragraph."
+    /// msgstr "Premier paragraphe.\n\nSecond paragraphe."
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `inconsistent blank lines (# / #)`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        _entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let id_runs = blank_line_runs(&msgid.value);
+        let str_runs = blank_line_runs(&msgstr.value);
+        let id_count: usize = id_runs.iter().map(|r| r.len() - 1).sum();
+        let str_count: usize = str_runs.iter().map(|r| r.len() - 1).sum();
+        if id_count == str_count {
+            return vec![];
+        }
+        self.new_diag(
+            checker,
+            self.severity(),
+            format!("inconsistent blank lines ({id_count} / {str_count})"),
+        )
+        .map(|d| {
+            d.with_msgs_hl(
+                msgid,
+                id_runs.into_iter().map(|r| (r.start, r.end)),
+                msgstr,
+                str_runs.into_iter().map(|r| (r.start, r.end)),
+            )
+        })
+        .into_iter()
+        .collect()
+    }
+}
+
+/// Return the byte ranges of the internal runs of two or more consecutive line
+/// feeds (`\n`) in `value`; runs touching the start or the end of the string are
+/// skipped.
+fn blank_line_runs(value: &str) -> Vec<Range<usize>> {
+    let bytes = value.as_bytes();
+    let len = bytes.len();
+    let mut runs = vec![];
+    let mut pos = 0;
+    while let Some(idx) = memchr::memchr(b'\n', &bytes[pos..]) {
+        let start = pos + idx;
+        let mut end = start;
+        while end < len && bytes[end] == b'\n' {
+            end += 1;
+        }
+        if end - start >= 2 && start > 0 && end < len {
+            runs.push(start..end);
+        }
+        pos = end;
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_blank_lines(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(BlankLinesRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_blank_line_runs() {
+        assert!(blank_line_runs("").is_empty());
+        assert!(blank_line_runs("a\nb").is_empty());
+        assert_eq!(blank_line_runs("a\n\nb"), vec![1..3]);
+        assert_eq!(blank_line_runs("a\n\n\nb\n\nc"), vec![1..4, 5..7]);
+        // Runs at the beginning or at the end are ignored.
+        assert!(blank_line_runs("\n\na\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_no_blank_lines() {
+        let diags = check_blank_lines(
+            r#"
+msgid "line 1\nline 2"
+msgstr "ligne 1\nligne 2"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_blank_lines_ok() {
+        let diags = check_blank_lines(
+            r#"
+msgid "paragraph 1\n\nparagraph 2\n\n\nparagraph 3"
+msgstr "paragraphe 1\n\nparagraphe 2\n\n\nparagraphe 3"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_blank_lines_leading_trailing_ignored() {
+        let diags = check_blank_lines(
+            r#"
+msgid "\n\nparagraph 1\n\nparagraph 2\n\n"
+msgstr "paragraphe 1\n\nparagraphe 2"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_blank_lines_noqa() {
+        let diags = check_blank_lines(
+            r#"
+#, noqa:blank-lines
+msgid "paragraph 1\n\nparagraph 2"
+msgstr "paragraphe 1\nparagraphe 2"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_blank_lines_error() {
+        let diags = check_blank_lines(
+            r#"
+msgid "paragraph 1\n\nparagraph 2\n\nparagraph 3"
+msgstr "paragraphe 1\nparagraphe 2\n\nparagraphe 3\n"
+
+msgid "paragraph 1\n\nparagraph 2"
+msgstr "paragraphe 1\n\n\nparagraphe 2"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "inconsistent blank lines (2 / 1)");
+        assert_eq!(diag.lines[0].highlights, vec![(11, 13), (24, 26)]);
+        assert_eq!(diag.lines[2].highlights, vec![(25, 27)]);
+        let diag = &diags[1];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "inconsistent blank lines (1 / 2)");
+    }
+}
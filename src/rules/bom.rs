@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `bom` rule: check byte-order mark at the start of the file.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::rules::rule::RuleChecker;
+
+pub struct BomRule;
+
+impl RuleChecker for BomRule {
+    fn name(&self) -> &'static str {
+        "bom"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for a UTF-8 byte-order mark (BOM) at the start of the file."
+    }
+
+    fn is_default(&self) -> bool {
+        true
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn is_source_only(&self) -> bool {
+        true
+    }
+
+    /// Check for a UTF-8 byte-order mark (BOM) at the start of the file.
+    ///
+    /// The BOM is ignored when parsing the file, but it is not expected in a PO file
+    /// and some gettext tools do not handle it.
+    ///
+    /// Diagnostics reported:
+    /// - [`warning`](Severity::Warning): `file starts with a UTF-8 BOM`
+    fn check_file(&self, checker: &Checker) -> Vec<Diagnostic> {
+        if !checker.has_bom() {
+            return vec![];
+        }
+        self.new_diag(checker, self.severity(), "file starts with a UTF-8 BOM")
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_bom(content: &[u8]) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content);
+        let rules = Rules::new(vec![Box::new(BomRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_no_bom() {
+        let diags = check_bom(
+            br#"
+msgid "tested"
+msgstr "test"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_bom_not_at_start() {
+        let diags = check_bom(b"msgid \"tested\"\nmsgstr \"\xEF\xBB\xBFtest\"\n");
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_bom_error() {
+        let diags = check_bom(
+            b"\xEF\xBB\xBFmsgid \"tested\"\nmsgstr \"test\"\n\nmsgid \"a\"\nmsgstr \"b\"\n",
+        );
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.rule, "bom");
+        assert_eq!(diag.severity, Severity::Warning);
+        assert_eq!(diag.message, "file starts with a UTF-8 BOM");
+    }
+}
@@ -7,6 +7,7 @@
 use crate::checker::Checker;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::po::entry::Entry;
+use crate::po::format::iter::FormatPos;
 use crate::po::message::Message;
 use crate::rules::rule::RuleChecker;
 
@@ -32,11 +33,18 @@ impl RuleChecker for BracketsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for missing or extra round/square/curly/angle brackets in the translation.
     ///
     /// Special case: extra parentheses in the translation are ignored, because this is
     /// often used to precise a word in the translated language.
     ///
+    /// Brackets that are part of a format string (e.g. `{0}` in Python brace format)
+    /// are ignored: they are checked by the `formats` rule.
+    ///
     /// Wrong entry:
     /// ```text
     /// msgid "this is a test (example)"
@@ -59,19 +67,21 @@ impl RuleChecker for BracketsRule {
     fn check_msg(
         &self,
         checker: &Checker,
-        _entry: &Entry,
+        entry: &Entry,
         msgid: &Message,
         msgstr: &Message,
     ) -> Vec<Diagnostic> {
         let mut diags = vec![];
+        let id_fmt = Self::get_format_pos(entry, &msgid.value);
+        let str_fmt = Self::get_format_pos(entry, &msgstr.value);
         for (idx, bracket) in BRACKET_PAIRS.iter().enumerate() {
-            let mut id_open = Self::get_opening_bracket_pos(&msgid.value, bracket.0);
+            let mut id_open = Self::get_opening_bracket_pos(&msgid.value, bracket.0, &id_fmt);
             let id_count_open = id_open.len();
-            let mut str_open = Self::get_opening_bracket_pos(&msgstr.value, bracket.0);
+            let mut str_open = Self::get_opening_bracket_pos(&msgstr.value, bracket.0, &str_fmt);
             let str_count_open = str_open.len();
-            let id_close = Self::get_closing_bracket_pos(&msgid.value, bracket.1);
+            let id_close = Self::get_closing_bracket_pos(&msgid.value, bracket.1, &id_fmt);
             let id_count_close = id_close.len();
-            let str_close = Self::get_closing_bracket_pos(&msgstr.value, bracket.1);
+            let str_close = Self::get_closing_bracket_pos(&msgstr.value, bracket.1, &str_fmt);
             let str_count_close = str_close.len();
             if BRACKET_PAIRS[idx].0 == '('
                 && id_count_open < str_count_open
@@ -104,7 +114,7 @@ impl RuleChecker for BracketsRule {
                     bracket.1,
                 );
                 let emit = |msg: String| {
-                    self.new_diag(checker, Severity::Info, msg).map(|d| {
+                    self.new_diag(checker, self.severity(), msg).map(|d| {
                         d.with_msgs_hl(
                             msgid,
                             id_open.iter().copied(),
@@ -117,7 +127,7 @@ impl RuleChecker for BracketsRule {
                 continue;
             }
             let emit_open = |msg: String| {
-                self.new_diag(checker, Severity::Info, msg).map(|d| {
+                self.new_diag(checker, self.severity(), msg).map(|d| {
                     d.with_msgs_hl(
                         msgid,
                         id_open.iter().copied(),
@@ -127,7 +137,7 @@ impl RuleChecker for BracketsRule {
                 })
             };
             let emit_close = |msg: String| {
-                self.new_diag(checker, Severity::Info, msg).map(|d| {
+                self.new_diag(checker, self.severity(), msg).map(|d| {
                     d.with_msgs_hl(
                         msgid,
                         id_close.iter().copied(),
@@ -166,19 +176,49 @@ impl RuleChecker for BracketsRule {
 }
 
 impl BracketsRule {
-    /// Get positions of opening brackets in the string, excluding some patterns.
-    fn get_opening_bracket_pos(s: &str, bracket_char: char) -> Vec<(usize, usize)> {
+    /// Get positions of format strings in the string, according to the format language
+    /// of the entry.
+    fn get_format_pos(entry: &Entry, s: &str) -> Vec<(usize, usize)> {
+        FormatPos::new(s, entry.format_language)
+            .map(|m| (m.start, m.end))
+            .collect()
+    }
+
+    /// Check if the index is inside one of the format strings.
+    fn is_in_format(index: usize, fmt_pos: &[(usize, usize)]) -> bool {
+        fmt_pos
+            .iter()
+            .any(|(start, end)| (*start..*end).contains(&index))
+    }
+
+    /// Get positions of opening brackets in the string, excluding some patterns and
+    /// brackets in format strings.
+    fn get_opening_bracket_pos(
+        s: &str,
+        bracket_char: char,
+        fmt_pos: &[(usize, usize)],
+    ) -> Vec<(usize, usize)> {
         s.match_indices(bracket_char)
             .map(|(idx, value)| (idx, idx + value.len()))
-            .filter(|(idx, _)| !Self::is_excluded_start(s, *idx, bracket_char))
+            .filter(|(idx, _)| {
+                !Self::is_excluded_start(s, *idx, bracket_char)
+                    && !Self::is_in_format(*idx, fmt_pos)
+            })
             .collect()
     }
 
-    /// Get positions of closing brackets in the string, excluding some patterns.
-    fn get_closing_bracket_pos(s: &str, bracket_char: char) -> Vec<(usize, usize)> {
+    /// Get positions of closing brackets in the string, excluding some patterns and
+    /// brackets in format strings.
+    fn get_closing_bracket_pos(
+        s: &str,
+        bracket_char: char,
+        fmt_pos: &[(usize, usize)],
+    ) -> Vec<(usize, usize)> {
         s.match_indices(bracket_char)
             .map(|(idx, value)| (idx, idx + value.len()))
-            .filter(|(idx, _)| !Self::is_excluded_end(s, *idx, bracket_char))
+            .filter(|(idx, _)| {
+                !Self::is_excluded_end(s, *idx, bracket_char) && !Self::is_in_format(*idx, fmt_pos)
+            })
             .collect()
     }
 
@@ -270,6 +310,47 @@ msgstr "test(s)"
         assert!(diags.is_empty());
     }
 
+    #[test]
+    fn test_brackets_format_ignored() {
+        // Curly brackets of Python brace format are checked by the `formats` rule.
+        let diags = check_brackets(
+            r#"
+#, python-brace-format
+msgid "{0} of {1}, {0}"
+msgstr "{1} : {0}"
+"#,
+        );
+        assert!(diags.is_empty());
+
+        // Other brackets are still checked.
+        let diags = check_brackets(
+            r#"
+#, python-brace-format
+msgid "file {0} (see {1})"
+msgstr "fichier {0} voir {1})"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "missing opening round brackets '(' (1 / 0)"
+        );
+        assert_eq!(diags[0].lines[0].highlights, vec![(9, 10)]);
+
+        // Without format, the curly brackets are counted.
+        let diags = check_brackets(
+            r#"
+msgid "{0} of {1}, {0}"
+msgstr "{1} : {0}"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "missing opening and closing curly brackets '{' (3 / 2) and '}' (3 / 2)"
+        );
+    }
+
     #[test]
     fn test_brackets_error_noqa() {
         let diags = check_brackets(
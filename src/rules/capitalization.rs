@@ -0,0 +1,227 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `capitalization` rule: check inconsistent case of the first letter.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::format::{FormatParser, language::Language};
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+/// Languages using scripts without letter case (sorted for binary search).
+const CASELESS_LANGUAGES: [&str; 6] = ["ar", "he", "ja", "ko", "th", "zh"];
+
+pub struct CapitalizationRule;
+
+impl RuleChecker for CapitalizationRule {
+    fn name(&self) -> &'static str {
+        "capitalization"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for inconsistent case of the first letter between source and translation."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check for inconsistent case of the first letter between source and translation.
+    ///
+    /// Leading whitespace is ignored. Strings starting with a format string, a digit
+    /// or a punctuation character are ignored, as well as translations to languages
+    /// whose script has no letter case (Arabic, Hebrew, Japanese, Korean, Thai and
+    /// Chinese).
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "Open file"
+    /// msgstr "ouvrir le fichier"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "Open file"
+    /// msgstr "Ouvrir le fichier"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `inconsistent first-letter capitalization`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        if CASELESS_LANGUAGES
+            .binary_search(&checker.language_code())
+            .is_ok()
+        {
+            return vec![];
+        }
+        let (Some((id_pos, id_char)), Some((str_pos, str_char))) = (
+            get_first_letter(&msgid.value, entry.format_language),
+            get_first_letter(&msgstr.value, entry.format_language),
+        ) else {
+            return vec![];
+        };
+        if id_char.is_uppercase() == str_char.is_uppercase() {
+            return vec![];
+        }
+        self.new_diag(
+            checker,
+            self.severity(),
+            "inconsistent first-letter capitalization",
+        )
+        .map(|d| {
+            d.with_msgs_hl(
+                msgid,
+                [(id_pos, id_pos + id_char.len_utf8())],
+                msgstr,
+                [(str_pos, str_pos + str_char.len_utf8())],
+            )
+        })
+        .into_iter()
+        .collect()
+    }
+}
+
+/// Get the position and the first letter of a string, skipping leading whitespace.
+///
+/// Returns `None` if the string starts with a format string or a character that has
+/// no case (digit, punctuation, letter of a caseless script, etc.).
+fn get_first_letter(s: &str, language: Language) -> Option<(usize, char)> {
+    let mut pos = 0;
+    while let Some((c, new_pos, is_format)) = language.next_char(s, pos) {
+        if is_format {
+            return None;
+        }
+        if !c.is_whitespace() {
+            return (c.is_uppercase() || c.is_lowercase()).then_some((pos, c));
+        }
+        pos = new_pos;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_capitalization(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(CapitalizationRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_get_first_letter() {
+        assert_eq!(get_first_letter("", Language::Null), None);
+        assert_eq!(get_first_letter("  test", Language::Null), Some((2, 't')));
+        assert_eq!(get_first_letter("Été", Language::Null), Some((0, 'É')));
+        assert_eq!(get_first_letter("...test", Language::Null), None);
+        assert_eq!(get_first_letter("3 files", Language::Null), None);
+        assert_eq!(get_first_letter("%s files", Language::C), None);
+        assert_eq!(get_first_letter("%s files", Language::Null), None);
+        assert_eq!(get_first_letter("文件", Language::Null), None);
+    }
+
+    #[test]
+    fn test_capitalization_ok() {
+        let diags = check_capitalization(
+            r#"
+msgid "Open file"
+msgstr "Ouvrir le fichier"
+
+msgid "open file"
+msgstr "ouvrir le fichier"
+
+msgid "Open file"
+msgstr " Ouvrir le fichier"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_capitalization_format_punc_ignored() {
+        let diags = check_capitalization(
+            r#"
+#, c-format
+msgid "%s files"
+msgstr "Fichiers : %s"
+
+msgid "Open file"
+msgstr "« ouvrir le fichier »"
+
+msgid "2 files"
+msgstr "Deux fichiers"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_capitalization_caseless_language() {
+        let diags = check_capitalization(
+            r#"
+msgid ""
+msgstr "Language: ja\n"
+
+msgid "open file"
+msgstr "Open ファイル"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_capitalization_noqa() {
+        let diags = check_capitalization(
+            r#"
+#, noqa:capitalization
+msgid "Open file"
+msgstr "ouvrir le fichier"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_capitalization_error() {
+        let diags = check_capitalization(
+            r#"
+msgid "Open file"
+msgstr "ouvrir le fichier"
+
+msgid "open file"
+msgstr "Élément ouvert"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "inconsistent first-letter capitalization");
+        assert_eq!(diag.lines[0].highlights, vec![(0, 1)]);
+        assert_eq!(diag.lines[2].highlights, vec![(0, 1)]);
+        let diag = &diags[1];
+        assert_eq!(diag.message, "inconsistent first-letter capitalization");
+        assert_eq!(diag.lines[2].highlights, vec![(0, 2)]);
+    }
+}
@@ -29,6 +29,10 @@ impl RuleChecker for ChangedRule {
         false
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for changed translation: the translation is not empty and different from
     /// the source string.
     ///
@@ -63,7 +67,7 @@ impl RuleChecker for ChangedRule {
             && !msgstr.value.trim().is_empty()
             && msgstr.value != msgid.value
         {
-            self.new_diag(checker, Severity::Info, "changed translation")
+            self.new_diag(checker, self.severity(), "changed translation")
                 .map(|d| d.with_msgs(msgid, msgstr))
                 .into_iter()
                 .collect()
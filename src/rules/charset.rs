@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `charset` rule: check charset declared in header.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+pub struct CharsetRule;
+
+impl RuleChecker for CharsetRule {
+    fn name(&self) -> &'static str {
+        "charset"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for a charset other than UTF-8 declared in PO file header."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check for a charset other than UTF-8 declared in PO file header.
+    ///
+    /// Modern PO files should be encoded in UTF-8, other charsets can be converted
+    /// with the `msgconv` command. An unknown charset is reported by the rule
+    /// `encoding`.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong header:
+    /// ```text
+    /// msgid ""
+    /// msgstr ""
+    /// "Content-Type: text/plain; charset=ISO-8859-15\n"
+    /// ```
+    ///
+    /// Correct header:
+    /// ```text
+    /// msgid ""
+    /// msgstr ""
+    /// "Content-Type: text/plain; charset=UTF-8\n"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `file charset is …, consider converting to UTF-8`
+    fn check_header(&self, checker: &Checker, _entry: &Entry, msgstr: &Message) -> Vec<Diagnostic> {
+        if checker.is_utf8() || checker.unknown_charset().is_some() {
+            return vec![];
+        }
+        self.new_diag(
+            checker,
+            self.severity(),
+            format!(
+                "file charset is {}, consider converting to UTF-8",
+                checker.encoding_name()
+            ),
+        )
+        .map(|d| d.with_msg(msgstr))
+        .into_iter()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_charset(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(CharsetRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_charset_ok() {
+        let diags = check_charset(
+            r#"
+msgid ""
+msgstr ""
+"Language: fr\n"
+"Content-Type: text/plain; charset=UTF-8\n"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_charset_noqa() {
+        let diags = check_charset(
+            r#"
+#, noqa:charset
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=ISO-8859-15\n"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_charset_error() {
+        let diags = check_charset(
+            r#"
+msgid ""
+msgstr ""
+"Language: fr\n"
+"Content-Type: text/plain; charset=ISO-8859-15\n"
+
+msgid "tested"
+msgstr "test"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(
+            diag.message,
+            "file charset is ISO-8859-15, consider converting to UTF-8"
+        );
+        assert_eq!(diag.lines[0].line_number, 3);
+    }
+
+    #[test]
+    fn test_charset_unknown() {
+        let content = r#"
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UNKNOWN-42\n"
+"#;
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(CharsetRule {})]);
+        checker.do_all_checks(&rules);
+        assert!(!checker.is_utf8());
+        assert_eq!(checker.unknown_charset(), Some("UNKNOWN-42"));
+        // Reported by the rule `encoding`.
+        assert!(checker.diagnostics.is_empty());
+    }
+}
@@ -27,6 +27,10 @@ impl RuleChecker for CompilationRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
     /// Check for compilation errors using the `msgfmt` command.
     ///
     /// This rule is not enabled by default.
@@ -48,7 +52,7 @@ impl RuleChecker for CompilationRule {
                 } else {
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         format!(
                             "command `{}` reported errors",
                             checker.config.check.path_msgfmt.display()
@@ -62,7 +66,7 @@ impl RuleChecker for CompilationRule {
             Err(err) => self
                 .new_diag(
                     checker,
-                    Severity::Error,
+                    self.severity(),
                     format!(
                         "failed to run command `{}`",
                         checker.config.check.path_msgfmt.display()
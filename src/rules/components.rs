@@ -0,0 +1,335 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `component-placeholders` rule: check numbered components
+//! (`<0>…</0>`, `<1/>`) used by react-i18next and ICU messages.
+
+use std::collections::HashMap;
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+pub struct ComponentPlaceholdersRule;
+
+/// Kind of numbered component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum ComponentKind {
+    /// Opening component: `<0>`.
+    Open,
+    /// Closing component: `</0>`.
+    Close,
+    /// Self-closing component: `<0/>`.
+    SelfClosing,
+}
+
+/// Numbered component found in a string.
+#[derive(Debug, PartialEq)]
+struct Component {
+    kind: ComponentKind,
+    index: u32,
+    start: usize,
+    end: usize,
+}
+
+impl Component {
+    /// Return the component in its canonical form (e.g. `<0/>` for `<0 />`).
+    fn to_canonical(&self) -> String {
+        match self.kind {
+            ComponentKind::Open => format!("<{}>", self.index),
+            ComponentKind::Close => format!("</{}>", self.index),
+            ComponentKind::SelfClosing => format!("<{}/>", self.index),
+        }
+    }
+}
+
+impl RuleChecker for ComponentPlaceholdersRule {
+    fn name(&self) -> &'static str {
+        "component-placeholders"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for missing, extra or unbalanced numbered components in translation."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Check for missing, extra or unbalanced numbered components in the translation.
+    ///
+    /// Numbered components are used by react-i18next and ICU messages: `<0>…</0>`
+    /// for a component with children and `<1/>` for a self-closing component.
+    /// Only numeric tag names are checked, other tags are checked by the rule `html-tags`.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "Read <0>the documentation</0>.<1/>"
+    /// msgstr "Lisez <0>la documentation<0>."
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "Read <0>the documentation</0>.<1/>"
+    /// msgstr "Lisez <0>la documentation</0>.<1/>"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`error`](Severity::Error): `missing numbered components: …`
+    /// - [`error`](Severity::Error): `extra numbered components: …`
+    /// - [`error`](Severity::Error): `unbalanced numbered components`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        _entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let id_components = find_components(&msgid.value);
+        let str_components = find_components(&msgstr.value);
+        if id_components.is_empty() && str_components.is_empty() {
+            return vec![];
+        }
+        let mut diags = vec![];
+        let missing = unmatched_components(&id_components, &str_components);
+        if !missing.is_empty() {
+            diags.extend(
+                self.new_diag(
+                    checker,
+                    self.severity(),
+                    format!("missing numbered components: {}", join_components(&missing)),
+                )
+                .map(|d| {
+                    d.with_msgs_hl(msgid, missing.iter().map(|c| (c.start, c.end)), msgstr, [])
+                }),
+            );
+        }
+        let extra = unmatched_components(&str_components, &id_components);
+        if !extra.is_empty() {
+            diags.extend(
+                self.new_diag(
+                    checker,
+                    self.severity(),
+                    format!("extra numbered components: {}", join_components(&extra)),
+                )
+                .map(|d| d.with_msgs_hl(msgid, [], msgstr, extra.iter().map(|c| (c.start, c.end)))),
+            );
+        }
+        if diags.is_empty()
+            && is_balanced(&id_components)
+            && let Some(unbalanced) = first_unbalanced(&str_components)
+        {
+            diags.extend(
+                self.new_diag(checker, self.severity(), "unbalanced numbered components")
+                    .map(|d| {
+                        d.with_msgs_hl(msgid, [], msgstr, [(unbalanced.start, unbalanced.end)])
+                    }),
+            );
+        }
+        diags
+    }
+}
+
+/// Find numbered components in a string: `<N>`, `</N>` and `<N/>` (or `<N />`).
+fn find_components(s: &str) -> Vec<Component> {
+    let bytes = s.as_bytes();
+    let mut components = vec![];
+    let mut pos = 0;
+    while let Some(offset) = memchr::memchr(b'<', &bytes[pos..]) {
+        let start = pos + offset;
+        pos = start + 1;
+        let mut idx = pos;
+        let closing = bytes.get(idx) == Some(&b'/');
+        if closing {
+            idx += 1;
+        }
+        let idx_digits = idx;
+        while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        let Ok(index) = s[idx_digits..idx].parse::<u32>() else {
+            continue;
+        };
+        let kind = if closing {
+            ComponentKind::Close
+        } else if s[idx..].starts_with("/>") {
+            idx += 1;
+            ComponentKind::SelfClosing
+        } else if s[idx..].starts_with(" />") {
+            idx += 2;
+            ComponentKind::SelfClosing
+        } else {
+            ComponentKind::Open
+        };
+        if bytes.get(idx) != Some(&b'>') {
+            continue;
+        }
+        pos = idx + 1;
+        components.push(Component {
+            kind,
+            index,
+            start,
+            end: pos,
+        });
+    }
+    components
+}
+
+/// Return the components of `components` that are not in `other` (each component
+/// of `other` matches at most one component).
+fn unmatched_components<'a>(
+    components: &'a [Component],
+    other: &[Component],
+) -> Vec<&'a Component> {
+    let mut counts: HashMap<(ComponentKind, u32), usize> = HashMap::new();
+    for component in other {
+        *counts.entry((component.kind, component.index)).or_default() += 1;
+    }
+    components
+        .iter()
+        .filter(
+            |component| match counts.get_mut(&(component.kind, component.index)) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            },
+        )
+        .collect()
+}
+
+/// Join components in their canonical form, separated by commas.
+fn join_components(components: &[&Component]) -> String {
+    components
+        .iter()
+        .map(|c| c.to_canonical())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Return the first component that is not properly nested, if any (a closing component
+/// without matching opening component, or an opening component never closed).
+fn first_unbalanced(components: &[Component]) -> Option<&Component> {
+    let mut stack: Vec<&Component> = vec![];
+    for component in components {
+        match component.kind {
+            ComponentKind::Open => stack.push(component),
+            ComponentKind::Close => match stack.pop() {
+                Some(open) if open.index == component.index => {}
+                _ => return Some(component),
+            },
+            ComponentKind::SelfClosing => {}
+        }
+    }
+    stack.first().copied()
+}
+
+/// Return `true` if all components are properly nested.
+fn is_balanced(components: &[Component]) -> bool {
+    first_unbalanced(components).is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_components(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(ComponentPlaceholdersRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_find_components() {
+        assert!(find_components("").is_empty());
+        assert!(find_components("a < b, <b>bold</b>, <0 >, <-1>, <a0>").is_empty());
+        assert_eq!(
+            find_components("<0>a</0> <12/><3 />")
+                .iter()
+                .map(|c| (c.to_canonical(), c.start, c.end))
+                .collect::<Vec<_>>(),
+            vec![
+                ("<0>".to_string(), 0, 3),
+                ("</0>".to_string(), 4, 8),
+                ("<12/>".to_string(), 9, 14),
+                ("<3/>".to_string(), 14, 19),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_components_ok() {
+        let diags = check_components(
+            r#"
+msgid "Read <0>the <1>documentation</1></0>.<2/>"
+msgstr "Lisez <0>la <1>documentation</1></0>.<2 />"
+
+msgid "Hello <b>world</b>"
+msgstr "Bonjour <b>monde"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_components_noqa() {
+        let diags = check_components(
+            r#"
+#, noqa:component-placeholders
+msgid "Read <0>the documentation</0>."
+msgstr "Lisez la documentation."
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_components_missing_extra() {
+        let diags = check_components(
+            r#"
+msgid "Read <0>the documentation</0>.<1/>"
+msgstr "Lisez <0>la documentation<0>."
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(diag.message, "missing numbered components: </0>, <1/>");
+        assert_eq!(diag.lines[0].highlights, vec![(25, 29), (30, 34)]);
+        let diag = &diags[1];
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(diag.message, "extra numbered components: <0>");
+        assert_eq!(diag.lines[2].highlights, vec![(25, 28)]);
+    }
+
+    #[test]
+    fn test_components_unbalanced() {
+        let diags = check_components(
+            r#"
+msgid "Read <0>the <1>documentation</1></0>."
+msgstr "Lisez <0>la <1>documentation</0></1>."
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(diag.message, "unbalanced numbered components");
+        assert_eq!(diag.lines[2].highlights, vec![(28, 32)]);
+    }
+}
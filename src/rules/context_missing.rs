@@ -0,0 +1,176 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `context-missing` rule: check short source strings without context.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::format::iter::FormatWordPos;
+use crate::rules::rule::RuleChecker;
+
+pub struct ContextMissingRule;
+
+impl RuleChecker for ContextMissingRule {
+    fn name(&self) -> &'static str {
+        "context-missing"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for short source strings without context (msgctxt)."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn is_source_only(&self) -> bool {
+        true
+    }
+
+    /// Check for short source strings without context (`msgctxt`).
+    ///
+    /// Short strings like "Open", "Close" or "May" are ambiguous: they can be a verb,
+    /// an adjective or a noun, and the translation depends on the context. A context
+    /// is expected for source strings with at most `context_max_words` words
+    /// (default: 1). Format strings and numbers are not counted as words.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "Open"
+    /// msgstr "Ouvrir"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgctxt "menu"
+    /// msgid "Open"
+    /// msgstr "Ouvrir"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `short source string without context`
+    fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
+        if entry.msgctxt.is_some() {
+            return vec![];
+        }
+        let Some(msgid) = &entry.msgid else {
+            return vec![];
+        };
+        let words = FormatWordPos::new(&msgid.value, entry.format_language)
+            .with_underscore(checker.config.check.words_keep_underscore)
+            .filter(|word| word.s.chars().any(char::is_alphabetic))
+            .count();
+        if words == 0 || words > checker.config.check.context_max_words {
+            return vec![];
+        }
+        self.new_diag(
+            checker,
+            self.severity(),
+            "short source string without context",
+        )
+        .map(|d| d.with_msg(msgid))
+        .into_iter()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_context_missing(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(ContextMissingRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_context_missing_ok() {
+        let diags = check_context_missing(
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "Open the file"
+msgstr "Ouvrir le fichier"
+
+msgctxt "menu"
+msgid "Open"
+msgstr "Ouvrir"
+
+#, c-format
+msgid "%s: %d"
+msgstr "%s : %d"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_context_missing_noqa() {
+        let diags = check_context_missing(
+            r#"
+#, noqa:context-missing
+msgid "Open"
+msgstr "Ouvrir"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_context_missing_error() {
+        let diags = check_context_missing(
+            r#"
+msgid "Open"
+msgstr "Ouvrir"
+
+#, c-format
+msgid "%d May"
+msgstr "%d mai"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "short source string without context");
+        assert_eq!(diag.lines[0].line_number, 2);
+        assert_eq!(diags[1].lines[0].line_number, 6);
+    }
+
+    #[test]
+    fn test_context_missing_max_words() {
+        let mut config = Config::default();
+        config.check.context_max_words = 2;
+        let mut checker = Checker::new(
+            r#"
+msgid "Open"
+msgstr "Ouvrir"
+
+msgid "Open file"
+msgstr "Ouvrir le fichier"
+
+msgid "Open the file"
+msgstr "Ouvrir le fichier"
+"#
+            .as_bytes(),
+        )
+        .with_config(config);
+        checker.do_all_checks(&Rules::new(vec![Box::new(ContextMissingRule {})]));
+        assert_eq!(checker.diagnostics.len(), 2);
+    }
+}
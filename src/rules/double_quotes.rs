@@ -40,6 +40,10 @@ impl RuleChecker for DoubleQuotesRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for missing or extra double quotes in the translation.
     ///
     /// The following quotes are considered:
@@ -85,7 +89,7 @@ impl RuleChecker for DoubleQuotesRule {
                 format!("extra double quotes ({id_count} / {str_count})")
             }
         };
-        self.new_diag(checker, Severity::Info, msg)
+        self.new_diag(checker, self.severity(), msg)
             .map(|d| {
                 d.with_msgs_hl(
                     msgid,
@@ -32,8 +32,17 @@ impl RuleChecker for DoubleSpacesRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for missing or extra double spaces in the translation.
     ///
+    /// A double space is a run of two or more consecutive spaces; runs at the
+    /// beginning or at the end of the string are ignored (they are checked by the
+    /// whitespace rules). The number of runs and their lengths are compared, so
+    /// three spaces in the source and two spaces in the translation are reported.
+    ///
     /// Wrong entry:
     /// ```text
     /// msgid "the test:  \"xyz\""
@@ -50,6 +59,7 @@ impl RuleChecker for DoubleSpacesRule {
     /// - [`info`](Severity::Info): `missing double spaces '  ' (# / #)`
     /// - [`info`](Severity::Info): `extra double spaces '  ' (# / #)` (auto-fixable
     ///   only when the source has *no* double spaces)
+    /// - [`info`](Severity::Info): `inconsistent double spaces lengths (… / …)`
     fn check_msg(
         &self,
         checker: &Checker,
@@ -57,10 +67,23 @@ impl RuleChecker for DoubleSpacesRule {
         msgid: &Message,
         msgstr: &Message,
     ) -> Vec<Diagnostic> {
-        let id_count = msgid.value.matches("  ").count();
-        let str_count = msgstr.value.matches("  ").count();
+        let id_runs = double_space_runs(&msgid.value);
+        let str_runs = double_space_runs(&msgstr.value);
+        let id_count = id_runs.len();
+        let str_count = str_runs.len();
         let msg = match id_count.cmp(&str_count) {
-            std::cmp::Ordering::Equal => return vec![],
+            std::cmp::Ordering::Equal => {
+                let id_lengths = sorted_lengths(&id_runs);
+                let str_lengths = sorted_lengths(&str_runs);
+                if id_lengths == str_lengths {
+                    return vec![];
+                }
+                format!(
+                    "inconsistent double spaces lengths ({} / {})",
+                    join_lengths(&id_lengths),
+                    join_lengths(&str_lengths),
+                )
+            }
             std::cmp::Ordering::Greater => {
                 format!("missing double spaces '  ' ({id_count} / {str_count})")
             }
@@ -72,9 +95,9 @@ impl RuleChecker for DoubleSpacesRule {
         // every interior double-space run in the translation is surplus and collapses
         // to a single space. When the source has some double spaces (`id_count > 0`)
         // the position of the surplus run is ambiguous, and the "missing" case has no
-        // determinable insertion point, so neither is fixed. Leading, trailing and
-        // per-line edge runs are left to the whitespace rules to avoid overlapping
-        // (and thus conflicting) fixes; see [`interior_double_space_runs`].
+        // determinable insertion point, so neither is fixed. Per-line edge runs are
+        // left to the whitespace rules to avoid overlapping (and thus conflicting)
+        // fixes; see [`interior_double_space_runs`].
         let fix = (id_count == 0)
             .then(|| interior_double_space_runs(&msgstr.value))
             .filter(|runs| !runs.is_empty())
@@ -91,19 +114,13 @@ impl RuleChecker for DoubleSpacesRule {
                     .collect(),
                 safe: true,
             });
-        self.new_diag(checker, Severity::Info, msg)
+        self.new_diag(checker, self.severity(), msg)
             .map(|d| {
                 d.with_msgs_hl(
                     msgid,
-                    msgid
-                        .value
-                        .match_indices("  ")
-                        .map(|(idx, value)| (idx, idx + value.len())),
+                    id_runs.into_iter().map(|r| (r.start, r.end)),
                     msgstr,
-                    msgstr
-                        .value
-                        .match_indices("  ")
-                        .map(|(idx, value)| (idx, idx + value.len())),
+                    str_runs.into_iter().map(|r| (r.start, r.end)),
                 )
                 .with_optional_fix(fix)
             })
@@ -112,14 +129,10 @@ impl RuleChecker for DoubleSpacesRule {
     }
 }
 
-/// Find maximal runs of two-or-more ASCII spaces in `value` that are *interior*:
-/// not touching the string start or end, and not adjacent to a `'\n'` on either
-/// side. Leading, trailing and per-line edge whitespace are the whitespace rules'
-/// responsibility (`whitespace-start`, `whitespace-end`, `whitespace-line-start`,
-/// `whitespace-line-end`), so those runs are skipped here to keep the collapse
-/// fix from overlapping — and therefore conflicting — with theirs. Each surviving
-/// run is returned as a byte range to be replaced by a single space.
-fn interior_double_space_runs(value: &str) -> Vec<Range<usize>> {
+/// Find maximal runs of two-or-more ASCII spaces in `value` that do not touch the
+/// string start or end (leading and trailing whitespace is the whitespace rules'
+/// responsibility). Each run is returned as a byte range.
+fn double_space_runs(value: &str) -> Vec<Range<usize>> {
     let bytes = value.as_bytes();
     let len = bytes.len();
     let mut runs = Vec::new();
@@ -134,21 +147,46 @@ fn interior_double_space_runs(value: &str) -> Vec<Range<usize>> {
             i += 1;
         }
         let end = i;
-        if end - start < 2 {
-            continue;
-        }
-        // A single ASCII byte comparison is safe even next to multi-byte UTF-8
-        // characters: `'\n'` (0x0A) never appears as a continuation byte.
-        let at_str_edge = start == 0 || end == len;
-        let at_line_edge =
-            (start > 0 && bytes[start - 1] == b'\n') || (end < len && bytes[end] == b'\n');
-        if !at_str_edge && !at_line_edge {
+        if end - start >= 2 && start > 0 && end < len {
             runs.push(start..end);
         }
     }
     runs
 }
 
+/// Return the sorted lengths of the runs, so that two strings can be compared
+/// regardless of the order of their runs.
+fn sorted_lengths(runs: &[Range<usize>]) -> Vec<usize> {
+    let mut lengths: Vec<usize> = runs.iter().map(ExactSizeIterator::len).collect();
+    lengths.sort_unstable();
+    lengths
+}
+
+/// Join the run lengths with a comma, for display in the diagnostic message.
+fn join_lengths(lengths: &[usize]) -> String {
+    lengths
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Find the double-space runs in `value` (see [`double_space_runs`]) that are
+/// *interior*: not adjacent to a `'\n'` on either side. Per-line edge whitespace
+/// is the whitespace rules' responsibility (`whitespace-line-start`,
+/// `whitespace-line-end`), so those runs are skipped here to keep the collapse
+/// fix from overlapping — and therefore conflicting — with theirs. Each surviving
+/// run is returned as a byte range to be replaced by a single space.
+fn interior_double_space_runs(value: &str) -> Vec<Range<usize>> {
+    let bytes = value.as_bytes();
+    double_space_runs(value)
+        .into_iter()
+        // A single ASCII byte comparison is safe even next to multi-byte UTF-8
+        // characters: `'\n'` (0x0A) never appears as a continuation byte.
+        .filter(|r| bytes[r.start - 1] != b'\n' && bytes[r.end] != b'\n')
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -305,18 +343,50 @@ msgstr "a b c"
     }
 
     #[test]
-    fn test_double_spaces_skips_trailing_edge_run() {
-        // A trailing double-space run is the whitespace-end rule's job: the
-        // diagnostic still fires but no double-spaces fix is attached.
+    fn test_double_spaces_ignores_edge_runs() {
+        // Leading and trailing double-space runs are the whitespace rules' job:
+        // no diagnostic is reported.
         let diags = check_double_spaces(
             r#"
 msgid "ab"
-msgstr "ab  "
+msgstr "  ab  "
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_double_spaces_three_four_five_spaces() {
+        let diags = check_double_spaces(
+            r#"
+msgid "a   b    c     d"
+msgstr "a     b   c    d"
+"#,
+        );
+        assert!(diags.is_empty());
+        // Four spaces are one run, not two.
+        let diags = check_double_spaces(
+            r#"
+msgid "a    b"
+msgstr "a  b  c"
 "#,
         );
         assert_eq!(diags.len(), 1);
-        assert_eq!(diags[0].message, "extra double spaces '  ' (0 / 1)");
-        assert!(diags[0].fix.is_none());
+        assert_eq!(diags[0].message, "extra double spaces '  ' (1 / 2)");
+        assert_eq!(diags[0].lines[0].highlights, vec![(1, 5)]);
+        assert_eq!(diags[0].lines[2].highlights, vec![(1, 3), (4, 6)]);
+        // Same number of runs, different lengths.
+        let diags = check_double_spaces(
+            r#"
+msgid "a   b     c"
+msgstr "a  b    c"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "inconsistent double spaces lengths (3, 5 / 2, 4)"
+        );
     }
 
     #[test]
@@ -333,10 +403,21 @@ msgstr "a  \n  b"
         assert!(diags[0].fix.is_none());
     }
 
+    #[test]
+    fn test_double_space_runs() {
+        assert!(double_space_runs("").is_empty());
+        assert!(double_space_runs("   ").is_empty());
+        assert_eq!(double_space_runs("  a   b    c  "), vec![3..6, 7..11]);
+        // Newline-adjacent runs are kept.
+        assert_eq!(double_space_runs("a  \n  b"), vec![1..3, 4..6]);
+    }
+
     #[test]
     fn test_interior_double_space_runs() {
         // Interior run only.
         assert_eq!(interior_double_space_runs("a  b"), vec![1..3]);
+        // Odd-length runs are a single run.
+        assert_eq!(interior_double_space_runs("a   b     c"), vec![1..4, 5..10]);
         // Leading and trailing edges skipped.
         assert_eq!(interior_double_space_runs("  a  b  "), vec![3..5]);
         // Newline-adjacent runs skipped on both sides; interior kept.
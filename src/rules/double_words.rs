@@ -12,6 +12,11 @@ use crate::po::format::iter::FormatWordPos;
 use crate::po::message::Message;
 use crate::rules::rule::RuleChecker;
 
+/// Words that can legitimately be repeated, by language code (e.g. "had had" in
+/// English, "nous nous" in French reflexive verbs).
+const ALLOWED_REPEATED_WORDS: [(&str, &[&str]); 2] =
+    [("en", &["had", "that"]), ("fr", &["nous", "vous"])];
+
 pub struct DoubleWordsRule;
 
 impl RuleChecker for DoubleWordsRule {
@@ -31,8 +36,16 @@ impl RuleChecker for DoubleWordsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for double consecutive words in the translation.
     ///
+    /// Words are compared case-insensitively. Some words that can legitimately be
+    /// repeated in the language of the translation are ignored (for example "had had"
+    /// in English or "nous nous" in French).
+    ///
     /// This rule is not enabled by default.
     ///
     /// Wrong entry:
@@ -59,13 +72,21 @@ impl RuleChecker for DoubleWordsRule {
         msgstr: &Message,
     ) -> Vec<Diagnostic> {
         let mut diags = vec![];
+        let language = checker.language_code();
+        let allowed_words = ALLOWED_REPEATED_WORDS
+            .iter()
+            .find(|(lang, _)| *lang == language)
+            .map_or(&[][..], |(_, words)| *words);
         let mut words_iter = FormatWordPos::new(&msgstr.value, entry.format_language).peekable();
         while let Some(word) = words_iter.next()
             && let Some(next_word) = words_iter.peek()
         {
             // If the current word is the same as the next word, and that there is only
             // whitespace between them, then report a double word.
-            if word.s == next_word.s
+            if eq_ignore_case(word.s, next_word.s)
+                && !allowed_words
+                    .iter()
+                    .any(|allowed| eq_ignore_case(word.s, allowed))
                 && msgstr.value[word.end..next_word.start]
                     .chars()
                     .all(char::is_whitespace)
@@ -85,7 +106,7 @@ impl RuleChecker for DoubleWordsRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Info,
+                        self.severity(),
                         format!("word '{}' is repeated", word.s),
                     )
                     .map(|d| {
@@ -99,6 +120,14 @@ impl RuleChecker for DoubleWordsRule {
     }
 }
 
+/// Compare two words case-insensitively, without allocating.
+fn eq_ignore_case(word1: &str, word2: &str) -> bool {
+    word1
+        .chars()
+        .flat_map(char::to_lowercase)
+        .eq(word2.chars().flat_map(char::to_lowercase))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,6 +177,47 @@ msgstr "ceci est un un test"
         assert_eq!(diag.message, "word 'un' is repeated");
     }
 
+    #[test]
+    fn test_double_words_case_insensitive() {
+        let diags = check_double_words(
+            r#"
+msgid "this is a test"
+msgstr "Le le test"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "word 'Le' is repeated");
+        assert_eq!(diags[0].lines[2].highlights, vec![(0, 5)]);
+    }
+
+    #[test]
+    fn test_double_words_allowed() {
+        let diags = check_double_words(
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "we wash"
+msgstr "nous nous lavons"
+
+msgid "you wash"
+msgstr "Vous vous lavez"
+"#,
+        );
+        assert!(diags.is_empty());
+        // Allowed words are specific to the language.
+        let diags = check_double_words(
+            r#"
+msgid ""
+msgstr "Language: de\n"
+
+msgid "we wash"
+msgstr "nous nous lavons"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+    }
+
     #[test]
     fn test_double_words_fix_deletes_second_occurrence() {
         // msgstr = "ceci est un un test"
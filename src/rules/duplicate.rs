@@ -0,0 +1,201 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `duplicate` rule: check duplicate entries.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::rules::rule::RuleChecker;
+
+pub struct DuplicateRule;
+
+impl RuleChecker for DuplicateRule {
+    fn name(&self) -> &'static str {
+        "duplicate"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for duplicate entries (same context and source)."
+    }
+
+    fn is_default(&self) -> bool {
+        true
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn is_source_only(&self) -> bool {
+        true
+    }
+
+    fn checks_untranslated(&self) -> bool {
+        true
+    }
+
+    /// Check for duplicate entries: two entries with the same context (`msgctxt`)
+    /// and source (`msgid`), which is rejected by gettext tools.
+    ///
+    /// The duplicate entry is reported, not the first one, even if it is untranslated.
+    /// Obsolete entries are ignored.
+    ///
+    /// Wrong entries:
+    /// ```text
+    /// msgid "Open"
+    /// msgstr "Ouvrir"
+    ///
+    /// msgid "Open"
+    /// msgstr "Ouvert"
+    /// ```
+    ///
+    /// Correct entries:
+    /// ```text
+    /// msgctxt "verb"
+    /// msgid "Open"
+    /// msgstr "Ouvrir"
+    ///
+    /// msgctxt "adjective"
+    /// msgid "Open"
+    /// msgstr "Ouvert"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`error`](Severity::Error): `duplicate message (first defined at line #)`
+    fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
+        let Some(line) = checker.duplicate_lines.get(&entry.line_number) else {
+            return vec![];
+        };
+        self.new_diag(
+            checker,
+            self.severity(),
+            format!("duplicate message (first defined at line {line})"),
+        )
+        .map(|d| d.with_entry(entry))
+        .into_iter()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_duplicate(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(DuplicateRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_duplicate_ok() {
+        let diags = check_duplicate(
+            r#"
+msgctxt "verb"
+msgid "Open"
+msgstr "Ouvrir"
+
+msgctxt "adjective"
+msgid "Open"
+msgstr "Ouvert"
+
+msgid "Open"
+msgstr "Ouvrir"
+
+#~ msgid "Open"
+#~ msgstr "Ouvert"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_noqa() {
+        let diags = check_duplicate(
+            r#"
+msgid "Open"
+msgstr "Ouvrir"
+
+#, noqa:duplicate
+msgid "Open"
+msgstr "Ouvert"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_error() {
+        let diags = check_duplicate(
+            r#"
+msgid "Open"
+msgstr "Ouvrir"
+
+msgid "Open"
+msgstr ""
+
+msgid "Open"
+msgstr "Ouvert"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(diag.message, "duplicate message (first defined at line 2)");
+        assert_eq!(diag.lines[0].line_number, 5);
+        assert_eq!(diags[1].lines[0].line_number, 8);
+    }
+
+    #[test]
+    fn test_duplicate_untranslated() {
+        let diags = check_duplicate(
+            r#"
+msgid "Open"
+msgstr ""
+
+msgid "Open"
+msgstr ""
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "duplicate message (first defined at line 2)"
+        );
+        assert_eq!(diags[0].lines[0].line_number, 5);
+    }
+
+    #[test]
+    fn test_duplicate_context_and_multiline() {
+        let diags = check_duplicate(
+            r#"
+msgctxt "menu"
+msgid "Open file"
+msgstr "Ouvrir le fichier"
+
+msgid "Open file"
+msgstr "Ouvrir le fichier"
+
+msgctxt "menu"
+msgid ""
+"Open "
+"file"
+msgstr "Ouvrir un fichier"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "duplicate message (first defined at line 2)"
+        );
+        assert_eq!(diags[0].lines[0].line_number, 9);
+    }
+}
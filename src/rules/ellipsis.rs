@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `ellipsis` rule: check inconsistent trailing ellipsis.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::punc::{get_punc_end, punc_normalize};
+use crate::rules::rule::RuleChecker;
+
+pub struct EllipsisRule;
+
+impl RuleChecker for EllipsisRule {
+    fn name(&self) -> &'static str {
+        "ellipsis"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for inconsistent trailing ellipsis between source and translation."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check for inconsistent trailing ellipsis between source and translation.
+    ///
+    /// Both `...` (three dots) and `…` (U+2026) are considered as an ellipsis, so
+    /// `...` in the source and `…` in the translation are consistent.
+    ///
+    /// This rule is complementary to the `punc-end` rule, which compares the whole
+    /// trailing punctuation: when `punc-end` is enabled and already reports a
+    /// different trailing punctuation for the string, this rule does not report
+    /// the same issue again.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "Save as..."
+    /// msgstr "Enregistrer sous"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "Save as..."
+    /// msgstr "Enregistrer sous…"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `inconsistent ellipsis ('…' / '…')`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let id_ellipsis = get_ellipsis_end(&msgid.value);
+        let str_ellipsis = get_ellipsis_end(&msgstr.value);
+        if id_ellipsis.is_some() == str_ellipsis.is_some() {
+            return vec![];
+        }
+        if checker.punc_end_rule && !entry.noqa_rules.iter().any(|r| r == "punc-end") {
+            let language = checker.language_code();
+            let ignore_ellipsis = checker.config.check.punc_ignore_ellipsis;
+            if punc_normalize(get_punc_end(&msgid.value), language, ignore_ellipsis)
+                != punc_normalize(get_punc_end(&msgstr.value), language, ignore_ellipsis)
+            {
+                // Already reported by the `punc-end` rule.
+                return vec![];
+            }
+        }
+        let id_str = if id_ellipsis.is_some() { "…" } else { "" };
+        let str_str = if str_ellipsis.is_some() { "…" } else { "" };
+        self.new_diag(
+            checker,
+            self.severity(),
+            format!("inconsistent ellipsis ('{id_str}' / '{str_str}')"),
+        )
+        .map(|d| d.with_msgs_hl(msgid, id_ellipsis, msgstr, str_ellipsis))
+        .into_iter()
+        .collect()
+    }
+}
+
+/// Get the position (start, end) of the ellipsis (`...` or `…`) at the end of the
+/// string, ignoring trailing whitespace.
+fn get_ellipsis_end(s: &str) -> Option<(usize, usize)> {
+    let trimmed = s.trim_end();
+    let end = trimmed.len();
+    if trimmed.ends_with("...") {
+        Some((end - 3, end))
+    } else if trimmed.ends_with('…') {
+        Some((end - '…'.len_utf8(), end))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::punc::PuncEndRule, rules::rule::Rules};
+
+    fn check_ellipsis(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(EllipsisRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    fn check_ellipsis_punc_end(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(EllipsisRule {}), Box::new(PuncEndRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_get_ellipsis_end() {
+        assert_eq!(get_ellipsis_end(""), None);
+        assert_eq!(get_ellipsis_end("test."), None);
+        assert_eq!(get_ellipsis_end("test.."), None);
+        assert_eq!(get_ellipsis_end("test..."), Some((4, 7)));
+        assert_eq!(get_ellipsis_end("test… "), Some((4, 7)));
+    }
+
+    #[test]
+    fn test_no_ellipsis() {
+        let diags = check_ellipsis(
+            r#"
+msgid "tested."
+msgstr "testé"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_ellipsis_ok() {
+        let diags = check_ellipsis(
+            r#"
+msgid "Save as..."
+msgstr "Enregistrer sous…"
+
+msgid "Loading…"
+msgstr "Chargement..."
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_ellipsis_noqa() {
+        let diags = check_ellipsis(
+            r#"
+#, noqa:ellipsis
+msgid "Save as..."
+msgstr "Enregistrer sous"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_ellipsis_error() {
+        let diags = check_ellipsis(
+            r#"
+msgid "Save as..."
+msgstr "Enregistrer sous"
+
+msgid "Loading"
+msgstr "Chargement…"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "inconsistent ellipsis ('…' / '')");
+        assert_eq!(diag.lines[0].highlights, vec![(7, 10)]);
+        assert!(diag.lines[2].highlights.is_empty());
+        let diag = &diags[1];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "inconsistent ellipsis ('' / '…')");
+        assert_eq!(diag.lines[2].highlights, vec![(10, 13)]);
+    }
+
+    #[test]
+    fn test_ellipsis_not_reported_with_punc_end() {
+        let diags = check_ellipsis_punc_end(
+            r#"
+msgid "Save as..."
+msgstr "Enregistrer sous"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].rule, "punc-end");
+    }
+
+    #[test]
+    fn test_ellipsis_reported_with_punc_end_noqa() {
+        let diags = check_ellipsis_punc_end(
+            r#"
+#, noqa:punc-end
+msgid "Save as..."
+msgstr "Enregistrer sous"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].rule, "ellipsis");
+    }
+}
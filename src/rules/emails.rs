@@ -34,6 +34,10 @@ impl RuleChecker for EmailsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check for missing, extra or different emails in the translation.
     ///
     /// Wrong entry:
@@ -73,7 +77,7 @@ impl RuleChecker for EmailsRule {
             std::cmp::Ordering::Greater => self
                 .new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!(
                         "missing emails ({} / {})",
                         id_emails.len(),
@@ -93,7 +97,7 @@ impl RuleChecker for EmailsRule {
             std::cmp::Ordering::Less => self
                 .new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!("extra emails ({} / {})", id_emails.len(), str_emails.len()),
                 )
                 .map(|d| {
@@ -132,7 +136,7 @@ impl RuleChecker for EmailsRule {
                         edits,
                         safe: false,
                     });
-                    self.new_diag(checker, Severity::Warning, "different emails")
+                    self.new_diag(checker, self.severity(), "different emails")
                         .map(|d| {
                             d.with_msgs_hl(
                                 msgid,
@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `empty-msgid` rule: check entries with an empty msgid.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::rules::rule::RuleChecker;
+
+pub struct EmptyMsgidRule;
+
+impl RuleChecker for EmptyMsgidRule {
+    fn name(&self) -> &'static str {
+        "empty-msgid"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for entries with an empty source which are not the header."
+    }
+
+    fn is_default(&self) -> bool {
+        true
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn is_source_only(&self) -> bool {
+        true
+    }
+
+    /// Check for entries with an empty source (`msgid`) which are not the header.
+    ///
+    /// Only the first entry of the file, without context, can have an empty `msgid`:
+    /// this is the header. Any other entry with an empty `msgid` and without context
+    /// is not checked by the other rules. An entry with a context and an empty `msgid`
+    /// is valid and checked like any other entry.
+    ///
+    /// Wrong entries:
+    /// ```text
+    /// msgid ""
+    /// msgstr "Content-Type: text/plain; charset=UTF-8\n"
+    ///
+    /// msgid ""
+    /// msgstr "Fichier"
+    /// ```
+    ///
+    /// Correct entries:
+    /// ```text
+    /// msgid ""
+    /// msgstr "Content-Type: text/plain; charset=UTF-8\n"
+    ///
+    /// msgid "File"
+    /// msgstr "Fichier"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`warning`](Severity::Warning): `unexpected empty msgid`
+    fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
+        if !entry.is_header() || entry.msgctxt.is_some() {
+            return vec![];
+        }
+        self.new_diag(checker, self.severity(), "unexpected empty msgid")
+            .map(|d| d.with_entry(entry))
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_empty_msgid(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(EmptyMsgidRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_empty_msgid_ok() {
+        let diags = check_empty_msgid(
+            r#"
+msgid ""
+msgstr ""
+"Language: fr\n"
+
+msgid "tested"
+msgstr "testé"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_empty_msgid_noqa() {
+        let diags = check_empty_msgid(
+            r#"
+msgid ""
+msgstr ""
+"Language: fr\n"
+
+#, noqa:empty-msgid
+msgid ""
+msgstr "test"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_empty_msgid_error() {
+        let diags = check_empty_msgid(
+            r#"
+msgid ""
+msgstr ""
+"Language: fr\n"
+
+msgid ""
+msgstr "test"
+
+msgctxt "menu"
+msgid ""
+msgstr "Fichier"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert_eq!(diags[0].message, "unexpected empty msgid");
+        assert_eq!(diags[0].lines[0].line_number, 6);
+    }
+
+    #[test]
+    fn test_empty_msgid_with_context() {
+        let diags = check_empty_msgid(
+            r#"
+msgctxt "menu"
+msgid ""
+msgstr "Fichier"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+}
@@ -7,6 +7,7 @@
 use crate::checker::Checker;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::po::entry::Entry;
+use crate::po::message::Message;
 use crate::rules::rule::RuleChecker;
 
 pub struct EncodingRule;
@@ -28,6 +29,14 @@ impl RuleChecker for EncodingRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn is_source_only(&self) -> bool {
+        true
+    }
+
     /// Check for translation with incorrect encoding.
     ///
     /// The encoding used to check is the one declared in the PO file, with a fallback
@@ -36,6 +45,9 @@ impl RuleChecker for EncodingRule {
     /// "Content-Type: text/plain; charset=UTF-8\n"
     /// ```
     ///
+    /// An unknown charset declared in the header is reported, and the strings are
+    /// then decoded as UTF-8 (the placeholder `CHARSET` of templates is ignored).
+    ///
     /// Wrong entry:
     /// ```text
     /// msgid "tested"
@@ -49,16 +61,28 @@ impl RuleChecker for EncodingRule {
     /// ```
     ///
     /// Diagnostics reported:
-    /// - [`error`](Severity::Error): `invalid characters for encoding …`
+    /// - [`error`](Severity::Error): `unknown charset '…'`
+    /// - [`error`](Severity::Error): `decoding error (not valid …)`
+    fn check_header(&self, checker: &Checker, _entry: &Entry, msgstr: &Message) -> Vec<Diagnostic> {
+        let Some(charset) = checker.unknown_charset() else {
+            return vec![];
+        };
+        self.new_diag(
+            checker,
+            self.severity(),
+            format!("unknown charset '{charset}'"),
+        )
+        .map(|d| d.with_msg(msgstr))
+        .into_iter()
+        .collect()
+    }
+
     fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
         if entry.encoding_error {
             self.new_diag(
                 checker,
-                Severity::Error,
-                format!(
-                    "invalid characters for encoding {}",
-                    checker.encoding_name()
-                ),
+                self.severity(),
+                format!("decoding error (not valid {})", checker.encoding_name()),
             )
             .map(|d| d.with_entry(entry))
             .into_iter()
@@ -111,6 +135,37 @@ msgstr "testé"
         assert_eq!(diags.len(), 1);
         let diag = &diags[0];
         assert_eq!(diag.severity, Severity::Error);
-        assert_eq!(diag.message, "invalid characters for encoding UTF-8");
+        assert_eq!(diag.message, "decoding error (not valid UTF-8)");
+    }
+
+    #[test]
+    fn test_encoding_unknown_charset() {
+        let diags = check_encoding(
+            r#"
+msgid ""
+msgstr ""
+"Language: fr\n"
+"Content-Type: text/plain; charset=UNKNOWN-42\n"
+
+msgid "tested"
+msgstr "testé"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Error);
+        assert_eq!(diags[0].message, "unknown charset 'UNKNOWN-42'");
+        assert_eq!(diags[0].lines[0].line_number, 3);
+        // Placeholder of templates.
+        let diags = check_encoding(
+            r#"
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=CHARSET\n"
+
+msgid "tested"
+msgstr ""
+"#,
+        );
+        assert!(diags.is_empty());
     }
 }
@@ -29,6 +29,10 @@ impl RuleChecker for EscapesRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
     /// Check for missing or extra escape characters (`\\` and `\`) in the translation.
     ///
     /// Wrong entry:
@@ -69,7 +73,7 @@ impl RuleChecker for EscapesRule {
         };
         if let Some(msg) = msg {
             return self
-                .new_diag(checker, Severity::Error, msg)
+                .new_diag(checker, self.severity(), msg)
                 .map(|d| {
                     d.with_msgs_hl(
                         msgid,
@@ -99,7 +103,7 @@ impl RuleChecker for EscapesRule {
                 format!("extra escape characters '\\' ({id_count} / {str_count})")
             }
         };
-        self.new_diag(checker, Severity::Error, msg)
+        self.new_diag(checker, self.severity(), msg)
             .map(|d| {
                 d.with_msgs_hl(
                     msgid,
@@ -33,6 +33,10 @@ impl RuleChecker for ForceTransRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check that every word listed in `check.force_trans_file` that appears
     /// in the source string has been translated, i.e. does NOT also appear
     /// verbatim in the translation. Matching against the word list is
@@ -106,7 +110,7 @@ impl RuleChecker for ForceTransRule {
             diags.extend(
                 self.new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!("word '{}' must be translated", str_word.s),
                 )
                 .map(|d| d.with_msgs_hl(msgid, id_hl, msgstr, str_hl)),
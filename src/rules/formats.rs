@@ -36,6 +36,10 @@ impl RuleChecker for FormatsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
     /// Check for inconsistent format strings.
     ///
     /// The following languages are supported:
@@ -100,7 +104,7 @@ impl RuleChecker for FormatsRule {
         if error {
             self.new_diag(
                 checker,
-                Severity::Error,
+                self.severity(),
                 format!("inconsistent format strings ({})", entry.format_language),
             )
             .map(|d| {
@@ -34,6 +34,10 @@ impl RuleChecker for FunctionsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check for missing, extra or different function names in the translation.
     ///
     /// A function name is a sequence of word characters and dots (optionally
@@ -80,7 +84,7 @@ impl RuleChecker for FunctionsRule {
             std::cmp::Ordering::Greater => self
                 .new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!(
                         "missing functions ({} / {})",
                         id_funcs.len(),
@@ -100,7 +104,7 @@ impl RuleChecker for FunctionsRule {
             std::cmp::Ordering::Less => self
                 .new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!("extra functions ({} / {})", id_funcs.len(), str_funcs.len()),
                 )
                 .map(|d| {
@@ -138,7 +142,7 @@ impl RuleChecker for FunctionsRule {
                         edits,
                         safe: false,
                     });
-                    self.new_diag(checker, Severity::Warning, "different functions")
+                    self.new_diag(checker, self.severity(), "different functions")
                         .map(|d| {
                             d.with_msgs_hl(
                                 msgid,
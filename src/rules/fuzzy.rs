@@ -28,6 +28,10 @@ impl RuleChecker for FuzzyRule {
         false
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Report entry if fuzzy.
     ///
     /// Fuzzy is not strictly speaking an error, but this check helps to identify fuzzy
@@ -52,7 +56,7 @@ impl RuleChecker for FuzzyRule {
     /// - [`info`](Severity::Info): `fuzzy entry`
     fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
         if entry.fuzzy {
-            self.new_diag(checker, Severity::Info, "fuzzy entry")
+            self.new_diag(checker, self.severity(), "fuzzy entry")
                 .map(|d| d.with_entry(entry))
                 .into_iter()
                 .collect()
@@ -0,0 +1,168 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `fuzzy-stale` rule: report fuzzy entries with unchanged source.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+pub struct FuzzyStaleRule;
+
+impl RuleChecker for FuzzyStaleRule {
+    fn name(&self) -> &'static str {
+        "fuzzy-stale"
+    }
+
+    fn description(&self) -> &'static str {
+        "Report fuzzy entries whose source did not change."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Report fuzzy entry if the previous source (`#| msgid`) is the same as the
+    /// current source.
+    ///
+    /// The previous context and plural source (`#| msgctxt` and `#| msgid_plural`)
+    /// must be the same as well. In this case the fuzzy flag has probably been added
+    /// by a merge that changed only comments or references, and it can be removed.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// #, fuzzy
+    /// #| msgid "this is a test"
+    /// msgid "this is a test"
+    /// msgstr "ceci est un test"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "this is a test"
+    /// msgstr "ceci est un test"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `fuzzy flag but source unchanged`
+    fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
+        if !entry.fuzzy
+            || entry.previous_msgid.is_none()
+            || !same_value(entry.previous_msgid.as_ref(), entry.msgid.as_ref())
+            || !same_value(
+                entry.previous_msgid_plural.as_ref(),
+                entry.msgid_plural.as_ref(),
+            )
+            || !same_value(entry.previous_msgctxt.as_ref(), entry.msgctxt.as_ref())
+        {
+            return vec![];
+        }
+        let Some(msgid) = &entry.msgid else {
+            return vec![];
+        };
+        self.new_diag(checker, self.severity(), "fuzzy flag but source unchanged")
+            .map(|d| d.with_msg_hl(msgid, [(0, msgid.value.len())]))
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Return `true` if both messages are missing or have the same value (the line
+/// numbers are ignored).
+fn same_value(msg1: Option<&Message>, msg2: Option<&Message>) -> bool {
+    msg1.map(|m| &m.value) == msg2.map(|m| &m.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_fuzzy_stale(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(FuzzyStaleRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_fuzzy_stale_ok() {
+        let diags = check_fuzzy_stale(
+            r#"
+msgid "this is a test"
+msgstr "ceci est un test"
+
+#, fuzzy
+msgid "this is a test"
+msgstr "ceci est un test"
+
+#, fuzzy
+#| msgid "this is test"
+msgid "this is a test"
+msgstr "ceci est un test"
+
+#, fuzzy
+#| msgctxt "old"
+#| msgid "this is a test"
+msgctxt "new"
+msgid "this is a test"
+msgstr "ceci est un test"
+
+#, fuzzy
+#| msgid "file"
+#| msgid_plural "file(s)"
+msgid "file"
+msgid_plural "files"
+msgstr[0] "fichier"
+msgstr[1] "fichiers"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_stale_noqa() {
+        let diags = check_fuzzy_stale(
+            r#"
+#, fuzzy, noqa:fuzzy-stale
+#| msgid "this is a test"
+msgid "this is a test"
+msgstr "ceci est un test"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_stale_error() {
+        let diags = check_fuzzy_stale(
+            r#"
+#, fuzzy
+#| msgid "this is a "
+#| "test"
+msgid "this is a test"
+msgstr "ceci est un test"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "fuzzy flag but source unchanged");
+        assert_eq!(diag.lines.len(), 1);
+        assert_eq!(diag.lines[0].line_number, 5);
+        assert_eq!(diag.lines[0].highlights, vec![(0, 14)]);
+    }
+}
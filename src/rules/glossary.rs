@@ -0,0 +1,311 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `glossary` rule: terms from the source that must be
+//! translated as defined in the glossary of the language.
+
+use std::fs::read_to_string;
+use std::path::Path;
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::format::{iter::FormatWordPos, language::Language};
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+/// Term of a glossary: a source term and its approved translations, with their
+/// lowercase words used to find them in strings.
+#[derive(Debug)]
+pub struct GlossaryTerm {
+    pub source: String,
+    pub translations: Vec<String>,
+    source_words: Vec<String>,
+    translations_words: Vec<Vec<String>>,
+}
+
+/// Load a glossary file: each line has a source term and its translation, separated
+/// by a tab. Blank lines, lines starting with `#` and lines without a tab are ignored.
+///
+/// A source term can be given on several lines to allow several translations
+/// (e.g. singular and plural forms).
+pub fn load_glossary(path: &Path) -> Result<Vec<GlossaryTerm>, std::io::Error> {
+    let content = read_to_string(path)?;
+    let mut terms: Vec<GlossaryTerm> = vec![];
+    for line in content.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((source, translation)) = line.split_once('\t') else {
+            continue;
+        };
+        let (source, translation) = (source.trim(), translation.trim());
+        let source_words = lowercase_words(source);
+        let translation_words = lowercase_words(translation);
+        if source_words.is_empty() || translation_words.is_empty() {
+            continue;
+        }
+        if let Some(term) = terms.iter_mut().find(|t| t.source_words == source_words) {
+            term.translations.push(translation.to_string());
+            term.translations_words.push(translation_words);
+        } else {
+            terms.push(GlossaryTerm {
+                source: source.to_string(),
+                translations: vec![translation.to_string()],
+                source_words,
+                translations_words: vec![translation_words],
+            });
+        }
+    }
+    Ok(terms)
+}
+
+/// Get the lowercase words of a string.
+fn lowercase_words(s: &str) -> Vec<String> {
+    FormatWordPos::new(s, Language::Null)
+        .map(|w| w.s.to_lowercase())
+        .collect()
+}
+
+/// Find the indices in `words` where the sequence of words `seq` starts.
+fn find_words<'a>(words: &'a [String], seq: &'a [String]) -> impl Iterator<Item = usize> + 'a {
+    words
+        .windows(seq.len())
+        .enumerate()
+        .filter(move |(_, w)| *w == seq)
+        .map(|(idx, _)| idx)
+}
+
+pub struct GlossaryRule;
+
+impl RuleChecker for GlossaryRule {
+    fn name(&self) -> &'static str {
+        "glossary"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check that terms of the glossary are translated as defined in the glossary."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Check that every term of the glossary that appears in the source string is
+    /// translated with one of the translations defined in the glossary.
+    ///
+    /// The glossary is the file `<lang>.tsv` in the directory `check.glossary_dir`,
+    /// where `<lang>` is the language code found in the header (e.g. `fr`). The terms
+    /// are matched as whole words, case-insensitively.
+    ///
+    /// This rule is not enabled by default and is skipped when the glossary could
+    /// not be loaded.
+    ///
+    /// Wrong entry (with `file` translated as `fichier` in the glossary):
+    /// ```text
+    /// msgid "Open a file"
+    /// msgstr "Ouvrir un document"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "Open a file"
+    /// msgstr "Ouvrir un fichier"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`warning`](Severity::Warning): `'…' should be translated as '…'`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let Some(glossary) = checker.glossary.as_ref() else {
+            return vec![];
+        };
+        let id_words: Vec<_> = FormatWordPos::new(&msgid.value, entry.format_language).collect();
+        let id_lower: Vec<String> = id_words.iter().map(|w| w.s.to_lowercase()).collect();
+        let mut str_lower: Option<Vec<String>> = None;
+        let mut diags = vec![];
+        for term in glossary {
+            let len = term.source_words.len();
+            let id_hl: Vec<(usize, usize)> = find_words(&id_lower, &term.source_words)
+                .map(|idx| (id_words[idx].start, id_words[idx + len - 1].end))
+                .collect();
+            if id_hl.is_empty() {
+                continue;
+            }
+            let str_lower = str_lower.get_or_insert_with(|| {
+                FormatWordPos::new(&msgstr.value, entry.format_language)
+                    .map(|w| w.s.to_lowercase())
+                    .collect()
+            });
+            if term
+                .translations_words
+                .iter()
+                .any(|words| find_words(str_lower, words).next().is_some())
+            {
+                continue;
+            }
+            let translations = term
+                .translations
+                .iter()
+                .map(|t| format!("'{t}'"))
+                .collect::<Vec<_>>()
+                .join(" or ");
+            diags.extend(
+                self.new_diag(
+                    checker,
+                    self.severity(),
+                    format!("'{}' should be translated as {translations}", term.source),
+                )
+                .map(|d| d.with_msgs_hl(msgid, id_hl, msgstr, [])),
+            );
+        }
+        diags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{config::Config, diagnostic::Diagnostic, rules::rule::Rules};
+
+    const GLOSSARY_FR: &str = "# French glossary
+file\tfichier
+file\tfichiers
+password\tmot de passe
+folder
+";
+
+    /// Write a temporary glossary directory with a French glossary and return the
+    /// path along with the owning `TempDir`.
+    fn write_glossary_dir() -> (tempfile::TempDir, PathBuf) {
+        let tmp =
+            tempfile::TempDir::with_prefix("poexam-glossary-").expect("create glossary temp dir");
+        std::fs::write(tmp.path().join("fr.tsv"), GLOSSARY_FR).expect("write glossary file");
+        let path = tmp.path().to_path_buf();
+        (tmp, path)
+    }
+
+    fn check_glossary(glossary_dir: &Path, content: &str) -> Vec<Diagnostic> {
+        let mut config = Config::default();
+        config.check.glossary_dir = Some(glossary_dir.to_path_buf());
+        let mut checker = Checker::new(content.as_bytes()).with_config(config);
+        let rules = Rules::new(vec![Box::new(GlossaryRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_load_glossary() {
+        let (_tmp, dir) = write_glossary_dir();
+        let terms = load_glossary(&dir.join("fr.tsv")).expect("load glossary");
+        assert_eq!(terms.len(), 2);
+        assert_eq!(terms[0].source, "file");
+        assert_eq!(terms[0].translations, vec!["fichier", "fichiers"]);
+        assert_eq!(terms[1].source, "password");
+        assert_eq!(
+            terms[1].translations_words,
+            vec![vec!["mot", "de", "passe"]]
+        );
+    }
+
+    #[test]
+    fn test_glossary_ok() {
+        let (_tmp, dir) = write_glossary_dir();
+        let diags = check_glossary(
+            &dir,
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "Open a file"
+msgstr "Ouvrir un fichier"
+
+msgid "Files and folders"
+msgstr "Fichiers et dossiers"
+
+msgid "Enter the Password"
+msgstr "Entrez le mot de passe"
+
+msgid "Profile"
+msgstr "Profil"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_glossary_error() {
+        let (_tmp, dir) = write_glossary_dir();
+        let diags = check_glossary(
+            &dir,
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "Open a file"
+msgstr "Ouvrir un document"
+
+msgid "Enter the password"
+msgstr "Entrez le passe"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert_eq!(
+            diags[0].message,
+            "'file' should be translated as 'fichier' or 'fichiers'"
+        );
+        assert_eq!(diags[0].lines[0].highlights, vec![(7, 11)]);
+        assert_eq!(
+            diags[1].message,
+            "'password' should be translated as 'mot de passe'"
+        );
+        assert_eq!(diags[1].lines[0].highlights, vec![(10, 18)]);
+    }
+
+    #[test]
+    fn test_glossary_missing_file() {
+        let (_tmp, dir) = write_glossary_dir();
+        let diags = check_glossary(
+            &dir,
+            r#"
+msgid ""
+msgstr "Language: de\n"
+
+msgid "Open a file"
+msgstr "Datei öffnen"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert!(diags[0].message.starts_with("glossary file not found"));
+        let diags = check_glossary(
+            &dir,
+            r#"
+msgid "Open a file"
+msgstr "Ouvrir un document"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "language not found in header, glossary rule ignored"
+        );
+    }
+}
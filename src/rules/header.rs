@@ -93,6 +93,10 @@ impl RuleChecker for HeaderRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
     /// Check the PO file header for invalid or missing required fields.
     ///
     /// Field matching is case-insensitive (per RFC 822, which the gettext
@@ -0,0 +1,175 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `header-fields` rule: check required fields in header.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+/// Fields that must be present and not empty in the header.
+const REQUIRED_FIELDS: [&str; 4] = [
+    "Project-Id-Version",
+    "Language",
+    "Content-Type",
+    "Plural-Forms",
+];
+
+pub struct HeaderFieldsRule;
+
+impl RuleChecker for HeaderFieldsRule {
+    fn name(&self) -> &'static str {
+        "header-fields"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for missing or empty required fields in PO file header."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn is_source_only(&self) -> bool {
+        true
+    }
+
+    /// Check for missing or empty required fields in PO file header:
+    /// `Project-Id-Version`, `Language`, `Content-Type` (with a charset) and
+    /// `Plural-Forms`.
+    ///
+    /// This rule is stricter than the rule `header` on these fields: a field with
+    /// an empty value is reported as missing.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong header:
+    /// ```text
+    /// msgid ""
+    /// msgstr ""
+    /// "Project-Id-Version: \n"
+    /// "Language: fr\n"
+    /// "Content-Type: text/plain\n"
+    /// ```
+    ///
+    /// Correct header:
+    /// ```text
+    /// msgid ""
+    /// msgstr ""
+    /// "Project-Id-Version: poexam\n"
+    /// "Language: fr\n"
+    /// "Content-Type: text/plain; charset=UTF-8\n"
+    /// "Plural-Forms: nplurals=2; plural=(n > 1);\n"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`warning`](Severity::Warning): `missing header field: …`
+    /// - [`warning`](Severity::Warning): `missing charset in header field: Content-Type`
+    fn check_header(&self, checker: &Checker, _entry: &Entry, msgstr: &Message) -> Vec<Diagnostic> {
+        REQUIRED_FIELDS
+            .iter()
+            .filter_map(|field| {
+                let message = match checker.header_field(field) {
+                    None | Some("") => format!("missing header field: {field}"),
+                    Some(value) if *field == "Content-Type" && !has_charset(value) => {
+                        format!("missing charset in header field: {field}")
+                    }
+                    _ => return None,
+                };
+                self.new_diag(checker, self.severity(), message)
+                    .map(|d| d.with_msg(msgstr))
+            })
+            .collect()
+    }
+}
+
+/// Return `true` if the `Content-Type` value has a non-empty charset.
+fn has_charset(value: &str) -> bool {
+    value.find("charset=").is_some_and(|pos| {
+        value[pos + 8..]
+            .chars()
+            .next()
+            .is_some_and(|c| !c.is_whitespace() && c != ';')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_header_fields(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(HeaderFieldsRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_has_charset() {
+        assert!(has_charset("text/plain; charset=UTF-8"));
+        assert!(!has_charset("text/plain"));
+        assert!(!has_charset("text/plain; charset="));
+        assert!(!has_charset("text/plain; charset= "));
+    }
+
+    #[test]
+    fn test_header_fields_ok() {
+        let diags = check_header_fields(
+            r#"
+msgid ""
+msgstr ""
+"Project-Id-Version: poexam\n"
+"language: fr\n"
+"Content-Type: text/plain; charset=UTF-8\n"
+"Plural-Forms: nplurals=2; plural=(n > 1);\n"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_header_fields_noqa() {
+        let diags = check_header_fields(
+            r#"
+#, noqa:header-fields
+msgid ""
+msgstr ""
+"Language: fr\n"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_header_fields_error() {
+        let diags = check_header_fields(
+            r#"
+msgid ""
+msgstr ""
+"Project-Id-Version: \n"
+"Language: fr\n"
+"Content-Type: text/plain\n"
+"#,
+        );
+        assert_eq!(diags.len(), 3);
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert_eq!(diags[0].message, "missing header field: Project-Id-Version");
+        assert_eq!(
+            diags[1].message,
+            "missing charset in header field: Content-Type"
+        );
+        assert_eq!(diags[2].message, "missing header field: Plural-Forms");
+    }
+}
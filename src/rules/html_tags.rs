@@ -33,6 +33,10 @@ impl RuleChecker for HtmlTagsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check for missing, extra or different HTML tags in the translation.
     ///
     /// This rule is not enabled by default.
@@ -73,7 +77,7 @@ impl RuleChecker for HtmlTagsRule {
             std::cmp::Ordering::Greater => self
                 .new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!("missing HTML tags ({} / {})", id_tags.len(), str_tags.len()),
                 )
                 .map(|d| {
@@ -89,7 +93,7 @@ impl RuleChecker for HtmlTagsRule {
             std::cmp::Ordering::Less => self
                 .new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!("extra HTML tags ({} / {})", id_tags.len(), str_tags.len()),
                 )
                 .map(|d| {
@@ -127,7 +131,7 @@ impl RuleChecker for HtmlTagsRule {
                         edits,
                         safe: false,
                     });
-                    self.new_diag(checker, Severity::Warning, "different HTML tags")
+                    self.new_diag(checker, self.severity(), "different HTML tags")
                         .map(|d| {
                             d.with_msgs_hl(
                                 msgid,
@@ -0,0 +1,179 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `length-ratio` rule: check the ratio of translation/source lengths.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+/// Minimum number of chars in the source to check the ratio: the ratio of short
+/// strings is meaningless.
+const MIN_SOURCE_CHARS: usize = 10;
+
+pub struct LengthRatioRule;
+
+impl RuleChecker for LengthRatioRule {
+    fn name(&self) -> &'static str {
+        "length-ratio"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check that the ratio of translation/source lengths is in the configured range."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check that the ratio of translation/source lengths (in UTF-8 characters,
+    /// ignoring leading and trailing whitespace) is between `check.length_ratio_min`
+    /// (default: 0.2) and `check.length_ratio_max` (default: 3.0).
+    ///
+    /// Source strings with less than 10 characters are ignored. Unlike the rules
+    /// `short` and `long`, which report only extreme cases, this rule uses a
+    /// configurable range and is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "Save the file"
+    /// msgstr "Enregistrer le fichier sur le disque dans le dossier courant, maintenant"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "Save the file"
+    /// msgstr "Enregistrer le fichier"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `translation length ratio # exceeds max #`
+    /// - [`info`](Severity::Info): `translation length ratio # below min #`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        _entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let len_msgid = msgid.value.trim().chars().count();
+        let len_msgstr = msgstr.value.trim().chars().count();
+        if len_msgid < MIN_SOURCE_CHARS || len_msgstr == 0 {
+            return vec![];
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let ratio = len_msgstr as f64 / len_msgid as f64;
+        let (min, max) = (
+            checker.config.check.length_ratio_min,
+            checker.config.check.length_ratio_max,
+        );
+        let msg = if ratio > max {
+            format!("translation length ratio {ratio:.1} exceeds max {max:.1}")
+        } else if ratio < min {
+            format!("translation length ratio {ratio:.1} below min {min:.1}")
+        } else {
+            return vec![];
+        };
+        self.new_diag(checker, self.severity(), msg)
+            .map(|d| d.with_msgs(msgid, msgstr))
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_length_ratio(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(LengthRatioRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_length_ratio_ok() {
+        let diags = check_length_ratio(
+            r#"
+msgid "Save the file"
+msgstr "Enregistrer le fichier"
+
+msgid "OK"
+msgstr "D'accord, c'est parfait"
+
+msgid "Open the document"
+msgstr "Öffnen"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_length_ratio_noqa() {
+        let diags = check_length_ratio(
+            r#"
+#, noqa:length-ratio
+msgid "Save the file"
+msgstr "Enregistrer le fichier sur le disque dans le dossier courant, maintenant"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_length_ratio_error() {
+        let diags = check_length_ratio(
+            r#"
+msgid "Save the file"
+msgstr "Enregistrer le fichier sur le disque dans le dossier courant, maintenant"
+
+msgid "Open the document in a new window of the application"
+msgstr "Ouvrir"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        assert_eq!(diags[0].severity, Severity::Info);
+        assert_eq!(
+            diags[0].message,
+            "translation length ratio 5.5 exceeds max 3.0"
+        );
+        assert_eq!(
+            diags[1].message,
+            "translation length ratio 0.1 below min 0.2"
+        );
+    }
+
+    #[test]
+    fn test_length_ratio_config() {
+        let mut config = Config::default();
+        config.check.length_ratio_max = 1.5;
+        let mut checker = Checker::new(
+            r#"
+msgid "Save the file"
+msgstr "Enregistrer le fichier"
+"#
+            .as_bytes(),
+        )
+        .with_config(config);
+        let rules = Rules::new(vec![Box::new(LengthRatioRule {})]);
+        checker.do_all_checks(&rules);
+        assert_eq!(checker.diagnostics.len(), 1);
+        assert_eq!(
+            checker.diagnostics[0].message,
+            "translation length ratio 1.7 exceeds max 1.5"
+        );
+    }
+}
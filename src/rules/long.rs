@@ -29,6 +29,10 @@ impl RuleChecker for LongRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check for too long translation.
     ///
     /// This rule reports the entry if one of both conditions is met (leading and trailing
@@ -82,7 +86,7 @@ impl RuleChecker for LongRule {
         if len_msgid * checker.config.check.long_factor as usize <= len_msgstr {
             self.new_diag(
                 checker,
-                Severity::Warning,
+                self.severity(),
                 format!("translation too long ({len_msgid} / {len_msgstr})"),
             )
             .map(|d| d.with_msgs(msgid, msgstr))
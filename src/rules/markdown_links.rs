@@ -0,0 +1,308 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `markdown-links` rule: check Markdown links.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+pub struct MarkdownLinksRule;
+
+/// Markdown link found in a string: `[text](target)`.
+#[derive(Debug, PartialEq)]
+struct MarkdownLink<'a> {
+    /// Target of the link (without the optional title).
+    target: &'a str,
+    /// Position of the target in the string.
+    target_pos: (usize, usize),
+}
+
+/// Result of the scan of Markdown links in a string.
+#[derive(Debug, Default, PartialEq)]
+struct MarkdownLinks<'a> {
+    /// Well-formed links.
+    links: Vec<MarkdownLink<'a>>,
+    /// Positions of malformed links: `[text](target` without closing parenthesis,
+    /// or `[text] (target)` with whitespace between brackets and parentheses.
+    malformed: Vec<(usize, usize)>,
+}
+
+impl RuleChecker for MarkdownLinksRule {
+    fn name(&self) -> &'static str {
+        "markdown-links"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for altered or malformed Markdown links in translation."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check for altered or malformed Markdown links in the translation.
+    ///
+    /// The targets of the links `[text](target)` must be the same in the source
+    /// and the translation (in any order), only the text of the links can be translated.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "See [the documentation](https://example.com/doc)."
+    /// msgstr "Voir [la documentation] (https://example.com/fr/doc)."
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "See [the documentation](https://example.com/doc)."
+    /// msgstr "Voir [la documentation](https://example.com/doc)."
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `malformed markdown link`
+    /// - [`info`](Severity::Info): `altered markdown link target`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        _entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let id_links = scan_markdown_links(&msgid.value);
+        if id_links.links.is_empty() {
+            return vec![];
+        }
+        let str_links = scan_markdown_links(&msgstr.value);
+        if !str_links.malformed.is_empty() {
+            return self
+                .new_diag(checker, self.severity(), "malformed markdown link")
+                .map(|d| {
+                    d.with_msgs_hl(
+                        msgid,
+                        id_links.links.iter().map(|link| link.target_pos),
+                        msgstr,
+                        str_links.malformed,
+                    )
+                })
+                .into_iter()
+                .collect();
+        }
+        let mut id_targets: Vec<_> = id_links.links.iter().map(|link| link.target).collect();
+        let mut str_targets: Vec<_> = str_links.links.iter().map(|link| link.target).collect();
+        id_targets.sort_unstable();
+        str_targets.sort_unstable();
+        if id_targets == str_targets {
+            return vec![];
+        }
+        self.new_diag(checker, self.severity(), "altered markdown link target")
+            .map(|d| {
+                d.with_msgs_hl(
+                    msgid,
+                    id_links
+                        .links
+                        .iter()
+                        .filter(|link| !str_targets.contains(&link.target))
+                        .map(|link| link.target_pos),
+                    msgstr,
+                    str_links
+                        .links
+                        .iter()
+                        .filter(|link| !id_targets.contains(&link.target))
+                        .map(|link| link.target_pos),
+                )
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Scan Markdown links `[text](target)` in a string.
+///
+/// The target may contain balanced parentheses (e.g. `https://example.com/a_(b)`)
+/// and can be followed by a title, which is ignored: `[text](target "title")`.
+fn scan_markdown_links(s: &str) -> MarkdownLinks<'_> {
+    let mut result = MarkdownLinks::default();
+    let bytes = s.as_bytes();
+    let mut pos = 0;
+    while let Some(offset) = memchr::memchr(b']', &bytes[pos..]) {
+        let idx_bracket = pos + offset;
+        pos = idx_bracket + 1;
+        let Some(idx_open) = s[..idx_bracket].rfind('[') else {
+            continue;
+        };
+        let after = &s[pos..];
+        let after_trimmed = after.trim_start_matches([' ', '\t']);
+        if after_trimmed.len() < after.len() {
+            // Whitespace between `]` and `(`: not a link, but looks like a broken one
+            // if the parentheses contain a single word (e.g. an URL).
+            if let Some(end) = parenthesized_len(after_trimmed)
+                && !after_trimmed[1..end - 1].contains(char::is_whitespace)
+            {
+                let start_paren = s.len() - after_trimmed.len();
+                result.malformed.push((idx_open, start_paren + end));
+                pos = start_paren + end;
+            }
+            continue;
+        }
+        if !after.starts_with('(') {
+            continue;
+        }
+        let Some(end) = parenthesized_len(after) else {
+            result.malformed.push((idx_open, s.len()));
+            break;
+        };
+        let inner = &after[1..end - 1];
+        let target = inner.trim_start();
+        let target = target
+            .find(char::is_whitespace)
+            .map_or(target, |idx| &target[..idx]);
+        let start_target = pos + 1 + (inner.len() - inner.trim_start().len());
+        result.links.push(MarkdownLink {
+            target,
+            target_pos: (start_target, start_target + target.len()),
+        });
+        pos += end;
+    }
+    result
+}
+
+/// Return the length of the parenthesized string at the start of `s` (including
+/// the parentheses), `None` if `s` does not start with `(` or if the closing
+/// parenthesis is missing.
+fn parenthesized_len(s: &str) -> Option<usize> {
+    if !s.starts_with('(') {
+        return None;
+    }
+    let mut depth = 0;
+    for (idx, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(idx + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_markdown_links(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(MarkdownLinksRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_scan_markdown_links() {
+        assert_eq!(scan_markdown_links(""), MarkdownLinks::default());
+        assert_eq!(
+            scan_markdown_links("[optional] (default: 1), [a] b (c d)"),
+            MarkdownLinks::default()
+        );
+        assert_eq!(
+            scan_markdown_links("[a](x) and [b]( https://e.com/a_(b) \"title\")"),
+            MarkdownLinks {
+                links: vec![
+                    MarkdownLink {
+                        target: "x",
+                        target_pos: (4, 5),
+                    },
+                    MarkdownLink {
+                        target: "https://e.com/a_(b)",
+                        target_pos: (16, 35),
+                    },
+                ],
+                malformed: vec![],
+            }
+        );
+        assert_eq!(
+            scan_markdown_links("[a] (x) and [b](y"),
+            MarkdownLinks {
+                links: vec![],
+                malformed: vec![(0, 7), (12, 17)],
+            }
+        );
+    }
+
+    #[test]
+    fn test_markdown_links_ok() {
+        let diags = check_markdown_links(
+            r#"
+msgid "See [the doc](https://example.com/doc) and [the FAQ](faq.md \"FAQ\")."
+msgstr "Voir [la FAQ](faq.md \"FAQ\") et [la doc](https://example.com/doc)."
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_markdown_links_noqa() {
+        let diags = check_markdown_links(
+            r#"
+#, noqa:markdown-links
+msgid "See [the doc](https://example.com/doc)."
+msgstr "Voir [la doc](https://example.com/fr/doc)."
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_markdown_links_altered() {
+        let diags = check_markdown_links(
+            r#"
+msgid "See [the doc](https://example.com/doc) and [the FAQ](faq.md)."
+msgstr "Voir [la doc](https://example.com/fr/doc) et [la FAQ](faq.md)."
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "altered markdown link target");
+        assert_eq!(diag.lines[0].highlights, vec![(14, 37)]);
+        assert_eq!(diag.lines[2].highlights, vec![(14, 40)]);
+    }
+
+    #[test]
+    fn test_markdown_links_malformed() {
+        let diags = check_markdown_links(
+            r#"
+msgid "See [the doc](https://example.com/doc)."
+msgstr "Voir [la doc] (https://example.com/doc)."
+
+msgid "See [the doc](https://example.com/doc)."
+msgstr "Voir [la doc](https://example.com/doc."
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "malformed markdown link");
+        assert_eq!(diag.lines[2].highlights, vec![(5, 39)]);
+        let diag = &diags[1];
+        assert_eq!(diag.message, "malformed markdown link");
+        assert_eq!(diag.lines[2].highlights, vec![(5, 38)]);
+    }
+}
@@ -6,35 +6,63 @@
 
 pub mod accelerators;
 pub mod acronyms;
+pub mod ascii_id;
 pub mod blank;
+pub mod blank_lines;
+pub mod bom;
 pub mod brackets;
+pub mod capitalization;
 pub mod changed;
+pub mod charset;
 pub mod compilation;
+pub mod components;
+pub mod context_missing;
 pub mod double_quotes;
 pub mod double_spaces;
 pub mod double_words;
+pub mod duplicate;
+pub mod ellipsis;
 pub mod emails;
+pub mod empty_msgid;
 pub mod encoding;
 pub mod escapes;
 pub mod force_trans;
 pub mod formats;
 pub mod functions;
 pub mod fuzzy;
+pub mod fuzzy_stale;
+pub mod glossary;
 pub mod header;
+pub mod header_fields;
 pub mod html_tags;
+pub mod length_ratio;
 pub mod long;
+pub mod markdown_links;
+pub mod msgid_whitespace;
+pub mod nbsp;
 pub mod newlines;
 pub mod no_trans;
 pub mod noqa;
+pub mod number_locale;
 pub mod obsolete;
+pub mod obsolete_count;
 pub mod paths;
 pub mod pipes;
+pub mod placeholder_order_c;
+pub mod plural_forms_expression;
+pub mod plural_incomplete;
+pub mod plural_index_gaps;
+pub mod plural_malformed;
 pub mod plurals;
+pub mod printf_types;
 pub mod punc;
+pub mod punc_repeat;
 pub mod punc_space;
 pub mod rule;
 pub mod short;
+pub mod smart_quotes;
 pub mod spelling;
+pub mod tab_position;
 pub mod tabs;
 pub mod unchanged;
 pub mod unicode_ctrl;
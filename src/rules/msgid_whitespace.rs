@@ -0,0 +1,208 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `msgid-whitespace` rule: check leading/trailing whitespace in source.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+pub struct MsgidWhitespaceRule;
+
+impl RuleChecker for MsgidWhitespaceRule {
+    fn name(&self) -> &'static str {
+        "msgid-whitespace"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for unexpected leading or trailing whitespace in source string."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn is_source_only(&self) -> bool {
+        true
+    }
+
+    fn checks_untranslated(&self) -> bool {
+        true
+    }
+
+    /// Check for unexpected leading or trailing whitespace (except newlines) in the
+    /// source string.
+    ///
+    /// The whitespace is considered intentional if a translation starts (or ends)
+    /// with the same whitespace, so only the source strings whose whitespace is not
+    /// kept in the translations (or not translated yet) are reported.
+    ///
+    /// The source (`msgid`) and the plural source (`msgid_plural`) are checked once
+    /// each, including in untranslated entries.
+    ///
+    /// Unlike the rules `whitespace-start` and `whitespace-end` which report an
+    /// inconsistency in the translation, this rule reports an issue in the source
+    /// string, to be fixed by the developers.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "this is a test "
+    /// msgstr "ceci est un test"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "this is a test"
+    /// msgstr "ceci est un test"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `leading whitespace in source`
+    /// - [`info`](Severity::Info): `trailing whitespace in source`
+    fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
+        let mut diags = vec![];
+        for msgid in entry.msgid.iter().chain(entry.msgid_plural.iter()) {
+            self.check_source(checker, entry, msgid, &mut diags);
+        }
+        diags
+    }
+}
+
+impl MsgidWhitespaceRule {
+    /// Check a source string (`msgid` or `msgid_plural`) of the entry.
+    fn check_source(
+        &self,
+        checker: &Checker,
+        entry: &Entry,
+        msgid: &Message,
+        diags: &mut Vec<Diagnostic>,
+    ) {
+        if msgid.value.trim().is_empty() {
+            return;
+        }
+        let new_diag = |message: &'static str, hl: (usize, usize)| {
+            self.new_diag(checker, self.severity(), message)
+                .map(|d| match entry.msgstr.get(&0) {
+                    Some(msgstr) => d.with_msgs_hl(msgid, [hl], msgstr, []),
+                    None => d.with_msg_hl(msgid, [hl]),
+                })
+        };
+        let id_ws = msgid.value.trim_start_matches(is_space);
+        let id_ws = &msgid.value[..msgid.value.len() - id_ws.len()];
+        if !id_ws.is_empty() && !entry.msgstr.values().any(|s| s.value.starts_with(id_ws)) {
+            diags.extend(new_diag("leading whitespace in source", (0, id_ws.len())));
+        }
+        let id_ws = &msgid.value[msgid.value.trim_end_matches(is_space).len()..];
+        if !id_ws.is_empty() && !entry.msgstr.values().any(|s| s.value.ends_with(id_ws)) {
+            let len = msgid.value.len();
+            diags.extend(new_diag(
+                "trailing whitespace in source",
+                (len - id_ws.len(), len),
+            ));
+        }
+    }
+}
+
+/// Return `true` if the char is a whitespace other than a newline.
+fn is_space(c: char) -> bool {
+    c.is_whitespace() && c != '\n'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_msgid_whitespace(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(MsgidWhitespaceRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_msgid_whitespace_ok() {
+        let diags = check_msgid_whitespace(
+            r#"
+msgid "this is a test\n"
+msgstr "ceci est un test\n"
+
+msgid "name: "
+msgstr "nom : "
+
+msgid "\tindented"
+msgstr "\tindenté"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_msgid_whitespace_noqa() {
+        let diags = check_msgid_whitespace(
+            r#"
+#, noqa:msgid-whitespace
+msgid "this is a test "
+msgstr "ceci est un test"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_msgid_whitespace_error() {
+        let diags = check_msgid_whitespace(
+            r#"
+msgid "this is a test  "
+msgstr "ceci est un test"
+
+msgid " test\n"
+msgstr "test\n"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "trailing whitespace in source");
+        assert_eq!(diag.lines[0].highlights, vec![(14, 16)]);
+        let diag = &diags[1];
+        assert_eq!(diag.message, "leading whitespace in source");
+        assert_eq!(diag.lines[0].highlights, vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_msgid_whitespace_plural_and_untranslated() {
+        let diags = check_msgid_whitespace(
+            r#"
+msgid ""
+msgstr "Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+msgid "%d file"
+msgid_plural "%d files "
+msgstr[0] "%d plik"
+msgstr[1] "%d pliki"
+msgstr[2] "%d plików"
+
+msgid "untranslated "
+msgstr ""
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        assert_eq!(diags[0].message, "trailing whitespace in source");
+        assert_eq!(diags[0].lines[0].message, "%d files ");
+        assert_eq!(diags[1].lines[0].message, "untranslated ");
+    }
+}
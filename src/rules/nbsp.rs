@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `nbsp` rule: check non-breaking spaces before punctuation.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+/// Punctuation that must be preceded by a non-breaking space, by language code.
+const NBSP_PUNCTUATION: [(&str, &[char]); 1] = [("fr", &[':', ';', '!', '?'])];
+
+pub struct NbspRule;
+
+impl RuleChecker for NbspRule {
+    fn name(&self) -> &'static str {
+        "nbsp"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for missing non-breaking space before punctuation in translation."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check for missing non-breaking space before punctuation in the translation.
+    ///
+    /// Only French is supported: a non-breaking space (U+00A0) or a narrow
+    /// non-breaking space (U+202F) is expected before `:`, `;`, `!` and `?`.
+    ///
+    /// Unlike the rule `punc-space-str`, a punctuation without any space before
+    /// is reported as well. A punctuation followed by another char than a whitespace
+    /// or a punctuation (for example in `10:30` or `https://`) is ignored.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "this is a test!"
+    /// msgstr "ceci est un test!"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "this is a test!"
+    /// msgstr "ceci est un test !"
+    /// ```
+    /// (with a non-breaking space before `!`)
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `expected non-breaking space before 'x'`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        _entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let lang_code = checker.language_code();
+        let Some((_, punctuation)) = NBSP_PUNCTUATION.iter().find(|(lang, _)| *lang == lang_code)
+        else {
+            return vec![];
+        };
+        let mut diags = vec![];
+        let mut prev: Option<(usize, char)> = None;
+        let mut chars_iter = msgstr.value.char_indices().peekable();
+        while let Some((idx, c)) = chars_iter.next() {
+            let next = chars_iter.peek().map(|(_, next_c)| *next_c);
+            if punctuation.contains(&c)
+                && next.is_none_or(|n| n.is_whitespace() || punctuation.contains(&n))
+                && let Some((prev_idx, prev_c)) = prev
+                && !matches!(prev_c, '\u{00A0}' | '\u{202F}')
+                && !punctuation.contains(&prev_c)
+            {
+                let start = if prev_c.is_whitespace() {
+                    prev_idx
+                } else {
+                    idx
+                };
+                diags.extend(
+                    self.new_diag(
+                        checker,
+                        self.severity(),
+                        format!("expected non-breaking space before '{c}'"),
+                    )
+                    .map(|d| d.with_msgs_hl(msgid, [], msgstr, [(start, idx + c.len_utf8())])),
+                );
+            }
+            prev = Some((idx, c));
+        }
+        diags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_nbsp(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(NbspRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_nbsp_ok() {
+        let diags = check_nbsp(
+            "
+msgid \"\"
+msgstr \"Language: fr\\n\"
+
+msgid \"status: done, this is a test?! at 10:30 on https://example.com\"
+msgstr \"état\u{00A0}: fait, ceci est un test\u{202F}?! à 10:30 sur https://example.com\"
+",
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_nbsp_other_language() {
+        let diags = check_nbsp(
+            r#"
+msgid ""
+msgstr "Language: de\n"
+
+msgid "this is a test!"
+msgstr "das ist ein Test!"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_nbsp_noqa() {
+        let diags = check_nbsp(
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+#, noqa:nbsp
+msgid "this is a test!"
+msgstr "ceci est un test!"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_nbsp_error() {
+        let diags = check_nbsp(
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "status: done; this is a test!"
+msgstr "état: fait ; ceci est un test!"
+"#,
+        );
+        assert_eq!(diags.len(), 3);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "expected non-breaking space before ':'");
+        assert_eq!(diag.lines[2].highlights, vec![(5, 6)]);
+        let diag = &diags[1];
+        assert_eq!(diag.message, "expected non-breaking space before ';'");
+        assert_eq!(diag.lines[2].highlights, vec![(11, 13)]);
+        let diag = &diags[2];
+        assert_eq!(diag.message, "expected non-breaking space before '!'");
+    }
+}
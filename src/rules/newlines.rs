@@ -30,6 +30,10 @@ impl RuleChecker for NewlinesRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
     /// Check for missing or extra newlines in the translation: carriage return (`\r`) or line feed (`\n`).
     ///
     /// Wrong entry:
@@ -93,7 +97,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         format!("missing carriage returns '\\r' ({id_count_cr} / {str_count_cr})"),
                     )
                     .map(|d| d.with_msgs(msgid, msgstr)),
@@ -103,7 +107,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         format!("extra carriage returns '\\r' ({id_count_cr} / {str_count_cr})"),
                     )
                     .map(|d| d.with_msgs(msgid, msgstr)),
@@ -119,7 +123,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         format!("missing line feeds '\\n' ({id_count_lf} / {str_count_lf})"),
                     )
                     .map(|d| d.with_msgs(msgid, msgstr)),
@@ -129,7 +133,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         format!("extra line feeds '\\n' ({id_count_lf} / {str_count_lf})"),
                     )
                     .map(|d| d.with_msgs(msgid, msgstr)),
@@ -173,7 +177,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         "missing carriage return '\\r' at the beginning".to_string(),
                     )
                     .map(|d| d.with_msgs(msgid, msgstr).with_optional_fix(fix.clone())),
@@ -183,7 +187,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         "extra carriage return '\\r' at the beginning".to_string(),
                     )
                     .map(|d| d.with_msgs(msgid, msgstr).with_optional_fix(fix.clone())),
@@ -199,7 +203,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         "missing line feed '\\n' at the beginning".to_string(),
                     )
                     .map(|d| d.with_msgs(msgid, msgstr).with_optional_fix(fix.clone())),
@@ -209,7 +213,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         "extra line feed '\\n' at the beginning".to_string(),
                     )
                     .map(|d| d.with_msgs(msgid, msgstr).with_optional_fix(fix.clone())),
@@ -253,7 +257,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         "missing carriage return '\\r' at the end".to_string(),
                     )
                     .map(|d| d.with_msgs(msgid, msgstr).with_optional_fix(fix.clone())),
@@ -263,7 +267,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         "extra carriage return '\\r' at the end".to_string(),
                     )
                     .map(|d| d.with_msgs(msgid, msgstr).with_optional_fix(fix.clone())),
@@ -279,7 +283,7 @@ impl NewlinesRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Error,
+                        self.severity(),
                         "missing line feed '\\n' at the end",
                     )
                     .map(|d| d.with_msgs(msgid, msgstr).with_optional_fix(fix.clone())),
@@ -287,7 +291,7 @@ impl NewlinesRule {
             }
             std::cmp::Ordering::Less => {
                 diags.extend(
-                    self.new_diag(checker, Severity::Error, "extra line feed '\\n' at the end")
+                    self.new_diag(checker, self.severity(), "extra line feed '\\n' at the end")
                         .map(|d| d.with_msgs(msgid, msgstr).with_optional_fix(fix.clone())),
                 );
             }
@@ -34,6 +34,10 @@ impl RuleChecker for NoTransRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check that every word listed in `check.no_trans_file` that appears in
     /// the source string also appears in the translation, the same number of
     /// times, and with the **exact case used in the source** (which may
@@ -114,7 +118,7 @@ impl RuleChecker for NoTransRule {
             diags.extend(
                 self.new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!(
                         "word '{word}' must not be translated ({id_count} in source, {str_count} in translation)"
                     ),
@@ -28,6 +28,10 @@ impl RuleChecker for NoqaRule {
         false
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Report entry if it has a `noqa` comment.
     ///
     /// This rule is not enabled by default.
@@ -49,7 +53,7 @@ impl RuleChecker for NoqaRule {
     /// - [`info`](Severity::Info): `entry with noqa`
     fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
         if entry.noqa || !entry.noqa_rules.is_empty() {
-            self.new_diag(checker, Severity::Info, "entry with noqa")
+            self.new_diag(checker, self.severity(), "entry with noqa")
                 .map(|d| d.with_keywords(entry).with_entry(entry))
                 .into_iter()
                 .collect()
@@ -0,0 +1,286 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `number-locale` rule: check separators of numbers in translation.
+
+use std::ops::Range;
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::format::{iter::FormatPos, language::Language};
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+/// Default languages using a decimal comma (e.g. `1 234,56` in French and
+/// `1.234,56` in German), see option `number_locales`.
+pub const DEFAULT_NUMBER_LOCALES: &[&str] = &["de", "es", "fr", "it", "nl", "pt", "ru"];
+
+pub struct NumberLocaleRule;
+
+impl RuleChecker for NumberLocaleRule {
+    fn name(&self) -> &'static str {
+        "number-locale"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check that numbers in translation use the decimal and grouping separators of the language."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check that the formatted numbers of the source (with English separators, e.g.
+    /// `1,234.56` or `3.5`) are not copied as-is in the translation, for languages
+    /// using a decimal comma (e.g. `1 234,56` in French, `1.234,56` in German).
+    ///
+    /// The languages checked are set by option `number_locales` (default: `de`, `es`,
+    /// `fr`, `it`, `nl`, `pt`, `ru`). Numbers in format strings and version-like numbers
+    /// (e.g. `1.2.3`) are ignored.
+    ///
+    /// This rule is heuristic and is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "Total: 1,234.56 MB"
+    /// msgstr "Total : 1,234.56 Mo"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "Total: 1,234.56 MB"
+    /// msgstr "Total : 1 234,56 Mo"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `number '…' uses source locale separators`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let language = checker.language_code();
+        if !checker
+            .config
+            .check
+            .number_locales
+            .iter()
+            .any(|lang| lang == language)
+        {
+            return vec![];
+        }
+        let id_numbers = english_numbers(&msgid.value, entry.format_language);
+        if id_numbers.is_empty() {
+            return vec![];
+        }
+        let str_numbers = english_numbers(&msgstr.value, entry.format_language);
+        let mut diags = vec![];
+        let mut reported: Vec<&str> = vec![];
+        for id_range in &id_numbers {
+            let number = &msgid.value[id_range.clone()];
+            if reported.contains(&number) {
+                continue;
+            }
+            let str_hl: Vec<(usize, usize)> = str_numbers
+                .iter()
+                .filter(|r| &msgstr.value[(*r).clone()] == number)
+                .map(|r| (r.start, r.end))
+                .collect();
+            if str_hl.is_empty() {
+                continue;
+            }
+            reported.push(number);
+            diags.extend(
+                self.new_diag(
+                    checker,
+                    self.severity(),
+                    format!("number '{number}' uses source locale separators"),
+                )
+                .map(|d| d.with_msgs_hl(msgid, [(id_range.start, id_range.end)], msgstr, str_hl)),
+            );
+        }
+        diags
+    }
+}
+
+/// Return `true` if the number uses the English separators: comma to group
+/// thousands and period for decimals, with at least one separator
+/// (e.g. `1,234`, `1,234.56`, `3.5`).
+fn is_english_number(s: &str) -> bool {
+    let (int_part, dec_part) = s.split_once('.').unwrap_or((s, ""));
+    if s.contains('.') && (dec_part.is_empty() || !dec_part.bytes().all(|b| b.is_ascii_digit())) {
+        return false;
+    }
+    let mut groups = int_part.split(',');
+    let first = groups.next().unwrap_or_default();
+    if first.is_empty() || !first.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    let mut has_grouping = false;
+    for group in groups {
+        if first.len() > 3 || group.len() != 3 || !group.bytes().all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+        has_grouping = true;
+    }
+    has_grouping || !dec_part.is_empty()
+}
+
+/// Find the numbers with English separators in `value` (see [`is_english_number`]),
+/// ignoring numbers in format strings. Each number is returned as a byte range.
+fn english_numbers(value: &str, language: Language) -> Vec<Range<usize>> {
+    let fmt_pos: Vec<_> = FormatPos::new(value, language)
+        .map(|m| m.start..m.end)
+        .collect();
+    let bytes = value.as_bytes();
+    let mut numbers = vec![];
+    let mut pos = 0;
+    while pos < bytes.len() {
+        if !bytes[pos].is_ascii_digit() || (pos > 0 && bytes[pos - 1].is_ascii_alphanumeric()) {
+            pos += 1;
+            continue;
+        }
+        let start = pos;
+        while pos < bytes.len() && (bytes[pos].is_ascii_digit() || b".,".contains(&bytes[pos])) {
+            pos += 1;
+        }
+        // Separators at the end are punctuation (e.g. end of sentence).
+        let mut end = pos;
+        while end > start && b".,".contains(&bytes[end - 1]) {
+            end -= 1;
+        }
+        if pos < bytes.len() && bytes[pos].is_ascii_alphabetic() {
+            continue;
+        }
+        if is_english_number(&value[start..end]) && !fmt_pos.iter().any(|r| r.contains(&start)) {
+            numbers.push(start..end);
+        }
+    }
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_number_locale(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(NumberLocaleRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_is_english_number() {
+        assert!(is_english_number("1,234"));
+        assert!(is_english_number("1,234,567.89"));
+        assert!(is_english_number("3.5"));
+        assert!(!is_english_number("1234"));
+        assert!(!is_english_number("1.2.3"));
+        assert!(!is_english_number("1234,567"));
+        assert!(!is_english_number("1,23"));
+        assert!(!is_english_number("1 234,56"));
+    }
+
+    #[test]
+    fn test_english_numbers() {
+        assert_eq!(
+            english_numbers(
+                "Size: 1,234.56 MB, version 1.2.3, ratio 3.5.",
+                Language::Null
+            ),
+            vec![6..14, 40..43]
+        );
+        assert!(english_numbers("Value: %5.2f, id x1.5", Language::C).is_empty());
+    }
+
+    #[test]
+    fn test_number_locale_fr() {
+        let diags = check_number_locale(
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "Total: 1,234.56 MB"
+msgstr "Total : 1 234,56 Mo"
+
+msgid "Version 1.2.3 with %.1f%%"
+msgstr "Version 1.2.3 avec %.1f%%"
+
+msgid "Free: 1,234.56 MB of 2.5 GB"
+msgstr "Libre : 1,234.56 Mo sur 2,5 Go"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Info);
+        assert_eq!(
+            diags[0].message,
+            "number '1,234.56' uses source locale separators"
+        );
+        assert_eq!(diags[0].lines[0].highlights, vec![(6, 14)]);
+        assert_eq!(diags[0].lines[2].highlights, vec![(8, 16)]);
+    }
+
+    #[test]
+    fn test_number_locale_de() {
+        let diags = check_number_locale(
+            r#"
+msgid ""
+msgstr "Language: de\n"
+
+msgid "Speed: 3.5 MB/s, total: 1,000 files"
+msgstr "Geschwindigkeit: 3.5 MB/s, insgesamt: 1.000 Dateien"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "number '3.5' uses source locale separators"
+        );
+    }
+
+    #[test]
+    fn test_number_locale_configured() {
+        let content = r#"
+msgid ""
+msgstr "Language: pl\n"
+
+msgid "Total: 1,234.56 MB"
+msgstr "Razem: 1,234.56 MB"
+"#;
+        assert!(check_number_locale(content).is_empty());
+        let mut config = Config::default();
+        config.check.number_locales = vec!["pl".to_string()];
+        let mut checker = Checker::new(content.as_bytes()).with_config(config);
+        let rules = Rules::new(vec![Box::new(NumberLocaleRule {})]);
+        checker.do_all_checks(&rules);
+        assert_eq!(checker.diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn test_number_locale_other_language() {
+        let diags = check_number_locale(
+            r#"
+msgid ""
+msgstr "Language: ja\n"
+
+msgid "Total: 1,234.56 MB"
+msgstr "合計: 1,234.56 MB"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+}
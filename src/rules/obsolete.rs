@@ -29,6 +29,10 @@ impl RuleChecker for ObsoleteRule {
         false
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Report entry if obsolete.
     ///
     /// Obsolete is not strictly speaking an error, but this check helps to identify
@@ -61,7 +65,7 @@ impl RuleChecker for ObsoleteRule {
                 edits: Vec::new(),
                 safe: true,
             };
-            self.new_diag(checker, Severity::Info, "obsolete entry")
+            self.new_diag(checker, self.severity(), "obsolete entry")
                 .map(|d| d.with_entry(entry).with_fix(fix))
                 .into_iter()
                 .collect()
@@ -120,6 +124,21 @@ msgstr "testé"
         assert_eq!(diag.message, "obsolete entry");
     }
 
+    #[test]
+    fn test_obsolete_with_context_error() {
+        let diags = check_obsolete(
+            r#"
+#~ msgctxt "context"
+#~ msgid "tested"
+#~ msgstr "testé"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.message, "obsolete entry");
+        assert_eq!(diag.msgctxt.as_deref(), Some("context"));
+    }
+
     #[test]
     fn test_obsolete_fix_targets_entire_entry() {
         let content = "\n#~ msgid \"tested\"\n#~ msgstr \"testé\"\n";
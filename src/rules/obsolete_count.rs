@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `obsolete-count` rule: report files with too many obsolete entries.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::rules::rule::RuleChecker;
+
+pub struct ObsoleteCountRule;
+
+impl RuleChecker for ObsoleteCountRule {
+    fn name(&self) -> &'static str {
+        "obsolete-count"
+    }
+
+    fn description(&self) -> &'static str {
+        "Report files with too many obsolete entries."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        false
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Report file if the number of obsolete entries is greater than the option
+    /// `max_obsolete` (default: 10).
+    ///
+    /// Obsolete entries accumulate over time and make the file bigger: they can be
+    /// removed with `msgattrib --no-obsolete`.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Reported (with `max_obsolete = 1`):
+    /// ```text
+    /// #~ msgid "this is a test"
+    /// #~ msgstr "ceci est un test"
+    ///
+    /// #~ msgid "this is another test"
+    /// #~ msgstr "ceci est un autre test"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `file has # obsolete entries (threshold #)`
+    fn check_file_end(&self, checker: &Checker) -> Vec<Diagnostic> {
+        let count = checker.obsolete_entries;
+        let threshold = checker.config.check.max_obsolete;
+        if count <= threshold {
+            return vec![];
+        }
+        self.new_diag(
+            checker,
+            self.severity(),
+            format!("file has {count} obsolete entries (threshold {threshold})"),
+        )
+        .into_iter()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, diagnostic::Diagnostic, rules::rule::Rules};
+    use std::fmt::Write as _;
+
+    fn check_obsolete_count(count: usize, max_obsolete: usize) -> Vec<Diagnostic> {
+        let mut content = String::from("msgid \"tested\"\nmsgstr \"testé\"\n");
+        for i in 0..count {
+            let _ = write!(content, "\n#~ msgid \"test {i}\"\n#~ msgstr \"test {i}\"\n");
+        }
+        let mut config = Config::default();
+        config.check.max_obsolete = max_obsolete;
+        let mut checker = Checker::new(content.as_bytes()).with_config(config);
+        let rules = Rules::new(vec![Box::new(ObsoleteCountRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_obsolete_count_ok() {
+        assert!(check_obsolete_count(0, 0).is_empty());
+        assert!(check_obsolete_count(3, 3).is_empty());
+        assert!(check_obsolete_count(10, 10).is_empty());
+    }
+
+    #[test]
+    fn test_obsolete_count_error() {
+        let diags = check_obsolete_count(4, 3);
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "file has 4 obsolete entries (threshold 3)");
+        let diags = check_obsolete_count(42, 10);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "file has 42 obsolete entries (threshold 10)"
+        );
+    }
+}
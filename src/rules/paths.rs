@@ -34,6 +34,10 @@ impl RuleChecker for PathsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check for missing, extra or different paths in the translation.
     ///
     /// This rule is not enabled by default.
@@ -74,7 +78,7 @@ impl RuleChecker for PathsRule {
             std::cmp::Ordering::Greater => self
                 .new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!("missing paths ({} / {})", id_paths.len(), str_paths.len()),
                 )
                 .map(|d| {
@@ -90,7 +94,7 @@ impl RuleChecker for PathsRule {
             std::cmp::Ordering::Less => self
                 .new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!("extra paths ({} / {})", id_paths.len(), str_paths.len()),
                 )
                 .map(|d| {
@@ -128,7 +132,7 @@ impl RuleChecker for PathsRule {
                         edits,
                         safe: false,
                     });
-                    self.new_diag(checker, Severity::Warning, "different paths")
+                    self.new_diag(checker, self.severity(), "different paths")
                         .map(|d| {
                             d.with_msgs_hl(
                                 msgid,
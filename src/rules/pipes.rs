@@ -29,6 +29,10 @@ impl RuleChecker for PipesRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for missing or extra pipes in the translation.
     ///
     /// Wrong entry:
@@ -64,7 +68,7 @@ impl RuleChecker for PipesRule {
                 format!("extra pipes '|' ({id_count} / {str_count})")
             }
         };
-        self.new_diag(checker, Severity::Info, msg)
+        self.new_diag(checker, self.severity(), msg)
             .map(|d| {
                 d.with_msgs_hl(
                     msgid,
@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `placeholder-order-c` rule: check C format strings
+//! reordered without positions.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::format::language::Language;
+use crate::po::format::{
+    iter::FormatPos,
+    lang_c::{fmt_sort_index, fmt_strip_index},
+};
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+pub struct PlaceholderOrderCRule;
+
+impl RuleChecker for PlaceholderOrderCRule {
+    fn name(&self) -> &'static str {
+        "placeholder-order-c"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for C format strings reordered without positions in translation."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Check for C format strings reordered without positions (`%1$s`) in the translation.
+    ///
+    /// The same format strings are used in source and translation, but in a different
+    /// order: the arguments are then passed in the wrong order at runtime, which can
+    /// cause a crash. The translation must use positions to reorder format strings.
+    ///
+    /// Entries using positions in source or translation are ignored (they are checked
+    /// by the `formats` rule), as well as entries with different format strings.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// #, c-format
+    /// msgid "%s has %d files"
+    /// msgstr "%d fichiers dans %s"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// #, c-format
+    /// msgid "%s has %d files"
+    /// msgstr "%2$d fichiers dans %1$s"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`warning`](Severity::Warning): `format strings reordered without positions`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        if entry.format_language != Language::C {
+            return vec![];
+        }
+        let id_fmt: Vec<_> = FormatPos::new(&msgid.value, entry.format_language).collect();
+        let str_fmt: Vec<_> = FormatPos::new(&msgstr.value, entry.format_language).collect();
+        if id_fmt
+            .iter()
+            .chain(str_fmt.iter())
+            .any(|m| fmt_sort_index(m.s) != usize::MAX)
+        {
+            return vec![];
+        }
+        let id_fmt2: Vec<_> = id_fmt.iter().map(|m| fmt_strip_index(m.s)).collect();
+        let str_fmt2: Vec<_> = str_fmt.iter().map(|m| fmt_strip_index(m.s)).collect();
+        if id_fmt2 == str_fmt2 {
+            return vec![];
+        }
+        let mut id_sorted = id_fmt2;
+        let mut str_sorted = str_fmt2;
+        id_sorted.sort_unstable();
+        str_sorted.sort_unstable();
+        if id_sorted != str_sorted {
+            // Different format strings: reported by the `formats` rule.
+            return vec![];
+        }
+        self.new_diag(
+            checker,
+            self.severity(),
+            "format strings reordered without positions",
+        )
+        .map(|d| {
+            d.with_msgs_hl(
+                msgid,
+                id_fmt.iter().map(|m| (m.start, m.end)),
+                msgstr,
+                str_fmt.iter().map(|m| (m.start, m.end)),
+            )
+        })
+        .into_iter()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_placeholder_order_c(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(PlaceholderOrderCRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_placeholder_order_c_ok() {
+        let diags = check_placeholder_order_c(
+            r#"
+#, c-format
+msgid "%s has %d files"
+msgstr "%s a %d fichiers"
+
+#, c-format
+msgid "%s has %d files"
+msgstr "%2$d fichiers dans %1$s"
+
+#, c-format
+msgid "%s has %d files"
+msgstr "%d fichiers dans %f"
+
+#, python-format
+msgid "%s has %d files"
+msgstr "%d fichiers dans %s"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_placeholder_order_c_noqa() {
+        let diags = check_placeholder_order_c(
+            r#"
+#, c-format, noqa:placeholder-order-c
+msgid "%s has %d files"
+msgstr "%d fichiers dans %s"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_placeholder_order_c_error() {
+        let diags = check_placeholder_order_c(
+            r#"
+#, c-format
+msgid "%s has %d files"
+msgstr "%d fichiers dans %s"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Warning);
+        assert_eq!(diag.message, "format strings reordered without positions");
+        assert_eq!(diag.lines[0].highlights, vec![(0, 2), (7, 9)]);
+        assert_eq!(diag.lines[2].highlights, vec![(0, 2), (17, 19)]);
+    }
+}
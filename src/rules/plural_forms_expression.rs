@@ -0,0 +1,156 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `plural-forms-expression` rule: check the plural expression in header.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::po::plural::PluralExpr;
+use crate::rules::rule::RuleChecker;
+
+/// Maximum value of `n` used to evaluate the plural expression.
+const MAX_N: u64 = 200;
+
+pub struct PluralFormsExpressionRule;
+
+impl RuleChecker for PluralFormsExpressionRule {
+    fn name(&self) -> &'static str {
+        "plural-forms-expression"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check that the plural expression in header is valid and consistent with nplurals."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Check that the plural expression in header (`plural=` in `Plural-Forms`)
+    /// can be parsed, and that it returns an index lower than `nplurals` for
+    /// any `n` from 0 to 200.
+    ///
+    /// Headers without plural expression or without `nplurals` are ignored (they
+    /// are reported by the rule `header`).
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong header:
+    /// ```text
+    /// msgid ""
+    /// msgstr ""
+    /// "Plural-Forms: nplurals=2; plural=n == 1 ? 0 : n == 2 ? 1 : 2;\n"
+    /// ```
+    ///
+    /// Correct header:
+    /// ```text
+    /// msgid ""
+    /// msgstr ""
+    /// "Plural-Forms: nplurals=3; plural=n == 1 ? 0 : n == 2 ? 1 : 2;\n"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`warning`](Severity::Warning): `invalid plural expression: …`
+    /// - [`warning`](Severity::Warning): `plural expression divides by zero (n = …)`
+    /// - [`warning`](Severity::Warning): `plural expression returns … for n = … (nplurals = …)`
+    fn check_header(&self, checker: &Checker, _entry: &Entry, msgstr: &Message) -> Vec<Diagnostic> {
+        let nplurals = checker.nplurals();
+        let expr = checker.plural_expr();
+        if nplurals == 0 || expr.is_empty() {
+            return vec![];
+        }
+        let message = match PluralExpr::parse(expr) {
+            Ok(plural) => (0..=MAX_N).find_map(|n| match plural.eval(n) {
+                None => Some(format!("plural expression divides by zero (n = {n})")),
+                Some(index) if index >= u64::from(nplurals) => Some(format!(
+                    "plural expression returns {index} for n = {n} (nplurals = {nplurals})"
+                )),
+                Some(_) => None,
+            }),
+            Err(err) => Some(format!("invalid plural expression: {err}")),
+        };
+        message
+            .and_then(|message| self.new_diag(checker, self.severity(), message))
+            .map(|d| d.with_msg(msgstr))
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_plural_forms_expression(plural_forms: &str) -> Vec<Diagnostic> {
+        let content = format!(
+            "msgid \"\"\nmsgstr \"\"\n\"Language: ru\\n\"\n\"Plural-Forms: {plural_forms}\\n\"\n"
+        );
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(PluralFormsExpressionRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_plural_forms_expression_ok() {
+        assert!(check_plural_forms_expression("nplurals=1; plural=0;").is_empty());
+        assert!(check_plural_forms_expression("nplurals=2; plural=(n != 1);").is_empty());
+        assert!(
+            check_plural_forms_expression(
+                "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : \
+                 n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);"
+            )
+            .is_empty()
+        );
+        // Missing expression or nplurals: ignored.
+        assert!(check_plural_forms_expression("nplurals=2;").is_empty());
+        assert!(check_plural_forms_expression("plural=(n > 1);").is_empty());
+    }
+
+    #[test]
+    fn test_plural_forms_expression_invalid() {
+        let diags = check_plural_forms_expression("nplurals=2; plural=(n > 1;");
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Warning);
+        assert_eq!(
+            diag.message,
+            "invalid plural expression: unexpected end of expression"
+        );
+    }
+
+    #[test]
+    fn test_plural_forms_expression_out_of_range() {
+        let diags = check_plural_forms_expression(
+            "nplurals=2; plural=(n%10==1 && n%100!=11 ? 0 : \
+             n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);",
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "plural expression returns 2 for n = 0 (nplurals = 2)"
+        );
+    }
+
+    #[test]
+    fn test_plural_forms_expression_division_by_zero() {
+        let diags = check_plural_forms_expression("nplurals=2; plural=(1 / n);");
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "plural expression divides by zero (n = 0)"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `plural-incomplete` rule: check partially translated plural forms.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::rules::rule::RuleChecker;
+
+pub struct PluralIncompleteRule;
+
+impl RuleChecker for PluralIncompleteRule {
+    fn name(&self) -> &'static str {
+        "plural-incomplete"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for partially translated plural forms."
+    }
+
+    fn is_default(&self) -> bool {
+        true
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check for partially translated plural forms: some plural forms are
+    /// translated and some others are empty.
+    ///
+    /// Entries where all plural forms are empty are not reported (see rule `untranslated`).
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "%d file"
+    /// msgid_plural "%d files"
+    /// msgstr[0] "%d fichier"
+    /// msgstr[1] ""
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "%d file"
+    /// msgid_plural "%d files"
+    /// msgstr[0] "%d fichier"
+    /// msgstr[1] "%d fichiers"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `untranslated plural forms: #, #`
+    fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
+        if !entry.has_plural_form() || !entry.is_translated() {
+            return vec![];
+        }
+        let empty: Vec<String> = entry
+            .iter_strs()
+            .filter(|(_, msg)| msg.value.is_empty())
+            .map(|(index, _)| index.to_string())
+            .collect();
+        if empty.is_empty() {
+            return vec![];
+        }
+        self.new_diag(
+            checker,
+            self.severity(),
+            format!("untranslated plural forms: {}", empty.join(", ")),
+        )
+        .map(|d| d.with_entry(entry))
+        .into_iter()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_plural_incomplete(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(PluralIncompleteRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_plural_incomplete_ok() {
+        let diags = check_plural_incomplete(
+            r#"
+msgid "tested"
+msgstr ""
+
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[1] "%d fichiers"
+
+msgid "%d dir"
+msgid_plural "%d dirs"
+msgstr[0] ""
+msgstr[1] ""
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_plural_incomplete_noqa() {
+        let diags = check_plural_incomplete(
+            r#"
+#, noqa:plural-incomplete
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[1] ""
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_plural_incomplete_error() {
+        let diags = check_plural_incomplete(
+            r#"
+msgid ""
+msgstr "Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d файл"
+msgstr[1] ""
+msgstr[2] ""
+
+msgid "%d dir"
+msgid_plural "%d dirs"
+msgstr[0] ""
+msgstr[1] "%d каталога"
+msgstr[2] "%d каталогов"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "untranslated plural forms: 1, 2");
+        assert_eq!(diags[1].message, "untranslated plural forms: 0");
+    }
+}
@@ -0,0 +1,211 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `plural-index-gaps` rule: check non-contiguous plural indices.
+
+use std::fmt::Write as _;
+use std::ops::RangeInclusive;
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::rules::rule::RuleChecker;
+
+/// Maximum number of ranges of missing indices displayed in the diagnostic.
+const MAX_GAPS_DISPLAYED: usize = 10;
+
+pub struct PluralIndexGapsRule;
+
+impl RuleChecker for PluralIndexGapsRule {
+    fn name(&self) -> &'static str {
+        "plural-index-gaps"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for missing indices in translated plural forms."
+    }
+
+    fn is_default(&self) -> bool {
+        true
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Check for missing indices in translated plural forms: the indices of
+    /// `msgstr[n]` must be contiguous, starting at 0.
+    ///
+    /// One diagnostic is reported per entry, with the missing indices or ranges of
+    /// indices (at most 10 ranges are displayed).
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "%d file"
+    /// msgid_plural "%d files"
+    /// msgstr[0] "%d fichier"
+    /// msgstr[2] "%d fichiers"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "%d file"
+    /// msgid_plural "%d files"
+    /// msgstr[0] "%d fichier"
+    /// msgstr[1] "%d fichiers"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`error`](Severity::Error): `missing plural index #`
+    /// - [`error`](Severity::Error): `missing plural indices #, #-#, …`
+    fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
+        let gaps = missing_indices(entry.msgstr.keys().copied());
+        let msg = match gaps.as_slice() {
+            [] => return vec![],
+            [gap] if gap.start() == gap.end() => format!("missing plural index {}", gap.start()),
+            _ => format!("missing plural indices {}", format_gaps(&gaps)),
+        };
+        self.new_diag(checker, self.severity(), msg)
+            .map(|d| d.with_entry(entry))
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Return the ranges of missing indices before and between the sorted `indices`.
+///
+/// Only the indices present are iterated, so a huge index is not a problem.
+fn missing_indices(indices: impl Iterator<Item = u32>) -> Vec<RangeInclusive<u32>> {
+    let mut gaps = vec![];
+    let mut next = 0;
+    for index in indices {
+        if index > next {
+            gaps.push(next..=index - 1);
+        }
+        next = index.saturating_add(1);
+    }
+    gaps
+}
+
+/// Format the ranges of missing indices (e.g. `0, 2-5`), with at most
+/// [`MAX_GAPS_DISPLAYED`] ranges.
+fn format_gaps(gaps: &[RangeInclusive<u32>]) -> String {
+    let mut out = String::new();
+    for (idx, gap) in gaps.iter().take(MAX_GAPS_DISPLAYED).enumerate() {
+        if idx > 0 {
+            out.push_str(", ");
+        }
+        if gap.start() == gap.end() {
+            let _ = write!(out, "{}", gap.start());
+        } else {
+            let _ = write!(out, "{}-{}", gap.start(), gap.end());
+        }
+    }
+    if gaps.len() > MAX_GAPS_DISPLAYED {
+        out.push_str(", …");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_plural_index_gaps(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(PluralIndexGapsRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_plural_index_gaps_ok() {
+        let diags = check_plural_index_gaps(
+            r#"
+msgid "tested"
+msgstr "testé"
+
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[1] "%d fichiers"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_plural_index_gaps_noqa() {
+        let diags = check_plural_index_gaps(
+            r#"
+#, noqa:plural-index-gaps
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[2] "%d fichiers"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_plural_index_gaps_error() {
+        let diags = check_plural_index_gaps(
+            r#"
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[1] "%d fichier"
+msgstr[3] "%d fichiers"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(diag.message, "missing plural indices 0, 2");
+        // All the translations are displayed, including the ones after the gap.
+        assert_eq!(diag.lines.len(), 4);
+        assert_eq!(diag.lines[3].message, "msgstr[3] \"%d fichiers\"");
+
+        let diags = check_plural_index_gaps(
+            r#"
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[2] "%d fichiers"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "missing plural index 1");
+    }
+
+    #[test]
+    fn test_plural_index_gaps_huge_index() {
+        let diags = check_plural_index_gaps(
+            r#"
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[3000000] "%d fichiers"
+msgstr[4294967295] "%d fichiers"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "missing plural indices 1-2999999, 3000001-4294967294"
+        );
+    }
+
+    #[test]
+    fn test_format_gaps() {
+        let gaps = missing_indices((0..30).map(|i| i * 2 + 1));
+        assert_eq!(gaps.len(), 30);
+        assert_eq!(format_gaps(&gaps), "0, 2, 4, 6, 8, 10, 12, 14, 16, 18, …");
+    }
+}
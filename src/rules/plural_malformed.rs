@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `plural-malformed` rule: check malformed plural indices.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::rules::rule::RuleChecker;
+
+pub struct PluralMalformedRule;
+
+impl RuleChecker for PluralMalformedRule {
+    fn name(&self) -> &'static str {
+        "plural-malformed"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for malformed indices in translated plural forms."
+    }
+
+    fn is_default(&self) -> bool {
+        true
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Check for malformed indices in translated plural forms: the index of
+    /// `msgstr[n]` must be a number. Such lines are ignored by the parser, so the
+    /// translation they contain is lost.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "%d file"
+    /// msgid_plural "%d files"
+    /// msgstr[0] "%d fichier"
+    /// msgstr[x] "%d fichiers"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "%d file"
+    /// msgid_plural "%d files"
+    /// msgstr[0] "%d fichier"
+    /// msgstr[1] "%d fichiers"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`error`](Severity::Error): `malformed plural index`
+    fn check_entry(&self, checker: &Checker, entry: &Entry) -> Vec<Diagnostic> {
+        if entry.malformed_plural_index {
+            self.new_diag(checker, self.severity(), "malformed plural index")
+                .map(|d| d.with_entry(entry))
+                .into_iter()
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_plural_malformed(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(PluralMalformedRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_plural_malformed_ok() {
+        let diags = check_plural_malformed(
+            r#"
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[1] "%d fichiers"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_plural_malformed_noqa() {
+        let diags = check_plural_malformed(
+            r#"
+#, noqa:plural-malformed
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[x] "%d fichiers"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_plural_malformed_error() {
+        let diags = check_plural_malformed(
+            r#"
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[x] "%d fichiers"
+
+msgid "%d dir"
+msgid_plural "%d dirs"
+msgstr[0] "%d dossier"
+msgstr[] "%d dossiers"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        for (diag, line) in diags.iter().zip([2, 7]) {
+            assert_eq!(diag.severity, Severity::Error);
+            assert_eq!(diag.message, "malformed plural index");
+            assert_eq!(diag.lines[0].line_number, line);
+        }
+    }
+}
@@ -28,6 +28,10 @@ impl RuleChecker for PluralsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
     /// Check for incorrect number of plurals in translation.
     ///
     /// The number of plurals is defined in the PO header like this:
@@ -66,7 +70,7 @@ impl RuleChecker for PluralsRule {
             std::cmp::Ordering::Less => self
                 .new_diag(
                     checker,
-                    Severity::Error,
+                    self.severity(),
                     format!(
                         "missing translated plural form (found: {found}, expected: {expected})",
                     ),
@@ -77,7 +81,7 @@ impl RuleChecker for PluralsRule {
             std::cmp::Ordering::Greater => self
                 .new_diag(
                     checker,
-                    Severity::Error,
+                    self.severity(),
                     format!("extra translated plural form (found: {found}, expected: {expected})"),
                 )
                 .map(|d| d.with_entry(entry))
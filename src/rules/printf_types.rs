@@ -0,0 +1,194 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `printf-types` rule: check types of C format strings.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::format::language::Language;
+use crate::po::format::{
+    iter::FormatPos,
+    lang_c::{fmt_sort_index, fmt_type},
+};
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+pub struct PrintfTypesRule;
+
+impl RuleChecker for PrintfTypesRule {
+    fn name(&self) -> &'static str {
+        "printf-types"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for different argument types in C format strings between source and translation."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Check for different argument types in C format strings (`c-format`).
+    ///
+    /// Each conversion specifier is mapped to the type of its argument: int
+    /// (`%d`, `%i`), uint (`%u`, `%o`, `%x`, `%X`), float (`%f`, `%e`, `%g`, `%a`, …),
+    /// string (`%s`), pointer (`%p`, `%n`) or char (`%c`). The length modifiers are
+    /// ignored, so `%d` and `%i` are equivalent, as well as `%ld` and `%d`.
+    ///
+    /// Format strings are compared in order of their reordering position (like the
+    /// rule `formats`), and only if the source and translation have the same number
+    /// of format strings.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// #, c-format
+    /// msgid "name: %s, age: %d"
+    /// msgstr "nom : %d, âge : %s"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// #, c-format
+    /// msgid "name: %s, age: %d"
+    /// msgstr "nom : %s, âge : %i"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`error`](Severity::Error): `format type mismatch: … (…) vs … (…)`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        if entry.format_language != Language::C {
+            return vec![];
+        }
+        let mut id_fmt: Vec<_> = FormatPos::new(&msgid.value, entry.format_language).collect();
+        let mut str_fmt: Vec<_> = FormatPos::new(&msgstr.value, entry.format_language).collect();
+        if id_fmt.len() != str_fmt.len() {
+            return vec![];
+        }
+        id_fmt.sort_by_key(|m| (fmt_sort_index(m.s), m.start, m.end));
+        str_fmt.sort_by_key(|m| (fmt_sort_index(m.s), m.start, m.end));
+        id_fmt
+            .iter()
+            .zip(str_fmt.iter())
+            .filter_map(|(id, str)| {
+                let (id_type, str_type) = (fmt_type(id.s)?, fmt_type(str.s)?);
+                if id_type == str_type {
+                    return None;
+                }
+                self.new_diag(
+                    checker,
+                    self.severity(),
+                    format!(
+                        "format type mismatch: {} ({id_type}) vs {} ({str_type})",
+                        id.s, str.s
+                    ),
+                )
+                .map(|d| {
+                    d.with_msgs_hl(msgid, [(id.start, id.end)], msgstr, [(str.start, str.end)])
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_printf_types(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(PrintfTypesRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_printf_types_ok() {
+        let diags = check_printf_types(
+            r#"
+#, c-format
+msgid "name: %s, age: %d, size: %lu"
+msgstr "nom : %s, âge : %i, taille : %zx"
+
+#, c-format
+msgid "%d test (%s)"
+msgstr "%2$s test (%1$i)"
+
+#, python-format
+msgid "name: %s, age: %d"
+msgstr "nom : %d, âge : %s"
+
+#, c-format
+msgid "name: %s, age: %d"
+msgstr "nom : %d"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_printf_types_noqa() {
+        let diags = check_printf_types(
+            r#"
+#, c-format, noqa:printf-types
+msgid "age: %d"
+msgstr "âge : %s"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_printf_types_error() {
+        let diags = check_printf_types(
+            r#"
+#, c-format
+msgid "name: %s, age: %d, size: %.1f"
+msgstr "nom : %d, âge : %s, taille : %.1f"
+
+#, c-format
+msgid "%d test (%s)"
+msgstr "%2$d test (%1$s)"
+"#,
+        );
+        assert_eq!(diags.len(), 4);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(
+            diag.message,
+            "format type mismatch: %s (string) vs %d (int)"
+        );
+        assert_eq!(diag.lines[0].highlights, vec![(6, 8)]);
+        assert_eq!(diag.lines[2].highlights, vec![(6, 8)]);
+        assert_eq!(
+            diags[1].message,
+            "format type mismatch: %d (int) vs %s (string)"
+        );
+        assert_eq!(
+            diags[2].message,
+            "format type mismatch: %d (int) vs %1$s (string)"
+        );
+        assert_eq!(
+            diags[3].message,
+            "format type mismatch: %s (string) vs %2$d (int)"
+        );
+    }
+}
@@ -34,6 +34,10 @@ impl RuleChecker for PuncStartRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for inconsistent leading punctuation between source and translation.
     ///
     /// The following characters are considered as punctuation for this check
@@ -102,7 +106,7 @@ impl RuleChecker for PuncStartRule {
             };
             self.new_diag(
                 checker,
-                Severity::Info,
+                self.severity(),
                 format!("inconsistent leading punctuation ('{id_punc2}' / '{str_punc2}')"),
             )
             .map(|d| {
@@ -134,6 +138,10 @@ impl RuleChecker for PuncEndRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for inconsistent trailing punctuation between source and translation.
     ///
     /// The following characters are considered as punctuation for this check
@@ -193,7 +201,7 @@ impl RuleChecker for PuncEndRule {
             };
             self.new_diag(
                 checker,
-                Severity::Info,
+                self.severity(),
                 format!("inconsistent trailing punctuation ('{id_punc2}' / '{str_punc2}')"),
             )
             .map(|d| {
@@ -216,7 +224,7 @@ impl RuleChecker for PuncEndRule {
 /// Covers Latin (ASCII and full-width), CJK ideographic, Arabic, and several
 /// other scripts whose punctuation regularly appears at sentence boundaries
 /// in PO translations.
-const fn is_punc(c: char) -> bool {
+pub(crate) const fn is_punc(c: char) -> bool {
     c == ':'
         || c == '：'
         || c == ';'
@@ -249,6 +257,24 @@ const fn is_punc(c: char) -> bool {
         || c == '\u{061F}'
 }
 
+/// Check if a character ends a sentence (full stop, ellipsis, exclamation or question
+/// mark, including full-width and other scripts).
+pub(crate) const fn is_sentence_end(c: char) -> bool {
+    c == '.'
+        || c == '。'
+        || c == '…'
+        || c == '\u{0964}'
+        || c == '\u{0965}'
+        || c == '\u{17D4}'
+        || c == '\u{104B}'
+        || c == '։'
+        || c == '!'
+        || c == '！'
+        || c == '?'
+        || c == '？'
+        || c == '\u{061F}'
+}
+
 /// Get the leading punctuation of a string. The returned slice includes any
 /// whitespace surrounding the punctuation run on both sides (any whitespace
 /// except `\n`), stopping at the first non-punctuation non-whitespace
@@ -279,7 +305,7 @@ fn get_punc_start(s: &str) -> &str {
 ///
 /// Returns an empty slice if no punctuation character is present in the
 /// trailing region.
-fn get_punc_end(s: &str) -> &str {
+pub(crate) fn get_punc_end(s: &str) -> &str {
     let mut saw_punc = false;
     let mut pos = 0;
     for c in s.chars().rev() {
@@ -304,7 +330,11 @@ fn get_punc_end(s: &str) -> &str {
 /// Returns `Cow::Borrowed` when the input is already normalized and contains
 /// no whitespace (the common case for ASCII English-style punctuation),
 /// avoiding an allocation.
-fn punc_normalize<'a>(s: &'a str, language: &str, ignore_ellipsis: bool) -> Cow<'a, str> {
+pub(crate) fn punc_normalize<'a>(
+    s: &'a str,
+    language: &str,
+    ignore_ellipsis: bool,
+) -> Cow<'a, str> {
     let needs_substitution = s.chars().any(|c| {
         matches!(
             c,
@@ -641,4 +671,49 @@ msgstr "testé"
         assert_eq!(fix.edits[0].range, 6..6);
         assert_eq!(fix.edits[0].replacement, ".");
     }
+
+    #[test]
+    fn test_punc_end_full_width() {
+        // Full-width punctuation is normalized for the comparison only.
+        let content = r#"
+msgid "Loading..."
+msgstr "読み込み中。。。"
+
+msgid "Loading…"
+msgstr "読み込み中。。。"
+
+msgid "Loading."
+msgstr "読み込み中。。。"
+"#;
+        let diags = check_punc_end(content);
+        assert_eq!(diags.len(), 2);
+        assert_eq!(
+            diags[0].message,
+            "inconsistent trailing punctuation ('…' / '...')"
+        );
+        assert_eq!(
+            diags[1].message,
+            "inconsistent trailing punctuation ('.' / '...')"
+        );
+        // Highlights and fix are computed on the raw strings: "読み込み中" is
+        // 15 bytes and "。。。" is 9 bytes.
+        let diag = &diags[1];
+        assert_eq!(diag.lines[0].highlights, vec![(7, 8)]);
+        assert_eq!(diag.lines[2].highlights, vec![(15, 24)]);
+        let msgstr = "読み込み中。。。";
+        for (start, end) in &diag.lines[2].highlights {
+            assert!(msgstr.is_char_boundary(*start) && msgstr.is_char_boundary(*end));
+        }
+        let fix = diag.fix.as_ref().expect("fix attached");
+        assert_eq!(fix.edits[0].range, 15..24);
+        assert_eq!(fix.edits[0].replacement, ".");
+        // With ellipsis ignored, "。。。" is the same as "…".
+        let diags = check_punc_end_ignore_ellipsis(content);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "inconsistent trailing punctuation ('.' / '…')"
+        );
+        assert_eq!(diags[0].lines[2].highlights, vec![(15, 24)]);
+    }
 }
@@ -0,0 +1,251 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `punc-repeat` rule: check inconsistent repeated punctuation.
+
+use std::ops::Range;
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::punc::is_punc;
+use crate::rules::rule::RuleChecker;
+
+pub struct PuncRepeatRule;
+
+impl RuleChecker for PuncRepeatRule {
+    fn name(&self) -> &'static str {
+        "punc-repeat"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for inconsistent repeated punctuation in translation."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check for inconsistent repeated punctuation between source and translation.
+    ///
+    /// A repeated punctuation is a run of two or more identical punctuation
+    /// characters (e.g. `!!` or `,,`), anywhere in the string. The runs of the source
+    /// and the translation are compared regardless of their order; the ellipsis forms
+    /// are ignored: three periods (`...`), three ideographic full stops (`。。。`) and
+    /// any run of ellipsis characters (`……`, used in Chinese and Japanese).
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "Warning! Disk is full."
+    /// msgstr "Attention !!! Le disque est plein."
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "Warning! Disk is full."
+    /// msgstr "Attention ! Le disque est plein."
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `inconsistent repeated punctuation ('…' / '…')`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        _entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let id_runs = punc_repeat_runs(&msgid.value);
+        let str_runs = punc_repeat_runs(&msgstr.value);
+        let id_diff = runs_not_in(&msgid.value, &id_runs, &msgstr.value, &str_runs);
+        let str_diff = runs_not_in(&msgstr.value, &str_runs, &msgid.value, &id_runs);
+        if id_diff.is_empty() && str_diff.is_empty() {
+            return vec![];
+        }
+        self.new_diag(
+            checker,
+            self.severity(),
+            format!(
+                "inconsistent repeated punctuation ('{}' / '{}')",
+                join_runs(&msgid.value, &id_diff),
+                join_runs(&msgstr.value, &str_diff),
+            ),
+        )
+        .map(|d| {
+            d.with_msgs_hl(
+                msgid,
+                id_diff.into_iter().map(|r| (r.start, r.end)),
+                msgstr,
+                str_diff.into_iter().map(|r| (r.start, r.end)),
+            )
+        })
+        .into_iter()
+        .collect()
+    }
+}
+
+/// Return `true` if a run of `count` characters `c` is an ellipsis: `...`, `。。。`
+/// or any run of `…`.
+const fn is_ellipsis(c: char, count: usize) -> bool {
+    matches!((c, count), ('.' | '。', 3) | ('…', _))
+}
+
+/// Find the runs of two or more identical punctuation characters in `value`,
+/// except the ellipsis (see [`is_ellipsis`]). Each run is returned as a byte range.
+fn punc_repeat_runs(value: &str) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut chars = value.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if !is_punc(c) {
+            continue;
+        }
+        let mut count = 1;
+        let mut end = start + c.len_utf8();
+        while let Some((idx, _)) = chars.next_if(|(_, c2)| *c2 == c) {
+            count += 1;
+            end = idx + c.len_utf8();
+        }
+        if count >= 2 && !is_ellipsis(c, count) {
+            runs.push(start..end);
+        }
+    }
+    runs
+}
+
+/// Return the runs of `value` that have no identical run in `other` (each run of
+/// `other` can match only one run of `value`).
+fn runs_not_in(
+    value: &str,
+    runs: &[Range<usize>],
+    other: &str,
+    other_runs: &[Range<usize>],
+) -> Vec<Range<usize>> {
+    let mut other_runs: Vec<&str> = other_runs.iter().map(|r| &other[r.clone()]).collect();
+    runs.iter()
+        .filter(|r| {
+            if let Some(pos) = other_runs.iter().position(|s| *s == &value[(*r).clone()]) {
+                other_runs.swap_remove(pos);
+                false
+            } else {
+                true
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+/// Join the runs with a comma, for display in the diagnostic message.
+fn join_runs(value: &str, runs: &[Range<usize>]) -> String {
+    runs.iter()
+        .map(|r| &value[r.clone()])
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_punc_repeat(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(PuncRepeatRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_punc_repeat_runs() {
+        assert!(punc_repeat_runs("").is_empty());
+        assert!(punc_repeat_runs("Hello, world!").is_empty());
+        assert!(punc_repeat_runs("Wait... ok?!").is_empty());
+        assert_eq!(punc_repeat_runs("Wow!! Really??"), vec![3..5, 12..14]);
+        assert_eq!(punc_repeat_runs("a,, b.... c"), vec![1..3, 5..9]);
+        assert_eq!(punc_repeat_runs("本当！！"), vec![6..12]);
+        assert!(punc_repeat_runs("等待……好。。。").is_empty());
+        assert_eq!(punc_repeat_runs("好。。"), vec![3..9]);
+    }
+
+    #[test]
+    fn test_punc_repeat_ok() {
+        let diags = check_punc_repeat(
+            r#"
+msgid "Warning! Disk is full."
+msgstr "Attention ! Le disque est plein."
+
+msgid "What?? Again!!"
+msgstr "Encore !! Quoi ??"
+
+msgid "Loading..."
+msgstr "Chargement…"
+
+msgid "Loading..."
+msgstr "加载中……"
+
+msgid "Wait..."
+msgstr "待って。。。"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_punc_repeat_error_noqa() {
+        let diags = check_punc_repeat(
+            r#"
+#, noqa:punc-repeat
+msgid "Warning! Disk is full."
+msgstr "Attention !! Le disque est plein."
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_punc_repeat_error() {
+        let diags = check_punc_repeat(
+            r#"
+msgid "Warning! Disk is full."
+msgstr "Attention !! Le disque est plein."
+
+msgid "Really?? Yes!"
+msgstr "Vraiment ? Oui !!!"
+
+msgid "one, two"
+msgstr "un,, deux"
+"#,
+        );
+        assert_eq!(diags.len(), 3);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(
+            diag.message,
+            "inconsistent repeated punctuation ('' / '!!')"
+        );
+        assert!(diag.lines[0].highlights.is_empty());
+        assert_eq!(diag.lines[2].highlights, vec![(10, 12)]);
+        let diag = &diags[1];
+        assert_eq!(
+            diag.message,
+            "inconsistent repeated punctuation ('??' / '!!!')"
+        );
+        assert_eq!(diag.lines[0].highlights, vec![(6, 8)]);
+        assert_eq!(diag.lines[2].highlights, vec![(15, 18)]);
+        assert_eq!(
+            diags[2].message,
+            "inconsistent repeated punctuation ('' / ',,')"
+        );
+    }
+}
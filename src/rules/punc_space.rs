@@ -56,6 +56,10 @@ impl RuleChecker for PuncSpaceIdRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for spaces around punctuation in the source string (English).
     ///
     /// In English there must be no space before punctuation.
@@ -100,7 +104,7 @@ impl RuleChecker for PuncSpaceIdRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Info,
+                        self.severity(),
                         format!("extra space before '{next_c}' in source"),
                     )
                     .map(|d| {
@@ -132,6 +136,10 @@ impl RuleChecker for PuncSpaceStrRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for spaces around punctuation in the translated string.
     ///
     /// Only French and Finnish are supported.
@@ -194,7 +202,7 @@ impl RuleChecker for PuncSpaceStrRule {
                     diags.extend(
                         self.new_diag(
                             checker,
-                            Severity::Info,
+                            self.severity(),
                             format!(
                                 "space must be a non-breaking space after '{c}' in translation"
                             ),
@@ -215,7 +223,7 @@ impl RuleChecker for PuncSpaceStrRule {
                     diags.extend(
                         self.new_diag(
                             checker,
-                            Severity::Info,
+                            self.severity(),
                             format!("missing non-breaking space after '{c}' in translation"),
                         )
                         .map(|d| {
@@ -236,7 +244,7 @@ impl RuleChecker for PuncSpaceStrRule {
                     diags.extend(
                         self.new_diag(
                             checker,
-                            Severity::Info,
+                            self.severity(),
                             format!(
                                 "space must be a non-breaking space before '{next_c}' in translation"
                             ),
@@ -257,7 +265,7 @@ impl RuleChecker for PuncSpaceStrRule {
                     diags.extend(
                         self.new_diag(
                             checker,
-                            Severity::Info,
+                            self.severity(),
                             format!("missing non-breaking space before '{next_c}' in translation"),
                         )
                         .map(|d| {
@@ -278,7 +286,7 @@ impl RuleChecker for PuncSpaceStrRule {
                     diags.extend(
                         self.new_diag(
                             checker,
-                            Severity::Info,
+                            self.severity(),
                             format!("missing non-breaking space before '{next_c}' in translation"),
                         )
                         .map(|d| {
@@ -297,7 +305,7 @@ impl RuleChecker for PuncSpaceStrRule {
                     diags.extend(
                         self.new_diag(
                             checker,
-                            Severity::Info,
+                            self.severity(),
                             format!("missing space before '{next_c}' in translation"),
                         )
                         .map(|d| {
@@ -321,7 +329,7 @@ impl RuleChecker for PuncSpaceStrRule {
                 diags.extend(
                     self.new_diag(
                         checker,
-                        Severity::Info,
+                        self.severity(),
                         format!(
                             "space must be a non-breaking space before '{next_c}' in translation"
                         ),
@@ -4,33 +4,82 @@
 
 //! Load rules and execute the `rules` command.
 
-use std::collections::HashSet;
+use std::{collections::HashSet, path::Path};
+
+use clap::ValueEnum;
+use colored::Colorize;
+use serde::Serialize;
 
 use crate::{
     args,
     checker::Checker,
-    config::Config,
+    config::{Config, find_config_path},
     diagnostic::{Diagnostic, Severity},
     po::{entry::Entry, message::Message},
     rules::{
-        accelerators, acronyms, blank, brackets, changed, compilation, double_quotes,
-        double_spaces, double_words, emails, encoding, escapes, force_trans, formats, functions,
-        fuzzy, header, html_tags, long, newlines, no_trans, noqa, obsolete, paths, pipes, plurals,
-        punc, punc_space, short, spelling, tabs, unchanged, unicode_ctrl, untranslated, urls,
-        whitespace,
+        accelerators, acronyms, ascii_id, blank, blank_lines, bom, brackets, capitalization,
+        changed, charset, compilation, components, context_missing, double_quotes, double_spaces,
+        double_words, duplicate, ellipsis, emails, empty_msgid, encoding, escapes, force_trans,
+        formats, functions, fuzzy, fuzzy_stale, glossary, header, header_fields, html_tags,
+        length_ratio, long, markdown_links, msgid_whitespace, nbsp, newlines, no_trans, noqa,
+        number_locale, obsolete, obsolete_count, paths, pipes, placeholder_order_c,
+        plural_forms_expression, plural_incomplete, plural_index_gaps, plural_malformed, plurals,
+        printf_types, punc, punc_repeat, punc_space, short, smart_quotes, spelling, tab_position,
+        tabs, unchanged, unicode_ctrl, untranslated, urls, whitespace,
     },
     table::render_table,
 };
 
 pub type Rule = Box<dyn RuleChecker + Send + Sync>;
 
-const SPECIAL_RULES: [&str; 4] = ["all", "checks", "default", "spelling"];
+const SPECIAL_RULES: [&str; 6] = ["all", "checks", "default", "info", "warning", "error"];
+
+// Explanations of rules (`RULE_EXPLANATIONS`), extracted from the doc comments of
+// the check methods by the build script.
+include!(concat!(env!("OUT_DIR"), "/explanations.rs"));
+
+/// Groups of rules that can be selected at once by their name.
+const RULE_GROUPS: [(&str, &[&str]); 3] = [
+    (
+        "formatting",
+        &[
+            "brackets",
+            "double-quotes",
+            "double-spaces",
+            "punc-end",
+            "punc-space-id",
+            "punc-space-str",
+            "punc-start",
+            "whitespace-end",
+            "whitespace-line-end",
+            "whitespace-line-start",
+            "whitespace-start",
+        ],
+    ),
+    (
+        "integrity",
+        &[
+            "escapes",
+            "formats",
+            "newlines",
+            "plural-malformed",
+            "plurals",
+            "printf-types",
+        ],
+    ),
+    (
+        "spelling",
+        &["spelling-ctxt", "spelling-id", "spelling-str"],
+    ),
+];
 
 #[derive(Default)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Rules {
     pub enabled: Vec<Rule>,
+    pub duplicate_rule: bool,
     pub fuzzy_rule: bool,
+    pub fuzzy_stale_rule: bool,
     pub noqa_rule: bool,
     pub obsolete_rule: bool,
     pub untranslated_rule: bool,
@@ -39,6 +88,8 @@ pub struct Rules {
     pub spelling_str_rule: bool,
     pub force_trans_rule: bool,
     pub no_trans_rule: bool,
+    pub glossary_rule: bool,
+    pub punc_end_rule: bool,
 }
 
 impl std::fmt::Display for Rule {
@@ -49,7 +100,9 @@ impl std::fmt::Display for Rule {
 
 impl Rules {
     pub fn new(rules: Vec<Rule>) -> Self {
+        let duplicate_rule = rules.iter().any(|r| r.name() == "duplicate");
         let fuzzy_rule = rules.iter().any(|r| r.name() == "fuzzy");
+        let fuzzy_stale_rule = rules.iter().any(|r| r.name() == "fuzzy-stale");
         let noqa_rule = rules.iter().any(|r| r.name() == "noqa");
         let obsolete_rule = rules.iter().any(|r| r.name() == "obsolete");
         let untranslated_rule = rules.iter().any(|r| r.name() == "untranslated");
@@ -58,9 +111,13 @@ impl Rules {
         let spelling_str_rule = rules.iter().any(|r| r.name() == "spelling-str");
         let force_trans_rule = rules.iter().any(|r| r.name() == "force-trans");
         let no_trans_rule = rules.iter().any(|r| r.name() == "no-trans");
+        let glossary_rule = rules.iter().any(|r| r.name() == "glossary");
+        let punc_end_rule = rules.iter().any(|r| r.name() == "punc-end");
         Self {
             enabled: rules,
+            duplicate_rule,
             fuzzy_rule,
+            fuzzy_stale_rule,
             noqa_rule,
             obsolete_rule,
             untranslated_rule,
@@ -69,8 +126,21 @@ impl Rules {
             spelling_str_rule,
             force_trans_rule,
             no_trans_rule,
+            glossary_rule,
+            punc_end_rule,
         }
     }
+
+    /// Keep only the rules checking the source strings, used to check template
+    /// files (`*.pot`).
+    pub fn source_only(self) -> Self {
+        Self::new(
+            self.enabled
+                .into_iter()
+                .filter(|rule| rule.is_source_only())
+                .collect(),
+        )
+    }
 }
 
 /// Trait for rules to check PO files. Each rule can check files, entries, contexts and messages and report diagnostics.
@@ -89,11 +159,43 @@ pub trait RuleChecker {
     /// Whether the rule is a check (as opposed to a special rule like "fuzzy" or "noqa").
     fn is_check(&self) -> bool;
 
+    /// Get the severity of the diagnostics reported by the rule (the highest one if the
+    /// rule reports diagnostics with different severities), used to select rules by
+    /// severity with `--select`.
+    fn severity(&self) -> Severity;
+
+    /// Get the explanation of the rule, with examples of wrong and correct entries:
+    /// the doc comment of its check method, displayed by `poexam rules --explain`.
+    fn explanation(&self) -> &'static str {
+        RULE_EXPLANATIONS
+            .iter()
+            .find(|(name, _)| *name == self.name())
+            .map_or("", |(_, explanation)| explanation)
+    }
+
+    /// Whether the rule checks only the source strings and the header, so that it can
+    /// be used on template files (`*.pot`), which have no translations.
+    fn is_source_only(&self) -> bool {
+        false
+    }
+
+    /// Whether the rule checks the source strings of untranslated entries too (in a
+    /// PO file, untranslated entries are skipped by the other rules).
+    fn checks_untranslated(&self) -> bool {
+        false
+    }
+
     /// Check a file for diagnostics.
     fn check_file(&self, _checker: &Checker) -> Vec<Diagnostic> {
         vec![]
     }
 
+    /// Check a file for diagnostics, after all its entries have been checked (e.g. to
+    /// report counts of entries).
+    fn check_file_end(&self, _checker: &Checker) -> Vec<Diagnostic> {
+        vec![]
+    }
+
     /// Check the PO file header for diagnostics.
     fn check_header(
         &self,
@@ -127,6 +229,9 @@ pub trait RuleChecker {
 
     /// Create a diagnostic for the rule with the given severity.
     ///
+    /// The severity is replaced by the one configured for the rule in the `[severity]`
+    /// table of the configuration file, if any.
+    ///
     /// Returns `None` if the configured `severity` filter excludes this severity, so rules can
     /// emit diagnostics of varying severities and the filter is applied at construction time.
     fn new_diag(
@@ -138,6 +243,12 @@ pub trait RuleChecker {
     where
         Self: Sized,
     {
+        let severity = checker
+            .config
+            .severity
+            .get(self.name())
+            .copied()
+            .unwrap_or(severity);
         let allowed = &checker.config.check.severity;
         if !allowed.is_empty() && !allowed.contains(&severity) {
             return None;
@@ -156,38 +267,66 @@ fn get_all_rules() -> Vec<Rule> {
     vec![
         Box::new(accelerators::AcceleratorsRule {}),
         Box::new(acronyms::AcronymsRule {}),
+        Box::new(ascii_id::AsciiIdRule {}),
         Box::new(blank::BlankRule {}),
+        Box::new(blank_lines::BlankLinesRule {}),
+        Box::new(bom::BomRule {}),
         Box::new(brackets::BracketsRule {}),
+        Box::new(capitalization::CapitalizationRule {}),
         Box::new(changed::ChangedRule {}),
+        Box::new(charset::CharsetRule {}),
         Box::new(compilation::CompilationRule {}),
+        Box::new(components::ComponentPlaceholdersRule {}),
+        Box::new(context_missing::ContextMissingRule {}),
         Box::new(double_quotes::DoubleQuotesRule {}),
         Box::new(double_spaces::DoubleSpacesRule {}),
         Box::new(double_words::DoubleWordsRule {}),
+        Box::new(duplicate::DuplicateRule {}),
+        Box::new(ellipsis::EllipsisRule {}),
         Box::new(emails::EmailsRule {}),
+        Box::new(empty_msgid::EmptyMsgidRule {}),
         Box::new(encoding::EncodingRule {}),
         Box::new(escapes::EscapesRule {}),
         Box::new(force_trans::ForceTransRule {}),
         Box::new(formats::FormatsRule {}),
         Box::new(functions::FunctionsRule {}),
         Box::new(fuzzy::FuzzyRule {}),
+        Box::new(fuzzy_stale::FuzzyStaleRule {}),
+        Box::new(glossary::GlossaryRule {}),
         Box::new(header::HeaderRule {}),
+        Box::new(header_fields::HeaderFieldsRule {}),
         Box::new(html_tags::HtmlTagsRule {}),
+        Box::new(length_ratio::LengthRatioRule {}),
         Box::new(long::LongRule {}),
+        Box::new(markdown_links::MarkdownLinksRule {}),
+        Box::new(msgid_whitespace::MsgidWhitespaceRule {}),
+        Box::new(nbsp::NbspRule {}),
         Box::new(newlines::NewlinesRule {}),
         Box::new(no_trans::NoTransRule {}),
         Box::new(noqa::NoqaRule {}),
+        Box::new(number_locale::NumberLocaleRule {}),
         Box::new(obsolete::ObsoleteRule {}),
+        Box::new(obsolete_count::ObsoleteCountRule {}),
         Box::new(paths::PathsRule {}),
         Box::new(pipes::PipesRule {}),
+        Box::new(placeholder_order_c::PlaceholderOrderCRule {}),
+        Box::new(plural_forms_expression::PluralFormsExpressionRule {}),
+        Box::new(plural_incomplete::PluralIncompleteRule {}),
+        Box::new(plural_index_gaps::PluralIndexGapsRule {}),
+        Box::new(plural_malformed::PluralMalformedRule {}),
         Box::new(plurals::PluralsRule {}),
+        Box::new(printf_types::PrintfTypesRule {}),
         Box::new(punc::PuncStartRule {}),
         Box::new(punc::PuncEndRule {}),
+        Box::new(punc_repeat::PuncRepeatRule {}),
         Box::new(punc_space::PuncSpaceIdRule {}),
         Box::new(punc_space::PuncSpaceStrRule {}),
         Box::new(short::ShortRule {}),
+        Box::new(smart_quotes::SmartQuotesRule {}),
         Box::new(spelling::SpellingCtxtRule {}),
         Box::new(spelling::SpellingIdRule {}),
         Box::new(spelling::SpellingStrRule {}),
+        Box::new(tab_position::TabPositionRule {}),
         Box::new(tabs::TabsRule {}),
         Box::new(unchanged::UnchangedRule {}),
         Box::new(unicode_ctrl::UnicodeCtrlRule {}),
@@ -217,6 +356,9 @@ fn get_unknown_rules<'a>(
     for name in SPECIAL_RULES {
         unknown_rules_names.remove(name);
     }
+    for (name, _) in RULE_GROUPS {
+        unknown_rules_names.remove(name);
+    }
     if unknown_rules_names.is_empty() {
         return vec![];
     }
@@ -225,6 +367,16 @@ fn get_unknown_rules<'a>(
     unknown
 }
 
+/// Get the severity of a rule: the one configured in the `[severity]` table of the
+/// configuration file, if any, otherwise the default severity of the rule.
+fn rule_severity(rule: &Rule, config: &Config) -> Severity {
+    config
+        .severity
+        .get(rule.name())
+        .copied()
+        .unwrap_or_else(|| rule.severity())
+}
+
 /// Get the selected rules based on command line parameters `--select` and `--ignore`.
 ///
 /// If `--select` is provided, only the specified rules are included.
@@ -246,9 +398,12 @@ pub fn get_selected_rules(config: &Config) -> Result<Rules, Box<dyn std::error::
             selected_rules.extend(all_rules.extract_if(.., |rule| rule.is_check()));
         } else if name == "default" {
             selected_rules.extend(all_rules.extract_if(.., |rule| rule.is_default()));
-        } else if name == "spelling" {
-            selected_rules
-                .extend(all_rules.extract_if(.., |rule| rule.name().starts_with("spelling-")));
+        } else if let Some((_, group)) = RULE_GROUPS.iter().find(|(group, _)| group == name) {
+            selected_rules.extend(all_rules.extract_if(.., |rule| group.contains(&rule.name())));
+        } else if let Ok(severity) = Severity::from_str(name, true) {
+            selected_rules.extend(all_rules.extract_if(.., |rule| {
+                rule.is_check() && rule_severity(rule, config) == severity
+            }));
         } else {
             selected_rules.extend(all_rules.extract_if(.., |rule| rule.name() == name));
         }
@@ -265,6 +420,22 @@ pub fn get_selected_rules(config: &Config) -> Result<Rules, Box<dyn std::error::
     }
     selected_rules.retain(|rule| !config.check.ignore.iter().any(|r| r == rule.name()));
 
+    // Check rules with a severity override.
+    let mut unknown_rules_names: Vec<&str> = config
+        .severity
+        .keys()
+        .map(String::as_str)
+        .filter(|name| !all_rules_names.contains(name))
+        .collect();
+    if !unknown_rules_names.is_empty() {
+        unknown_rules_names.sort_unstable();
+        return Err(format!(
+            "unknown rules in severity overrides: {}",
+            unknown_rules_names.join(", ")
+        )
+        .into());
+    }
+
     // Sort rules by name.
     selected_rules.sort_by(|a, b| a.name().cmp(b.name()));
 
@@ -297,18 +468,23 @@ fn print_rules_table(all_rules: &[Rule]) {
     );
 }
 
+/// Count the rules that check and report diagnostics with the given severity (the
+/// severity overridden in the configuration, if any).
+fn count_rules_with_severity(all_rules: &[Rule], config: &Config, severity: Severity) -> usize {
+    all_rules
+        .iter()
+        .filter(|rule| rule.is_check() && rule_severity(rule, config) == severity)
+        .count()
+}
+
 /// Print all special rules as a table.
-fn print_special_rules_table(all_rules: &[Rule]) {
+fn print_special_rules_table(all_rules: &[Rule], config: &Config) {
     let mut non_check_rules: Vec<&'static str> = Vec::new();
-    let mut spelling_rules: Vec<&'static str> = Vec::new();
     let mut default_count = 0;
     for rule in all_rules {
         if !rule.is_check() {
             non_check_rules.push(rule.name());
         }
-        if rule.name().starts_with("spelling-") {
-            spelling_rules.push(rule.name());
-        }
         if rule.is_default() {
             default_count += 1;
         }
@@ -333,24 +509,103 @@ fn print_special_rules_table(all_rules: &[Rule]) {
             "Default rules (can be used to add extra rules, e.g. \"default,spelling,fuzzy\")."
                 .to_string(),
         ],
+    ]
+    .into_iter()
+    .chain(RULE_GROUPS.iter().map(|(name, rules)| {
         vec![
-            "spelling".to_string(),
-            spelling_rules.len().to_string(),
-            format!("All spelling rules: {}.", spelling_rules.join(", ")),
-        ],
-    ];
+            (*name).to_string(),
+            rules.len().to_string(),
+            format!("All {name} rules: {}.", rules.join(", ")),
+        ]
+    }))
+    .chain(Severity::value_variants().iter().filter_map(|severity| {
+        let name = severity.to_possible_value()?.get_name().to_string();
+        let count = count_rules_with_severity(all_rules, config, *severity);
+        Some(vec![
+            name.clone(),
+            count.to_string(),
+            format!(
+                "All rules that actually check, reporting diagnostics with severity \"{name}\"."
+            ),
+        ])
+    }))
+    .collect();
     println!(
         "Special rules to enable multiple rules at once:\n\n{}",
         render_table(&["Name", "Rules", "Description"], &rows),
     );
 }
 
+/// Information about a rule displayed in JSON output.
+#[derive(Serialize)]
+struct RuleInfo {
+    name: &'static str,
+    severity: Severity,
+    default: bool,
+    is_check: bool,
+    description: &'static str,
+}
+
+/// Build the JSON output of rules: an array of rules with their metadata.
+fn build_rules_json(all_rules: &[Rule]) -> String {
+    let rules: Vec<RuleInfo> = all_rules
+        .iter()
+        .map(|r| RuleInfo {
+            name: r.name(),
+            severity: r.severity(),
+            default: r.is_default(),
+            is_check: r.is_check(),
+            description: r.description(),
+        })
+        .collect();
+    serde_json::to_string(&rules).unwrap_or_default()
+}
+
+/// Build the explanation of a rule: name, status, severity and explanation with
+/// examples. Returns `None` if the rule is unknown.
+fn explain_rule(all_rules: &[Rule], name: &str) -> Option<String> {
+    let rule = all_rules.iter().find(|r| r.name() == name)?;
+    Some(format!(
+        "{}: {}\n\nDefault: {}\nCheck: {}\nSeverity: {}\n\n{}",
+        rule.name(),
+        rule.description(),
+        if rule.is_default() { "yes" } else { "no" },
+        if rule.is_check() { "yes" } else { "no" },
+        rule.severity(),
+        rule.explanation().trim_end(),
+    ))
+}
+
 /// Display rules used to check PO files.
-pub fn run_rules(_args: &args::RulesArgs) -> i32 {
+pub fn run_rules(args: &args::RulesArgs) -> i32 {
     let rules = get_all_rules();
-    print_rules_table(&rules);
-    println!();
-    print_special_rules_table(&rules);
+    if let Some(name) = &args.explain {
+        let Some(explanation) = explain_rule(&rules, name) else {
+            eprintln!("{}: unknown rule: {name}", "Error".red());
+            return 1;
+        };
+        println!("{explanation}");
+        return 0;
+    }
+    match args.output {
+        args::RulesOutputFormat::Human => {
+            let config = match find_config_path(Path::new(".")) {
+                Some(path) => Config::new(Some(&path)),
+                None => Config::new(None),
+            };
+            let config = match config {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("{}: invalid config file: {err}", "Error".red());
+                    return 1;
+                }
+            };
+            print_rules_table(&rules);
+            println!();
+            print_special_rules_table(&rules, &config);
+        }
+        args::RulesOutputFormat::Json => println!("{}", build_rules_json(&rules)),
+    }
     0
 }
 
@@ -487,6 +742,50 @@ mod tests {
         assert!(display.contains(rule.description()));
     }
 
+    #[test]
+    fn test_build_rules_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&build_rules_json(&get_all_rules())).unwrap();
+        let rules = json.as_array().unwrap();
+        assert_eq!(rules.len(), get_all_rules().len());
+        let rule = rules.iter().find(|r| r["name"] == "formats").unwrap();
+        assert_eq!(rule["severity"], "error");
+        assert_eq!(rule["default"], true);
+        assert_eq!(rule["is_check"], true);
+        assert_eq!(rule["description"], formats::FormatsRule {}.description());
+        let rule = rules.iter().find(|r| r["name"] == "fuzzy").unwrap();
+        assert_eq!(rule["severity"], "info");
+        assert_eq!(rule["default"], false);
+        assert_eq!(rule["is_check"], false);
+    }
+
+    #[test]
+    fn test_rule_explanation() {
+        for rule in get_all_rules() {
+            assert!(
+                !rule.explanation().is_empty(),
+                "missing explanation for rule {}",
+                rule.name()
+            );
+        }
+        let explanation = brackets::BracketsRule {}.explanation();
+        assert!(explanation.contains("Wrong entry:\n"));
+        assert!(explanation.contains("Correct entry:\n"));
+        assert!(explanation.contains("\n    msgid \""));
+        assert!(!explanation.contains("```"));
+        assert!(!explanation.contains("](Severity::"));
+    }
+
+    #[test]
+    fn test_explain_rule() {
+        let rules = get_all_rules();
+        let explanation = explain_rule(&rules, "brackets").unwrap();
+        assert!(explanation.starts_with("brackets: "));
+        assert!(explanation.contains("Wrong entry:"));
+        assert!(explanation.contains("Correct entry:"));
+        assert!(explain_rule(&rules, "unknown").is_none());
+    }
+
     #[test]
     fn test_get_unknown_rules_empty_names() {
         let names: Vec<String> = vec![];
@@ -526,12 +825,17 @@ mod tests {
 
     #[test]
     fn test_get_unknown_rules_special_rules_ignored() {
-        // Rules "all", "checks", "default", "spelling" are special and should NOT be reported as unknown.
+        // Rules "all", "checks", "default", groups and severities are special and should NOT be reported as unknown.
         let names = vec![
             String::from("all"),
             String::from("checks"),
             String::from("default"),
             String::from("spelling"),
+            String::from("formatting"),
+            String::from("integrity"),
+            String::from("info"),
+            String::from("warning"),
+            String::from("error"),
         ];
         let all_names = all_rules_name_set();
         let unknown = get_unknown_rules(&names, &all_names);
@@ -617,6 +921,113 @@ mod tests {
         assert_eq!(rules.enabled.len(), 3);
     }
 
+    #[test]
+    fn test_rule_groups() {
+        let all_names = all_rules_name_set();
+        for (name, rules) in RULE_GROUPS {
+            assert!(!all_names.contains(name), "group {name} is a rule name");
+            for rule in rules {
+                assert!(
+                    all_names.contains(rule),
+                    "unknown rule {rule} in group {name}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_selected_rules_groups() {
+        let config = make_config(vec!["formatting", "integrity"], vec![], vec![]);
+        let rules = get_selected_rules(&config).unwrap();
+        assert_eq!(
+            rule_names(&rules),
+            vec![
+                "brackets",
+                "double-quotes",
+                "double-spaces",
+                "escapes",
+                "formats",
+                "newlines",
+                "plural-malformed",
+                "plurals",
+                "printf-types",
+                "punc-end",
+                "punc-space-id",
+                "punc-space-str",
+                "punc-start",
+                "whitespace-end",
+                "whitespace-line-end",
+                "whitespace-line-start",
+                "whitespace-start",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_selected_rules_severity() {
+        let config = make_config(vec!["error"], vec![], vec![]);
+        let rules = get_selected_rules(&config).unwrap();
+        let names = rule_names(&rules);
+        assert!(names.contains(&"formats"));
+        assert!(names.contains(&"tabs"));
+        assert!(!names.contains(&"blank"));
+        assert!(!names.contains(&"fuzzy"));
+        assert!(
+            rules
+                .enabled
+                .iter()
+                .all(|rule| rule.severity() == Severity::Error)
+        );
+        let expected = get_all_rules()
+            .iter()
+            .filter(|rule| rule.severity() == Severity::Error)
+            .count();
+        assert_eq!(rules.enabled.len(), expected);
+    }
+
+    #[test]
+    fn test_get_selected_rules_severity_override() {
+        let mut config = make_config(vec!["error"], vec![], vec![]);
+        config.severity.insert("blank".to_string(), Severity::Error);
+        config
+            .severity
+            .insert("tabs".to_string(), Severity::Warning);
+        let rules = get_selected_rules(&config).unwrap();
+        let names = rule_names(&rules);
+        assert!(names.contains(&"blank"));
+        assert!(!names.contains(&"tabs"));
+    }
+
+    #[test]
+    fn test_get_selected_rules_severity_only_checks() {
+        let config = make_config(vec!["info", "warning", "error"], vec![], vec![]);
+        let rules = get_selected_rules(&config).unwrap();
+        let names = rule_names(&rules);
+        assert!(!names.is_empty());
+        for name in ["fuzzy", "untranslated", "noqa", "obsolete"] {
+            assert!(!names.contains(&name), "rule {name} must not be selected");
+        }
+        assert!(rules.enabled.iter().all(|rule| rule.is_check()));
+    }
+
+    #[test]
+    fn test_count_rules_with_severity_override() {
+        let all_rules = get_all_rules();
+        let mut config = make_config(vec![], vec![], vec![]);
+        let errors = count_rules_with_severity(&all_rules, &config, Severity::Error);
+        let warnings = count_rules_with_severity(&all_rules, &config, Severity::Warning);
+        // Rule "blank" reports warnings by default.
+        config.severity.insert("blank".to_string(), Severity::Error);
+        assert_eq!(
+            count_rules_with_severity(&all_rules, &config, Severity::Error),
+            errors + 1
+        );
+        assert_eq!(
+            count_rules_with_severity(&all_rules, &config, Severity::Warning),
+            warnings - 1
+        );
+    }
+
     #[test]
     fn test_get_selected_rules_single_rule() {
         let config = make_config(vec!["blank"], vec![], vec![]);
@@ -701,6 +1112,41 @@ mod tests {
         assert!(rule.new_diag(&checker, Severity::Info, "boom").is_some());
     }
 
+    #[test]
+    fn test_new_diag_severity_override() {
+        let mut checker = Checker::new(b"");
+        checker
+            .config
+            .severity
+            .insert("blank".to_string(), Severity::Info);
+        let rule = blank::BlankRule {};
+        let diag = rule.new_diag(&checker, Severity::Error, "boom").unwrap();
+        assert_eq!(diag.severity, Severity::Info);
+        // The severity filter applies to the overridden severity.
+        checker.config.check.severity = vec![Severity::Error];
+        assert!(rule.new_diag(&checker, Severity::Error, "boom").is_none());
+        // Other rules are not affected.
+        let rule = brackets::BracketsRule {};
+        assert!(rule.new_diag(&checker, Severity::Error, "boom").is_some());
+    }
+
+    #[test]
+    fn test_get_selected_rules_unknown_severity_override_error() {
+        let mut config = make_config(vec!["default"], vec![], vec![]);
+        config
+            .severity
+            .insert("blank".to_string(), Severity::Warning);
+        assert!(get_selected_rules(&config).is_ok());
+        config
+            .severity
+            .insert("nonexistent-rule".to_string(), Severity::Info);
+        let err = get_selected_rules(&config)
+            .err()
+            .expect("expected error for unknown rule in severity overrides")
+            .to_string();
+        assert_eq!(err, "unknown rules in severity overrides: nonexistent-rule");
+    }
+
     #[test]
     fn test_get_selected_rules_unknown_select_error() {
         let config = make_config(vec!["nonexistent-rule"], vec![], vec![]);
@@ -763,8 +1209,25 @@ mod tests {
 
     #[test]
     fn test_run_rules_returns_zero() {
-        let args = args::RulesArgs;
-        let exit_code = run_rules(&args);
-        assert_eq!(exit_code, 0);
+        let args = args::RulesArgs {
+            output: args::RulesOutputFormat::Human,
+            explain: None,
+        };
+        assert_eq!(run_rules(&args), 0);
+        let args = args::RulesArgs {
+            output: args::RulesOutputFormat::Json,
+            explain: None,
+        };
+        assert_eq!(run_rules(&args), 0);
+        let args = args::RulesArgs {
+            output: args::RulesOutputFormat::Human,
+            explain: Some("brackets".to_string()),
+        };
+        assert_eq!(run_rules(&args), 0);
+        let args = args::RulesArgs {
+            output: args::RulesOutputFormat::Human,
+            explain: Some("unknown".to_string()),
+        };
+        assert_eq!(run_rules(&args), 1);
     }
 }
@@ -29,6 +29,10 @@ impl RuleChecker for ShortRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check for too short translation.
     ///
     /// This rule reports the entry if one of both conditions is met (leading and trailing
@@ -82,7 +86,7 @@ impl RuleChecker for ShortRule {
         if len_msgstr * checker.config.check.short_factor as usize <= len_msgid {
             self.new_diag(
                 checker,
-                Severity::Warning,
+                self.severity(),
                 format!("translation too short ({len_msgid} / {len_msgstr})"),
             )
             .map(|d| d.with_msgs(msgid, msgstr))
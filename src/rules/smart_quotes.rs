@@ -0,0 +1,253 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `smart-quotes` rule: check quote style used in translation.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::double_quotes::DOUBLE_QUOTES;
+use crate::rules::rule::RuleChecker;
+
+/// Opening and closing quotes expected in translations, by language code.
+const LANGUAGE_QUOTES: [(&str, char, char); 8] = [
+    ("cs", '„', '“'),
+    ("de", '„', '“'),
+    ("es", '«', '»'),
+    ("fr", '«', '»'),
+    ("it", '«', '»'),
+    ("pl", '„', '”'),
+    ("ru", '«', '»'),
+    ("uk", '«', '»'),
+];
+
+pub struct SmartQuotesRule;
+
+impl RuleChecker for SmartQuotesRule {
+    fn name(&self) -> &'static str {
+        "smart-quotes"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for double quotes not matching the language convention in translation."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check for double quotes not matching the language convention in the translation.
+    ///
+    /// The quotes expected for the language of the file are taken from the `quotes`
+    /// section of the configuration file, then from a built-in list: `„“` for Czech
+    /// and German, `„”` for Polish, `«»` for French, Italian, Russian, Spanish and
+    /// Ukrainian. Other languages are not checked.
+    ///
+    /// All other double quotes (see rule `double-quotes`) are reported, including
+    /// straight quotes `"`. Single quotes are ignored, as they are mostly used as
+    /// apostrophes.
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "this is a \"test\""
+    /// msgstr "ceci est un \"test\""
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "this is a \"test\""
+    /// msgstr "ceci est un « test »"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `unexpected quotes: … (expected: … …)`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        _entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let Some((open, close)) = expected_quotes(checker) else {
+            return vec![];
+        };
+        let mut found: Vec<char> = vec![];
+        let mut hl = vec![];
+        for (idx, c) in msgstr.value.char_indices() {
+            if DOUBLE_QUOTES.contains(&c) && c != open && c != close {
+                if !found.contains(&c) {
+                    found.push(c);
+                }
+                hl.push((idx, idx + c.len_utf8()));
+            }
+        }
+        if found.is_empty() {
+            return vec![];
+        }
+        let found: Vec<String> = found.iter().map(char::to_string).collect();
+        self.new_diag(
+            checker,
+            self.severity(),
+            format!(
+                "unexpected quotes: {} (expected: {open} {close})",
+                found.join(" "),
+            ),
+        )
+        .map(|d| d.with_msgs_hl(msgid, [], msgstr, hl))
+        .into_iter()
+        .collect()
+    }
+}
+
+/// Return the opening and closing quotes expected for the language of the file,
+/// from the configuration or the built-in list.
+fn expected_quotes(checker: &Checker) -> Option<(char, char)> {
+    let lang_code = checker.language_code();
+    if let Some(quotes) = checker.config.quotes.get(lang_code) {
+        let mut chars = quotes.chars();
+        return chars.next().zip(chars.next());
+    }
+    LANGUAGE_QUOTES
+        .iter()
+        .find(|(lang, _, _)| *lang == lang_code)
+        .map(|(_, open, close)| (*open, *close))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_smart_quotes(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(SmartQuotesRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_smart_quotes_ok() {
+        let diags = check_smart_quotes(
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "this is a \"test\""
+msgstr "ceci est un « test »"
+"#,
+        );
+        assert!(diags.is_empty());
+        let diags = check_smart_quotes(
+            r#"
+msgid ""
+msgstr "Language: de_DE\n"
+
+msgid "this is a \"test\""
+msgstr "das ist ein „Test“"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_smart_quotes_other_language() {
+        let diags = check_smart_quotes(
+            r#"
+msgid ""
+msgstr "Language: nl\n"
+
+msgid "this is a \"test\""
+msgstr "dit is een \"test\""
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_smart_quotes_noqa() {
+        let diags = check_smart_quotes(
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+#, noqa:smart-quotes
+msgid "this is a \"test\""
+msgstr "ceci est un \"test\""
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_smart_quotes_error_french() {
+        let diags = check_smart_quotes(
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "this is a \"test\""
+msgstr "ceci est un \"test\""
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "unexpected quotes: \" (expected: « »)");
+        assert_eq!(diag.lines[2].highlights, vec![(12, 13), (17, 18)]);
+    }
+
+    #[test]
+    fn test_smart_quotes_error_german() {
+        let diags = check_smart_quotes(
+            r#"
+msgid ""
+msgstr "Language: de\n"
+
+msgid "this is a \"test\""
+msgstr "das ist ein “Test” oder «Test»"
+"#,
+        );
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "unexpected quotes: ” « » (expected: „ “)");
+    }
+
+    #[test]
+    fn test_smart_quotes_config() {
+        let mut config = Config::default();
+        config.quotes.insert("fr".to_string(), "“”".to_string());
+        let mut checker = Checker::new(
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "this is a \"test\""
+msgstr "ceci est un « test »"
+
+msgid "this is a \"test\""
+msgstr "ceci est un “test”"
+"#
+            .as_bytes(),
+        )
+        .with_config(config);
+        checker.do_all_checks(&Rules::new(vec![Box::new(SmartQuotesRule {})]));
+        assert_eq!(checker.diagnostics.len(), 1);
+        assert_eq!(
+            checker.diagnostics[0].message,
+            "unexpected quotes: « » (expected: “ ”)"
+        );
+    }
+}
@@ -7,11 +7,12 @@
 //! - `spelling-id`: in the source (`msgid`)
 //! - `spelling-str`: in the translation (`msgstr`)
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use spellbook::Dictionary;
 
 use crate::checker::Checker;
+use crate::config::CheckConfig;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::po::entry::Entry;
 use crate::po::format::iter::FormatWordPos;
@@ -19,6 +20,13 @@ use crate::po::format::language::Language;
 use crate::po::message::Message;
 use crate::rules::rule::RuleChecker;
 
+/// Languages using apostrophes in compound words (e.g. `aujourd'hui` or `l'ordinateur`
+/// in French).
+const APOSTROPHE_COMPOUND_LANGUAGES: [&str; 4] = ["ca", "fr", "it", "oc"];
+
+/// Apostrophes allowed inside words.
+const APOSTROPHES: [char; 3] = ['\'', '’', 'ʼ'];
+
 pub struct SpellingCtxtRule;
 
 impl RuleChecker for SpellingCtxtRule {
@@ -38,6 +46,14 @@ impl RuleChecker for SpellingCtxtRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn is_source_only(&self) -> bool {
+        true
+    }
+
     /// Check spelling in the context string (English).
     ///
     /// This rule is not enabled by default.
@@ -60,13 +76,23 @@ impl RuleChecker for SpellingCtxtRule {
     /// - [`info`](Severity::Info): `misspelled words in context: …`
     fn check_ctxt(&self, checker: &Checker, entry: &Entry, msgctxt: &Message) -> Vec<Diagnostic> {
         if let Some(dict) = &checker.dict_id {
-            let (misspelled_words, pos_words) =
-                check_words(&msgctxt.value, entry.format_language, dict);
+            let (misspelled_words, pos_words) = check_words(
+                &msgctxt.value,
+                entry.format_language,
+                dict,
+                &checker.config.check,
+                has_apostrophe_compounds(&checker.config.check.lang_id),
+            );
             if !misspelled_words.is_empty() {
                 return self
-                    .new_diag(checker, Severity::Info, "misspelled words in context")
+                    .new_diag(checker, self.severity(), "misspelled words in context")
                     .map(|d| {
                         d.with_msg_hl(msgctxt, pos_words)
+                            .with_spelling_suggestions(suggest_words(
+                                &misspelled_words,
+                                dict,
+                                &checker.config.check,
+                            ))
                             .with_misspelled_words(misspelled_words)
                     })
                     .into_iter()
@@ -96,6 +122,14 @@ impl RuleChecker for SpellingIdRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn is_source_only(&self) -> bool {
+        true
+    }
+
     /// Check spelling in the source string (English).
     ///
     /// This rule is not enabled by default.
@@ -122,13 +156,23 @@ impl RuleChecker for SpellingIdRule {
         msgstr: &Message,
     ) -> Vec<Diagnostic> {
         if let Some(dict) = &checker.dict_id {
-            let (misspelled_words, pos_words) =
-                check_words(&msgid.value, entry.format_language, dict);
+            let (misspelled_words, pos_words) = check_words(
+                &msgid.value,
+                entry.format_language,
+                dict,
+                &checker.config.check,
+                has_apostrophe_compounds(&checker.config.check.lang_id),
+            );
             if !misspelled_words.is_empty() {
                 return self
-                    .new_diag(checker, Severity::Info, "misspelled words in source")
+                    .new_diag(checker, self.severity(), "misspelled words in source")
                     .map(|d| {
                         d.with_msgs_hl(msgid, pos_words, msgstr, [])
+                            .with_spelling_suggestions(suggest_words(
+                                &misspelled_words,
+                                dict,
+                                &checker.config.check,
+                            ))
                             .with_misspelled_words(misspelled_words)
                     })
                     .into_iter()
@@ -158,6 +202,10 @@ impl RuleChecker for SpellingStrRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check spelling in the translated string (using language detected in PO file).
     ///
     /// This rule is not enabled by default.
@@ -184,13 +232,29 @@ impl RuleChecker for SpellingStrRule {
         msgstr: &Message,
     ) -> Vec<Diagnostic> {
         if let Some(dict) = &checker.dict_str {
-            let (misspelled_words, pos_words) =
-                check_words(&msgstr.value, entry.format_language, dict);
+            let language = checker
+                .config
+                .check
+                .lang_str
+                .as_deref()
+                .unwrap_or_else(|| checker.language());
+            let (misspelled_words, pos_words) = check_words(
+                &msgstr.value,
+                entry.format_language,
+                dict,
+                &checker.config.check,
+                has_apostrophe_compounds(language),
+            );
             if !misspelled_words.is_empty() {
                 return self
-                    .new_diag(checker, Severity::Info, "misspelled words in translation")
+                    .new_diag(checker, self.severity(), "misspelled words in translation")
                     .map(|d| {
                         d.with_msgs_hl(msgid, [], msgstr, pos_words)
+                            .with_spelling_suggestions(suggest_words(
+                                &misspelled_words,
+                                dict,
+                                &checker.config.check,
+                            ))
                             .with_misspelled_words(misspelled_words)
                     })
                     .into_iter()
@@ -201,6 +265,78 @@ impl RuleChecker for SpellingStrRule {
     }
 }
 
+/// Split an identifier at lowercase to uppercase boundaries, for example `fileName`
+/// is split into `file` and `Name`.
+///
+/// Return the parts with their offset in the identifier.
+fn split_identifier(word: &str) -> Vec<(usize, &str)> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut prev_lowercase = false;
+    for (idx, c) in word.char_indices() {
+        if prev_lowercase && c.is_uppercase() {
+            parts.push((start, &word[start..idx]));
+            start = idx;
+        }
+        prev_lowercase = c.is_lowercase();
+    }
+    parts.push((start, &word[start..]));
+    parts
+}
+
+/// Return `true` if the language (e.g. `fr` or `fr_FR`) uses apostrophes in compound words.
+fn has_apostrophe_compounds(language: &str) -> bool {
+    let code = language.split_once('_').map_or(language, |(code, _)| code);
+    APOSTROPHE_COMPOUND_LANGUAGES.contains(&code)
+}
+
+/// Check a compound word (with hyphens or apostrophes) rejected by the dictionary:
+/// the word is accepted if all its parts are valid words (e.g. `well` and `known` for
+/// `well-known`).
+///
+/// Apostrophes are considered only if `apostrophe_compounds` is `true`: the word is
+/// then first checked with typographic apostrophes replaced by `'` and the parts
+/// ending with an apostrophe are checked with the apostrophe (e.g. `l'` in
+/// `l'ordinateur`).
+fn check_compound_word(word: &str, dict: &Dictionary, apostrophe_compounds: bool) -> bool {
+    let is_separator = |c: char| c == '-' || (apostrophe_compounds && APOSTROPHES.contains(&c));
+    if !word.contains(is_separator) {
+        return false;
+    }
+    let normalize = |s: &str| s.replace(['’', 'ʼ'], "'");
+    if apostrophe_compounds && dict.check(&normalize(word)) {
+        return true;
+    }
+    word.split_inclusive(is_separator).all(|part| {
+        let part_word = part.trim_end_matches(is_separator);
+        part_word.is_empty()
+            || dict.check(part_word)
+            || (apostrophe_compounds && part.ends_with(APOSTROPHES) && dict.check(&normalize(part)))
+    })
+}
+
+/// Return the top suggestion of the dictionary for each misspelled word, if the
+/// option `spell_suggest` is enabled (words without suggestion are not returned).
+fn suggest_words(
+    misspelled_words: &HashSet<&str>,
+    dict: &Dictionary,
+    config: &CheckConfig,
+) -> HashMap<String, String> {
+    let mut suggestions = HashMap::new();
+    if !config.spell_suggest {
+        return suggestions;
+    }
+    let mut words = vec![];
+    for word in misspelled_words {
+        words.clear();
+        dict.suggest(word, &mut words);
+        if let Some(suggestion) = words.first() {
+            suggestions.insert((*word).to_string(), suggestion.clone());
+        }
+    }
+    suggestions
+}
+
 /// Check words in a string: context (msgctxt), source (msgid) or translation (msgstr).
 ///
 /// Return list of misspelled words (can be empty) and their positions in the string (start, end).
@@ -208,28 +344,45 @@ fn check_words<'s>(
     s: &'s str,
     format_language: Language,
     dict: &Dictionary,
+    config: &CheckConfig,
+    apostrophe_compounds: bool,
 ) -> (HashSet<&'s str>, Vec<(usize, usize)>) {
     let mut misspelled_words: HashSet<&str> = HashSet::new();
     let mut hash_words: HashSet<&str> = HashSet::new();
     let mut pos_words = Vec::new();
-    for word in FormatWordPos::new(s, format_language) {
-        // Ignore word if it contains at least one digit.
-        if word.s.chars().any(|c| c.is_ascii_digit()) {
-            continue;
-        }
-        // Ignore with at least two chars and only uppercase chars (e.g. "HTTP").
-        if word.s.len() >= 2 && word.s.chars().all(|c| c.is_ascii_uppercase()) {
-            continue;
-        }
-        if hash_words.contains(word.s) {
-            if misspelled_words.contains(word.s) {
-                pos_words.push((word.start, word.end));
-            }
+    for word_pos in
+        FormatWordPos::new(s, format_language).with_underscore(config.words_keep_underscore)
+    {
+        let words = if config.spell_split_identifiers {
+            split_identifier(word_pos.s)
         } else {
-            hash_words.insert(word.s);
-            if !dict.check(word.s) {
-                misspelled_words.insert(word.s);
-                pos_words.push((word.start, word.end));
+            vec![(0, word_pos.s)]
+        };
+        for (offset, word) in words {
+            let (start, end) = (
+                word_pos.start + offset,
+                word_pos.start + offset + word.len(),
+            );
+            // Ignore short words and words containing at least one digit.
+            if word.chars().count() < config.spell_min_length
+                || word.chars().any(|c| c.is_ascii_digit())
+            {
+                continue;
+            }
+            // Ignore with at least two chars and only uppercase chars (e.g. "HTTP").
+            if word.len() >= 2 && word.chars().all(|c| c.is_ascii_uppercase()) {
+                continue;
+            }
+            if hash_words.contains(word) {
+                if misspelled_words.contains(word) {
+                    pos_words.push((start, end));
+                }
+            } else {
+                hash_words.insert(word);
+                if !dict.check(word) && !check_compound_word(word, dict, apostrophe_compounds) {
+                    misspelled_words.insert(word);
+                    pos_words.push((start, end));
+                }
             }
         }
     }
@@ -244,10 +397,13 @@ mod tests {
     use crate::{config::Config, diagnostic::Diagnostic, rules::rule::Rules};
 
     fn check_spelling(content: &str) -> Vec<Diagnostic> {
+        check_spelling_config(content, Config::default())
+    }
+
+    fn check_spelling_config(content: &str, mut config: Config) -> Vec<Diagnostic> {
         let mut test_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         test_dir.push("resources");
         test_dir.push("test");
-        let mut config = Config::default();
         config.check.path_dicts = test_dir;
         let mut checker = Checker::new(content.as_bytes()).with_config(config);
         let rules = Rules::new(vec![
@@ -325,4 +481,212 @@ msgstr "ceci est unz fôte, ceci est unz fôte"
             HashSet::from(["fôte".to_string(), "unz".to_string()])
         );
     }
+
+    #[test]
+    fn test_split_identifier() {
+        assert_eq!(split_identifier("file"), vec![(0, "file")]);
+        assert_eq!(split_identifier("fileName"), vec![(0, "file"), (4, "Name")]);
+        assert_eq!(
+            split_identifier("PascalCaseName"),
+            vec![(0, "Pascal"), (6, "Case"), (10, "Name")]
+        );
+        assert_eq!(split_identifier("HTTP"), vec![(0, "HTTP")]);
+        assert_eq!(split_identifier("HTTPServer"), vec![(0, "HTTPServer")]);
+    }
+
+    #[test]
+    fn test_spelling_split_identifiers() {
+        let content = r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "someContext, SomeContext, HTTP, thisTyypo"
+msgstr "testé"
+"#;
+        // Identifiers are not split by default.
+        let diags = check_spelling(content);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].build_message(),
+            "misspelled words in source: SomeContext, someContext, thisTyypo"
+        );
+        let mut config = Config::default();
+        config.check.spell_split_identifiers = true;
+        let diags = check_spelling_config(content, config);
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.build_message(), "misspelled words in source: Tyypo");
+        assert_eq!(diag.lines[0].highlights, vec![(36, 41)]);
+    }
+
+    #[test]
+    fn test_spelling_min_length() {
+        let content = r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgctxt "px"
+msgid "this is a typo: px"
+msgstr "ceci est une faute : px"
+"#;
+        // Words with 2 chars are checked by default.
+        assert_eq!(check_spelling(content).len(), 3);
+        let mut config = Config::default();
+        config.check.spell_min_length = 3;
+        assert!(check_spelling_config(content, config).is_empty());
+        let mut config = Config::default();
+        config.check.spell_min_length = 1;
+        let diags = check_spelling_config(content, config);
+        assert_eq!(diags.len(), 3);
+        assert_eq!(diags[0].build_message(), "misspelled words in context: px");
+        assert_eq!(diags[1].build_message(), "misspelled words in source: px");
+        assert_eq!(
+            diags[2].build_message(),
+            "misspelled words in translation: px"
+        );
+    }
+
+    #[test]
+    fn test_spelling_min_length_single_char() {
+        let content = r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "this is a typo: x"
+msgstr "ceci est une faute : x"
+"#;
+        assert!(check_spelling(content).is_empty());
+        let mut config = Config::default();
+        config.check.spell_min_length = 1;
+        assert_eq!(check_spelling_config(content, config).len(), 2);
+    }
+
+    #[test]
+    fn test_spelling_suggest() {
+        let content = r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "this is a typo"
+msgstr "ceci est une fôte"
+"#;
+        let diags = check_spelling(content);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].build_message(),
+            "misspelled words in translation: fôte"
+        );
+        let mut config = Config::default();
+        config.check.spell_suggest = true;
+        let diags = check_spelling_config(content, config);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].build_message(),
+            "misspelled words in translation: fôte (faute?)"
+        );
+    }
+
+    #[test]
+    fn test_spelling_lang_str() {
+        let content = r#"
+msgid ""
+msgstr "Language: de\n"
+
+msgid "this is a typo"
+msgstr "ceci est une fôte"
+"#;
+        // No dictionary for the language found in header.
+        let diags = check_spelling(content);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert!(
+            diags[0]
+                .message
+                .contains("dictionary not found for language 'de'")
+        );
+        let mut config = Config::default();
+        config.check.lang_str = Some("fr".to_string());
+        let diags = check_spelling_config(content, config);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].build_message(),
+            "misspelled words in translation: fôte"
+        );
+    }
+
+    #[test]
+    fn test_spelling_lang_str_overrides_header() {
+        let content = r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "this is a typo"
+msgstr "this is a typo"
+"#;
+        let diags = check_spelling(content);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].build_message(),
+            "misspelled words in translation: is, this, typo"
+        );
+        let mut config = Config::default();
+        config.check.lang_str = Some("en_US".to_string());
+        assert!(check_spelling_config(content, config).is_empty());
+    }
+
+    #[test]
+    fn test_spelling_lang_str_without_header() {
+        let content = r#"
+msgid "this is a typo"
+msgstr "ceci est une fôte"
+"#;
+        let diags = check_spelling(content);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].rule, "spelling-str");
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert_eq!(
+            diags[0].message,
+            "language not found in header (option --lang-str can be used), spelling rule ignored"
+        );
+        let mut config = Config::default();
+        config.check.lang_str = Some("fr".to_string());
+        let diags = check_spelling_config(content, config);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].build_message(),
+            "misspelled words in translation: fôte"
+        );
+    }
+
+    #[test]
+    fn test_spelling_compound_words() {
+        let diags = check_spelling(
+            r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "well-known, well-knwon, this'is"
+msgstr "aujourd'hui, aujourd’hui, l'ordinateur, l’ordinateur, aujourd'hiu, l'ordinatuer"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        // Apostrophes are not used in English compound words.
+        assert_eq!(
+            diags[0].build_message(),
+            "misspelled words in source: this'is, well-knwon"
+        );
+        assert_eq!(
+            diags[1].build_message(),
+            "misspelled words in translation: aujourd'hiu, l'ordinatuer"
+        );
+    }
+
+    #[test]
+    fn test_has_apostrophe_compounds() {
+        assert!(has_apostrophe_compounds("fr"));
+        assert!(has_apostrophe_compounds("fr_FR"));
+        assert!(has_apostrophe_compounds("it"));
+        assert!(!has_apostrophe_compounds("en_US"));
+        assert!(!has_apostrophe_compounds(""));
+    }
 }
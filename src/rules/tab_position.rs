@@ -0,0 +1,210 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Implementation of the `tab-position` rule: check inconsistent position of tabs.
+
+use crate::checker::Checker;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::po::entry::Entry;
+use crate::po::message::Message;
+use crate::rules::rule::RuleChecker;
+
+pub struct TabPositionRule;
+
+/// Position of a tab relative to the text around it: is there text before and
+/// after the tab on the same line (another tab is not considered as text)?
+#[derive(Debug, PartialEq)]
+struct TabPosition {
+    text_before: bool,
+    text_after: bool,
+}
+
+impl RuleChecker for TabPositionRule {
+    fn name(&self) -> &'static str {
+        "tab-position"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check for inconsistent position of tab characters in translation."
+    }
+
+    fn is_default(&self) -> bool {
+        false
+    }
+
+    fn is_check(&self) -> bool {
+        true
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    /// Check for inconsistent position of tabs (`\t`) in the translation.
+    ///
+    /// For each tab, the rule checks whether there is text before and after it on
+    /// the same line, and compares the sequence of tabs between source and translation:
+    /// for example a tab at the beginning of a line in the source must also be at the
+    /// beginning of a line in the translation, not in the middle of the text.
+    ///
+    /// The strings are compared only if they have the same number of tabs (otherwise
+    /// the `tabs` rule reports the issue).
+    ///
+    /// This rule is not enabled by default.
+    ///
+    /// Wrong entry:
+    /// ```text
+    /// msgid "\tName:\tvalue"
+    /// msgstr "Nom :\t\tvaleur"
+    /// ```
+    ///
+    /// Correct entry:
+    /// ```text
+    /// msgid "\tName:\tvalue"
+    /// msgstr "\tNom :\tvaleur"
+    /// ```
+    ///
+    /// Diagnostics reported:
+    /// - [`info`](Severity::Info): `inconsistent tab structure`
+    fn check_msg(
+        &self,
+        checker: &Checker,
+        _entry: &Entry,
+        msgid: &Message,
+        msgstr: &Message,
+    ) -> Vec<Diagnostic> {
+        let id_tabs = tab_positions(&msgid.value);
+        let str_tabs = tab_positions(&msgstr.value);
+        if id_tabs.len() != str_tabs.len()
+            || id_tabs
+                .iter()
+                .zip(str_tabs.iter())
+                .all(|((_, id_pos), (_, str_pos))| id_pos == str_pos)
+        {
+            return vec![];
+        }
+        self.new_diag(checker, self.severity(), "inconsistent tab structure")
+            .map(|d| {
+                d.with_msgs_hl(
+                    msgid,
+                    id_tabs.into_iter().map(|(idx, _)| (idx, idx + 1)),
+                    msgstr,
+                    str_tabs.into_iter().map(|(idx, _)| (idx, idx + 1)),
+                )
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Return the byte offset and the position of each tab in the string.
+fn tab_positions(s: &str) -> Vec<(usize, TabPosition)> {
+    let bytes = s.as_bytes();
+    let is_separator = |b: Option<&u8>| matches!(b, None | Some(b'\t' | b'\n'));
+    memchr::memchr_iter(b'\t', bytes)
+        .map(|idx| {
+            let before = idx.checked_sub(1).and_then(|i| bytes.get(i));
+            let after = bytes.get(idx + 1);
+            (
+                idx,
+                TabPosition {
+                    text_before: !is_separator(before),
+                    text_after: !is_separator(after),
+                },
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+
+    fn check_tab_position(content: &str) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes());
+        let rules = Rules::new(vec![Box::new(TabPositionRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
+    #[test]
+    fn test_tab_positions() {
+        assert!(tab_positions("test").is_empty());
+        assert_eq!(
+            tab_positions("\ta\t\nb\t"),
+            vec![
+                (
+                    0,
+                    TabPosition {
+                        text_before: false,
+                        text_after: true
+                    }
+                ),
+                (
+                    2,
+                    TabPosition {
+                        text_before: true,
+                        text_after: false
+                    }
+                ),
+                (
+                    5,
+                    TabPosition {
+                        text_before: true,
+                        text_after: false
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tab_position_ok() {
+        let diags = check_tab_position(
+            r#"
+msgid "\tName:\tvalue\n\tSize:\t\tsize"
+msgstr "\tNom :\tvaleur\n\tTaille :\t\ttaille"
+
+msgid "missing\ttab"
+msgstr "tabulation manquante"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_tab_position_noqa() {
+        let diags = check_tab_position(
+            r#"
+#, noqa:tab-position
+msgid "\tName:\tvalue"
+msgstr "Nom :\t\tvaleur"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_tab_position_error() {
+        let diags = check_tab_position(
+            r#"
+msgid "\tName:\tvalue"
+msgstr "Nom :\t\tvaleur"
+
+msgid "Name\tvalue"
+msgstr "Nom valeur\t"
+"#,
+        );
+        assert_eq!(diags.len(), 2);
+        let diag = &diags[0];
+        assert_eq!(diag.severity, Severity::Info);
+        assert_eq!(diag.message, "inconsistent tab structure");
+        assert_eq!(diag.lines[0].highlights, vec![(0, 1), (6, 7)]);
+        assert_eq!(diag.lines[2].highlights, vec![(5, 6), (6, 7)]);
+        let diag = &diags[1];
+        assert_eq!(diag.message, "inconsistent tab structure");
+        assert_eq!(diag.lines[2].highlights, vec![(10, 11)]);
+    }
+}
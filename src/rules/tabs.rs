@@ -29,6 +29,10 @@ impl RuleChecker for TabsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
     /// Check for missing or extra tabs (`\t`) in the translation.
     ///
     /// Wrong entry:
@@ -64,7 +68,7 @@ impl RuleChecker for TabsRule {
                 format!("extra tabs '\\t' ({id_count} / {str_count})")
             }
         };
-        self.new_diag(checker, Severity::Error, msg)
+        self.new_diag(checker, self.severity(), msg)
             .map(|d| {
                 d.with_msgs_hl(
                     msgid,
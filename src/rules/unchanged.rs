@@ -7,6 +7,7 @@
 use crate::checker::Checker;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::po::entry::Entry;
+use crate::po::format::iter::FormatWordPos;
 use crate::po::message::Message;
 use crate::rules::rule::RuleChecker;
 
@@ -29,9 +30,19 @@ impl RuleChecker for UnchangedRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for unchanged translation: the same as the source string.
     ///
     /// If the source message contains only upper case characters, it is ignored.
+    /// Messages without any word to translate (only format strings, numbers and
+    /// punctuation, like `%s`, `{0}` or `100%`) are ignored as well.
+    ///
+    /// Source messages listed in option `unchanged_allow` (for example `OK` or `Menu`)
+    /// are ignored, as well as messages with fewer words than the option
+    /// `unchanged_min_words` (default: 0, all messages are checked).
     ///
     /// This rule is not enabled by default.
     ///
@@ -52,29 +63,41 @@ impl RuleChecker for UnchangedRule {
     fn check_msg(
         &self,
         checker: &Checker,
-        _entry: &Entry,
+        entry: &Entry,
         msgid: &Message,
         msgstr: &Message,
     ) -> Vec<Diagnostic> {
-        if !msgid.value.trim().is_empty()
-            && !msgstr.value.trim().is_empty()
-            && msgid.value == msgstr.value
-            && msgid.value.chars().any(char::is_lowercase)
+        let config = &checker.config.check;
+        if msgid.value.trim().is_empty()
+            || msgstr.value.trim().is_empty()
+            || msgid.value != msgstr.value
+            || !msgid.value.chars().any(char::is_lowercase)
+            || config
+                .unchanged_allow
+                .iter()
+                .any(|allowed| allowed == msgid.value.trim())
         {
-            return self
-                .new_diag(checker, Severity::Info, "unchanged translation")
-                .map(|d| d.with_msgs(msgid, msgstr))
-                .into_iter()
-                .collect();
+            return vec![];
         }
-        vec![]
+        // Count words with at least one letter, ignoring format strings and numbers.
+        let words = FormatWordPos::new(&msgid.value, entry.format_language)
+            .with_underscore(config.words_keep_underscore)
+            .filter(|word| word.s.chars().any(char::is_alphabetic))
+            .count();
+        if words == 0 || words < config.unchanged_min_words {
+            return vec![];
+        }
+        self.new_diag(checker, self.severity(), "unchanged translation")
+            .map(|d| d.with_msgs(msgid, msgstr))
+            .into_iter()
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{diagnostic::Diagnostic, rules::rule::Rules};
+    use crate::{config::Config, diagnostic::Diagnostic, rules::rule::Rules};
 
     fn check_unchanged(content: &str) -> Vec<Diagnostic> {
         let mut checker = Checker::new(content.as_bytes());
@@ -83,6 +106,13 @@ mod tests {
         checker.diagnostics
     }
 
+    fn check_unchanged_config(content: &str, config: Config) -> Vec<Diagnostic> {
+        let mut checker = Checker::new(content.as_bytes()).with_config(config);
+        let rules = Rules::new(vec![Box::new(UnchangedRule {})]);
+        checker.do_all_checks(&rules);
+        checker.diagnostics
+    }
+
     #[test]
     fn test_not_translated() {
         let diags = check_unchanged(
@@ -117,6 +147,29 @@ msgstr "ACRONYM"
         assert!(diags.is_empty());
     }
 
+    #[test]
+    fn test_unchanged_no_words() {
+        let diags = check_unchanged(
+            r#"
+#, c-format
+msgid "%s"
+msgstr "%s"
+
+#, python-brace-format
+msgid "{0}: {name}"
+msgstr "{0}: {name}"
+
+#, c-format
+msgid "%d%% (%s)"
+msgstr "%d%% (%s)"
+
+msgid "100%"
+msgstr "100%"
+"#,
+        );
+        assert!(diags.is_empty());
+    }
+
     #[test]
     fn test_unchanged_error_noqa() {
         let diags = check_unchanged(
@@ -135,11 +188,55 @@ msgstr "this is a test"
             r#"
 msgid "this is a test"
 msgstr "this is a test"
+
+#, c-format
+msgid "%s: test"
+msgstr "%s: test"
 "#,
         );
-        assert_eq!(diags.len(), 1);
+        assert_eq!(diags.len(), 2);
         let diag = &diags[0];
         assert_eq!(diag.severity, Severity::Info);
         assert_eq!(diag.message, "unchanged translation");
     }
+
+    #[test]
+    fn test_unchanged_allow() {
+        let mut config = Config::default();
+        config.check.unchanged_allow = vec!["Ok".to_string(), "Menu".to_string()];
+        let diags = check_unchanged_config(
+            r#"
+msgid "Ok"
+msgstr "Ok"
+
+msgid "Menu"
+msgstr "Menu"
+
+msgid "this is a test"
+msgstr "this is a test"
+"#,
+            config,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "unchanged translation");
+        assert_eq!(diags[0].lines[0].message, "this is a test");
+    }
+
+    #[test]
+    fn test_unchanged_min_words() {
+        let mut config = Config::default();
+        config.check.unchanged_min_words = 2;
+        let diags = check_unchanged_config(
+            r#"
+msgid "Email"
+msgstr "Email"
+
+msgid "this is a test"
+msgstr "this is a test"
+"#,
+            config,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "unchanged translation");
+    }
 }
@@ -34,6 +34,10 @@ impl RuleChecker for UnicodeCtrlRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
     /// Check for Unicode control / format characters that appear in the translation
     /// but not in the source string. These are usually invisible (zero-width spaces,
     /// bidi overrides, soft hyphens, BOM, C0/C1 controls, …) and are a typical
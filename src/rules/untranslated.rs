@@ -29,6 +29,10 @@ impl RuleChecker for UntranslatedRule {
         false
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Report entry if untranslated.
     ///
     /// Untranslated is not strictly speaking an error, but this check helps to identify
@@ -58,7 +62,7 @@ impl RuleChecker for UntranslatedRule {
         msgstr: &Message,
     ) -> Vec<Diagnostic> {
         if msgstr.value.is_empty() {
-            self.new_diag(checker, Severity::Info, "untranslated message")
+            self.new_diag(checker, self.severity(), "untranslated message")
                 .map(|d| d.with_msg(msgid))
                 .into_iter()
                 .collect()
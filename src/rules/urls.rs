@@ -34,6 +34,10 @@ impl RuleChecker for UrlsRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     /// Check for missing, extra or different URLs in the translation.
     ///
     /// This rule is not enabled by default.
@@ -74,7 +78,7 @@ impl RuleChecker for UrlsRule {
             std::cmp::Ordering::Greater => self
                 .new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!("missing URLs ({} / {})", id_urls.len(), str_urls.len()),
                 )
                 .map(|d| {
@@ -90,7 +94,7 @@ impl RuleChecker for UrlsRule {
             std::cmp::Ordering::Less => self
                 .new_diag(
                     checker,
-                    Severity::Warning,
+                    self.severity(),
                     format!("extra URLs ({} / {})", id_urls.len(), str_urls.len()),
                 )
                 .map(|d| {
@@ -127,7 +131,7 @@ impl RuleChecker for UrlsRule {
                         edits,
                         safe: false,
                     });
-                    self.new_diag(checker, Severity::Warning, "different URLs")
+                    self.new_diag(checker, self.severity(), "different URLs")
                         .map(|d| {
                             d.with_msgs_hl(
                                 msgid,
@@ -8,6 +8,9 @@
 //! - `whitespace-line-start`: whitespace at the beginning of each interior line
 //! - `whitespace-line-end`: whitespace at the end of each interior line
 
+use std::borrow::Cow;
+use std::fmt::Write as _;
+
 use crate::checker::Checker;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::fix::{Edit, Fix, FixTarget};
@@ -34,6 +37,10 @@ impl RuleChecker for WhitespaceStartRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for inconsistent leading whitespace between source and translation.
     ///
     /// Wrong entry:
@@ -77,8 +84,12 @@ impl RuleChecker for WhitespaceStartRule {
             };
             self.new_diag(
                 checker,
-                Severity::Info,
-                format!("inconsistent leading whitespace ('{id_ws}' / '{str_ws}')"),
+                self.severity(),
+                format!(
+                    "inconsistent leading whitespace ('{}' / '{}')",
+                    display_whitespace(id_ws),
+                    display_whitespace(str_ws)
+                ),
             )
             .map(|d| {
                 d.with_msgs_hl(msgid, [(0, id_ws.len())], msgstr, [(0, str_ws.len())])
@@ -109,8 +120,17 @@ impl RuleChecker for WhitespaceEndRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for inconsistent trailing whitespace between source and translation.
     ///
+    /// Unicode spaces like the non-breaking space (U+00A0) and the narrow
+    /// non-breaking space (U+202F) are whitespace as well: a translation ending
+    /// with a non-breaking space when the source ends with a regular space (or
+    /// without space) is reported.
+    ///
     /// Wrong entry:
     /// ```text
     /// msgid "this is a test "
@@ -153,8 +173,12 @@ impl RuleChecker for WhitespaceEndRule {
             };
             self.new_diag(
                 checker,
-                Severity::Info,
-                format!("inconsistent trailing whitespace ('{id_ws}' / '{str_ws}')"),
+                self.severity(),
+                format!(
+                    "inconsistent trailing whitespace ('{}' / '{}')",
+                    display_whitespace(id_ws),
+                    display_whitespace(str_ws)
+                ),
             )
             .map(|d| {
                 d.with_msgs_hl(
@@ -190,6 +214,10 @@ impl RuleChecker for WhitespaceLineStartRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for inconsistent leading whitespace at the start of each *interior*
     /// line (the lines after an embedded newline). The string's own leading
     /// whitespace is handled by `whitespace-start`, so the first line is skipped.
@@ -238,6 +266,10 @@ impl RuleChecker for WhitespaceLineEndRule {
         true
     }
 
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
     /// Check for inconsistent trailing whitespace at the end of each *interior*
     /// line (the lines before an embedded newline). The string's own trailing
     /// whitespace is handled by `whitespace-end`, so the last line is skipped.
@@ -365,8 +397,12 @@ fn check_interior_whitespace<R: RuleChecker>(
         };
         if let Some(diag) = rule.new_diag(
             checker,
-            Severity::Info,
-            format!("inconsistent {position} whitespace ('{id_ws}' / '{str_ws}')"),
+            rule.severity(),
+            format!(
+                "inconsistent {position} whitespace ('{}' / '{}')",
+                display_whitespace(id_ws),
+                display_whitespace(str_ws)
+            ),
         ) {
             diagnostics.push(
                 diag.with_msgs_hl(msgid, [id_hl], msgstr, [str_hl])
@@ -387,7 +423,34 @@ fn get_whitespace_start(value: &str) -> &str {
     &value[..pos]
 }
 
+/// Make a run of whitespace visible in a diagnostic message: spaces are kept, tabs
+/// and carriage returns are escaped, non-breaking spaces are displayed as `<NBSP>` and
+/// `<NNBSP>` (narrow), and any other whitespace as its code point (e.g. `<U+3000>`).
+fn display_whitespace(ws: &str) -> Cow<'_, str> {
+    if ws.chars().all(|c| c == ' ') {
+        return Cow::Borrowed(ws);
+    }
+    let mut s = String::with_capacity(ws.len());
+    for c in ws.chars() {
+        match c {
+            ' ' => s.push(' '),
+            '\t' => s.push_str("\\t"),
+            '\r' => s.push_str("\\r"),
+            '\u{00a0}' => s.push_str("<NBSP>"),
+            '\u{202f}' => s.push_str("<NNBSP>"),
+            _ => {
+                let _ = write!(s, "<U+{:04X}>", u32::from(c));
+            }
+        }
+    }
+    Cow::Owned(s)
+}
+
 /// Get the trailing whitespace of a string (up to the last non-whitespace character or newline).
+///
+/// Whitespace is any char with the Unicode property `White_Space` (including the
+/// non-breaking spaces), so the length of the slice is in bytes and the slice
+/// always starts on a char boundary.
 fn get_whitespace_end(value: &str) -> &str {
     let pos = value
         .chars()
@@ -449,6 +512,8 @@ mod tests {
         assert_eq!(get_whitespace_end("test\t"), "\t");
         assert_eq!(get_whitespace_end("test\t "), "\t ");
         assert_eq!(get_whitespace_end("test \n"), "");
+        assert_eq!(get_whitespace_end("test\u{00a0}"), "\u{00a0}");
+        assert_eq!(get_whitespace_end("test \u{202f}"), " \u{202f}");
     }
 
     #[test]
@@ -557,6 +622,63 @@ msgstr "testé"
         assert!(file_byte_range.start < file_byte_range.end);
     }
 
+    #[test]
+    fn test_display_whitespace() {
+        assert_eq!(display_whitespace(""), "");
+        assert_eq!(display_whitespace("  "), "  ");
+        assert_eq!(display_whitespace(" \t\r"), " \\t\\r");
+        assert_eq!(display_whitespace("\u{00a0} \u{202f}"), "<NBSP> <NNBSP>");
+        assert_eq!(display_whitespace("\u{3000}"), "<U+3000>");
+    }
+
+    #[test]
+    fn test_whitespace_start_nbsp() {
+        let diags = check_whitespace_start(
+            "
+msgid \" test\"
+msgstr \"\u{00a0}test\"
+
+msgid \"\ttest\"
+msgstr \"\ttest\"
+",
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "inconsistent leading whitespace (' ' / '<NBSP>')"
+        );
+    }
+
+    #[test]
+    fn test_whitespace_end_nbsp() {
+        let diags = check_whitespace_end(
+            "
+msgid \"test \"
+msgstr \"test\u{00a0}\"
+
+msgid \"test\"
+msgstr \"testé\u{202f}\"
+",
+        );
+        assert_eq!(diags.len(), 2);
+        assert_eq!(
+            diags[0].message,
+            "inconsistent trailing whitespace (' ' / '<NBSP>')"
+        );
+        // Non-breaking space is 2 bytes (U+00A0), narrow non-breaking space is 3 bytes
+        // (U+202F).
+        assert_eq!(diags[0].lines[0].highlights, vec![(4, 5)]);
+        assert_eq!(diags[0].lines[2].highlights, vec![(4, 6)]);
+        let fix = diags[0].fix.as_ref().expect("fix should be attached");
+        assert_eq!(fix.edits[0].range, 4..6);
+        assert_eq!(fix.edits[0].replacement, " ");
+        assert_eq!(diags[1].lines[0].highlights, vec![(4, 4)]);
+        assert_eq!(diags[1].lines[2].highlights, vec![(6, 9)]);
+        let fix = diags[1].fix.as_ref().expect("fix should be attached");
+        assert_eq!(fix.edits[0].range, 6..9);
+        assert_eq!(fix.edits[0].replacement, "");
+    }
+
     #[test]
     fn test_whitespace_end_fix() {
         // msgstr has two trailing spaces; msgid has one.
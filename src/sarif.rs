@@ -89,7 +89,10 @@ pub struct SarifMessage<'a> {
 #[serde(rename_all = "camelCase")]
 pub struct SarifResult<'a> {
     pub rule_id: &'static str,
-    pub rule_index: usize,
+    /// Index of the rule in the driver rules; not set for diagnostics that are not
+    /// reported by a rule (e.g. `read-error` or `config-error`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_index: Option<usize>,
     pub level: &'static str,
     pub message: SarifMessage<'a>,
     pub locations: Vec<SarifLocation<'a>>,
@@ -215,7 +218,7 @@ pub fn build_sarif(result: &[CheckFileResult]) -> SarifLog<'_> {
             let message_text = diag.build_message();
             let fingerprint = compute_fingerprint(diag.rule, &path_str, start_line, &message_text);
 
-            let rule_index = rule_index_map.get(diag.rule).copied().unwrap_or(0);
+            let rule_index = rule_index_map.get(diag.rule).copied();
 
             sarif_results.push(SarifResult {
                 rule_id: diag.rule,
@@ -258,7 +261,7 @@ pub fn build_sarif(result: &[CheckFileResult]) -> SarifLog<'_> {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
     use std::path::PathBuf;
 
     use super::*;
@@ -288,6 +291,10 @@ mod tests {
         fn is_check(&self) -> bool {
             true
         }
+
+        fn severity(&self) -> Severity {
+            Severity::Error
+        }
     }
 
     fn mock_rule(
@@ -311,12 +318,15 @@ mod tests {
             rule,
             severity,
             message: message.to_string().into(),
+            msgctxt: None,
             lines: vec![DiagnosticLine {
                 line_number,
+                column: 0,
                 message: line_message.to_string(),
                 highlights,
             }],
             misspelled_words: HashSet::new(),
+            spelling_suggestions: HashMap::new(),
             fix: None,
         }
     }
@@ -421,7 +431,7 @@ mod tests {
 
         let r0 = &run.results[0];
         assert_eq!(r0.rule_id, "blank");
-        assert_eq!(r0.rule_index, 0);
+        assert_eq!(r0.rule_index, Some(0));
         assert_eq!(r0.level, "warning");
         assert_eq!(r0.message.text, "blank translation");
         assert_eq!(r0.locations.len(), 1);
@@ -442,7 +452,7 @@ mod tests {
 
         let r1 = &run.results[1];
         assert_eq!(r1.rule_id, "escapes");
-        assert_eq!(r1.rule_index, 1);
+        assert_eq!(r1.rule_index, Some(1));
         assert_eq!(r1.level, "error");
         assert_eq!(r1.locations[0].physical_location.region.start_line, 42);
         // No highlights → no columns.
@@ -474,8 +484,10 @@ mod tests {
                 rule: "encoding",
                 severity: Severity::Info,
                 message: Cow::Borrowed("invalid encoding"),
+                msgctxt: None,
                 lines: vec![],
                 misspelled_words: HashSet::new(),
+                spelling_suggestions: HashMap::new(),
                 fix: None,
             }],
             ..Default::default()
@@ -518,6 +530,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_sarif_no_rule_index_for_non_rule_diagnostic() {
+        // A diagnostic not reported by a rule (e.g. file read error) must not point
+        // to an unrelated rule of the driver.
+        let result = vec![CheckFileResult {
+            path: PathBuf::from("test.po"),
+            config: Config::default(),
+            rules: Rules::new(vec![mock_rule("blank", "Checks blank.")]),
+            diagnostics: vec![mock_diagnostic(
+                "test.po",
+                "read-error",
+                Severity::Error,
+                "could not read file",
+                0,
+                "",
+                vec![],
+            )],
+            ..Default::default()
+        }];
+        let sarif = build_sarif(&result);
+        assert_eq!(sarif.runs[0].results[0].rule_id, "read-error");
+        assert!(sarif.runs[0].results[0].rule_index.is_none());
+        let parsed: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&sarif).expect("serialize SARIF"))
+                .expect("SARIF JSON should be valid");
+        assert_eq!(
+            parsed["runs"][0]["results"].as_array().map(Vec::len),
+            Some(1)
+        );
+        assert!(parsed["runs"][0]["results"][0].get("ruleIndex").is_none());
+    }
+
     #[test]
     fn test_build_sarif_rules_deduplicated() {
         // Two files with the same rules → rules appear only once.
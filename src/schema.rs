@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! JSON Schema of the diagnostics displayed with `check --output json`.
+
+use schemars::{Schema, schema_for};
+
+use crate::args;
+use crate::diagnostic::Diagnostic;
+
+/// Return the JSON Schema of the diagnostics (array of `Diagnostic`), generated from
+/// the serialized types so it is always in sync with the JSON output.
+pub fn diagnostics_schema() -> Schema {
+    let mut schema = schema_for!(Vec<Diagnostic>);
+    schema.insert("title".to_string(), "Poexam diagnostics".into());
+    schema
+}
+
+/// Display the JSON Schema of the diagnostics.
+pub fn run_schema(_args: &args::SchemaArgs) -> i32 {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&diagnostics_schema()).unwrap_or_default()
+    );
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostics_schema() {
+        let json = serde_json::to_string(&diagnostics_schema()).unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(schema["type"], "array");
+        assert_eq!(schema["items"]["$ref"], "#/$defs/Diagnostic");
+        let diagnostic = &schema["$defs"]["Diagnostic"];
+        let required: Vec<&str> = diagnostic["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        for field in ["path", "rule", "severity", "message", "lines"] {
+            assert!(required.contains(&field), "missing field: {field}");
+        }
+        assert!(!required.contains(&"msgctxt"));
+        assert!(diagnostic["properties"].get("fix").is_none());
+        assert_eq!(
+            schema["$defs"]["Severity"]["enum"],
+            serde_json::json!(["info", "warning", "error"])
+        );
+        let line = &schema["$defs"]["DiagnosticLine"]["properties"];
+        for field in ["line_number", "column", "message", "highlights"] {
+            assert!(line.get(field).is_some(), "missing field: {field}");
+        }
+    }
+}
@@ -4,22 +4,24 @@
 
 //! Statistics for PO files.
 
-use std::fs::File;
-use std::io::Read;
+use std::collections::BTreeMap;
 use std::ops::AddAssign;
 use std::path::{Path, PathBuf};
 
 use colored::Colorize;
 use rayon::prelude::*;
-use serde::Serialize;
+use serde::{Serialize, Serializer, ser::SerializeStruct};
 
 use crate::args;
-use crate::dir::find_po_files;
+use crate::dir::get_po_files;
+use crate::po::entry::Entry;
 use crate::po::format::language::Language;
 use crate::po::format::{iter::FormatWordPos, strip_formats};
 use crate::po::parser::Parser;
+use crate::progress::Progress;
+use crate::rules::punc::is_sentence_end;
 
-#[derive(Clone, Copy, Default, Serialize)]
+#[derive(Clone, Copy, Default)]
 struct Entries {
     total: u64,
     translated: u64,
@@ -28,7 +30,7 @@ struct Entries {
     obsolete: u64,
 }
 
-#[derive(Clone, Copy, Default, Serialize)]
+#[derive(Clone, Copy, Default)]
 struct Counts {
     id_total: u64,
     id_translated: u64,
@@ -44,11 +46,19 @@ struct Counts {
 #[derive(Default, Serialize)]
 struct StatsFile {
     path: PathBuf,
+    /// Language found in header of the file.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    language: String,
+    /// Number of files in these statistics (more than one for a group or the total).
+    #[serde(skip)]
+    files: usize,
     entries: Entries,
     #[serde(skip_serializing_if = "Option::is_none")]
     words: Option<Counts>,
     #[serde(skip_serializing_if = "Option::is_none")]
     chars: Option<Counts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sentences: Option<Counts>,
 }
 
 impl std::fmt::Display for Entries {
@@ -102,6 +112,23 @@ impl std::fmt::Display for Entries {
     }
 }
 
+impl Serialize for Entries {
+    /// Serialize the `Entries` struct with the counters and their percentages.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Entries", 9)?;
+        state.serialize_field("total", &self.total)?;
+        state.serialize_field("translated", &self.translated)?;
+        state.serialize_field("fuzzy", &self.fuzzy)?;
+        state.serialize_field("untranslated", &self.untranslated)?;
+        state.serialize_field("obsolete", &self.obsolete)?;
+        state.serialize_field("pct_translated", &self.pct_translated())?;
+        state.serialize_field("pct_fuzzy", &self.pct_fuzzy())?;
+        state.serialize_field("pct_untranslated", &self.pct_untranslated())?;
+        state.serialize_field("pct_obsolete", &self.pct_obsolete())?;
+        state.end()
+    }
+}
+
 impl AddAssign for Entries {
     /// Add the values from another `Entries` struct to this one.
     fn add_assign(&mut self, other: Self) {
@@ -191,6 +218,27 @@ impl Entries {
     }
 }
 
+impl Serialize for Counts {
+    /// Serialize the `Counts` struct with the counters and the percentages in msgid.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Counts", 13)?;
+        state.serialize_field("id_total", &self.id_total)?;
+        state.serialize_field("id_translated", &self.id_translated)?;
+        state.serialize_field("id_fuzzy", &self.id_fuzzy)?;
+        state.serialize_field("id_untranslated", &self.id_untranslated)?;
+        state.serialize_field("id_obsolete", &self.id_obsolete)?;
+        state.serialize_field("str_translated", &self.str_translated)?;
+        state.serialize_field("str_fuzzy", &self.str_fuzzy)?;
+        state.serialize_field("str_untranslated", &self.str_untranslated)?;
+        state.serialize_field("str_obsolete", &self.str_obsolete)?;
+        state.serialize_field("pct_id_translated", &self.pct_id_translated())?;
+        state.serialize_field("pct_id_fuzzy", &self.pct_id_fuzzy())?;
+        state.serialize_field("pct_id_untranslated", &self.pct_id_untranslated())?;
+        state.serialize_field("pct_id_obsolete", &self.pct_id_obsolete())?;
+        state.end()
+    }
+}
+
 impl AddAssign for Counts {
     /// Add the values from another `Counts` struct to this one.
     fn add_assign(&mut self, other: Self) {
@@ -209,6 +257,24 @@ impl AddAssign for Counts {
 }
 
 impl Counts {
+    /// Add the counts of an entry in source (`count_id`) and translation (`count_str`),
+    /// according to the status of the entry.
+    fn add_entry(&mut self, entry: &Entry, count_id: u64, count_str: u64) {
+        self.id_total += count_id;
+        if entry.fuzzy {
+            self.id_fuzzy += count_id;
+            self.str_fuzzy += count_str;
+        } else if entry.obsolete {
+            self.id_obsolete += count_id;
+            self.str_obsolete += count_str;
+        } else if entry.is_translated() {
+            self.id_translated += count_id;
+            self.str_translated += count_str;
+        } else {
+            self.id_untranslated += count_id;
+        }
+    }
+
     /// Return the percentage of translated words/characters in msgid as integer.
     pub const fn pct_id_translated(&self) -> u64 {
         if self.id_total == 0 {
@@ -254,10 +320,35 @@ impl StatsFile {
     pub fn new(path: &Path) -> Self {
         Self {
             path: PathBuf::from(path),
+            files: 1,
             ..Default::default()
         }
     }
 
+    /// Create a new empty `StatsFile` to add statistics of several files (a group
+    /// or the total).
+    fn new_sum(path: &Path) -> Self {
+        Self {
+            files: 0,
+            ..Self::new(path)
+        }
+    }
+
+    /// Add statistics of another file to this one.
+    fn add(&mut self, other: &Self) {
+        self.files += other.files;
+        self.entries += other.entries;
+        if let Some(words) = other.words {
+            *self.words.get_or_insert_default() += words;
+        }
+        if let Some(chars) = other.chars {
+            *self.chars.get_or_insert_default() += chars;
+        }
+        if let Some(sentences) = other.sentences {
+            *self.sentences.get_or_insert_default() += sentences;
+        }
+    }
+
     /// Return a formatted string with colors for translated words/characters statistics.
     fn to_string_words_translated(&self) -> String {
         if let Some(words) = &self.words
@@ -274,7 +365,14 @@ impl StatsFile {
                 format!("{:10}", chars.id_translated).bright_green(),
                 format!("({:3}%)", chars.pct_id_translated()).green(),
                 format!("{:10}", chars.str_translated).bright_green(),
-            )
+            ) + &self.sentences.map_or_else(String::new, |sentences| {
+                format!(
+                    " {} {} {}",
+                    format!("{:10}", sentences.id_translated).bright_green(),
+                    format!("({:3}%)", sentences.pct_id_translated()).green(),
+                    format!("{:10}", sentences.str_translated).bright_green(),
+                )
+            })
         } else {
             String::new()
         }
@@ -296,7 +394,14 @@ impl StatsFile {
                 format!("{:10}", chars.id_fuzzy).bright_yellow(),
                 format!("({:3}%)", chars.pct_id_fuzzy()).yellow(),
                 format!("{:10}", chars.str_fuzzy).bright_yellow(),
-            )
+            ) + &self.sentences.map_or_else(String::new, |sentences| {
+                format!(
+                    " {} {} {}",
+                    format!("{:10}", sentences.id_fuzzy).bright_yellow(),
+                    format!("({:3}%)", sentences.pct_id_fuzzy()).yellow(),
+                    format!("{:10}", sentences.str_fuzzy).bright_yellow(),
+                )
+            })
         } else {
             String::new()
         }
@@ -318,7 +423,14 @@ impl StatsFile {
                 format!("{:10}", chars.id_untranslated).bright_red(),
                 format!("({:3}%)", chars.pct_id_untranslated()).red(),
                 format!("{:>10}", chars.str_untranslated).red(),
-            )
+            ) + &self.sentences.map_or_else(String::new, |sentences| {
+                format!(
+                    " {} {} {}",
+                    format!("{:10}", sentences.id_untranslated).bright_red(),
+                    format!("({:3}%)", sentences.pct_id_untranslated()).red(),
+                    format!("{:>10}", sentences.str_untranslated).red(),
+                )
+            })
         } else {
             String::new()
         }
@@ -340,7 +452,14 @@ impl StatsFile {
                 format!("{:10}", chars.id_obsolete).bright_magenta(),
                 format!("({:3}%)", chars.pct_id_obsolete()).magenta(),
                 format!("{:10}", chars.str_obsolete).bright_magenta(),
-            )
+            ) + &self.sentences.map_or_else(String::new, |sentences| {
+                format!(
+                    " {} {} {}",
+                    format!("{:10}", sentences.id_obsolete).bright_magenta(),
+                    format!("({:3}%)", sentences.pct_id_obsolete()).magenta(),
+                    format!("{:10}", sentences.str_obsolete).bright_magenta(),
+                )
+            })
         } else {
             String::new()
         }
@@ -359,7 +478,12 @@ impl StatsFile {
                 words.str_translated,
                 chars.id_total,
                 chars.str_translated,
-            )
+            ) + &self.sentences.map_or_else(String::new, |sentences| {
+                format!(
+                    "{:11}       {:11}",
+                    sentences.id_total, sentences.str_translated
+                )
+            })
         } else {
             String::new()
         }
@@ -370,8 +494,13 @@ impl StatsFile {
         format!(
             "                    Entries          \
             Words (src / translated)     \
-            Chars (src / translated)\n\
+            Chars (src / translated){}\n\
             {}\n{}\n{}\n{}\n{}",
+            if self.sentences.is_some() {
+                "     Sentences (src / translated)"
+            } else {
+                ""
+            },
             self.to_string_words_translated(),
             self.to_string_words_fuzzy(),
             self.to_string_words_untranslated(),
@@ -381,109 +510,145 @@ impl StatsFile {
     }
 }
 
-/// Count words in a given string.
-fn count_words(s: &str) -> u64 {
-    FormatWordPos::new(s, Language::Null).count() as u64
+/// Count words in a given string, `snake_case` being one word if `keep_underscore`
+/// is `true`.
+pub(crate) fn count_words(s: &str, keep_underscore: bool) -> u64 {
+    FormatWordPos::new(s, Language::Null)
+        .with_underscore(keep_underscore)
+        .count() as u64
 }
 
 /// Count characters (non-whitespace or punctuation) in a given string.
-fn count_chars(s: &str) -> u64 {
+pub(crate) fn count_chars(s: &str) -> u64 {
     s.chars()
         .filter(|c| c.is_alphanumeric() || *c == '-')
         .count() as u64
 }
 
+/// Count sentences in a given string: a sentence ends with a run of sentence-ending
+/// punctuation (`.`, `!`, `?`, full-width equivalents, etc.) followed by a whitespace
+/// or the end of string (for ASCII punctuation), and the last sentence may have no
+/// punctuation.
+fn count_sentences(s: &str) -> u64 {
+    let mut count = 0;
+    let mut in_sentence = false;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() {
+            in_sentence = true;
+        } else if in_sentence
+            && is_sentence_end(c)
+            && (!c.is_ascii()
+                || chars
+                    .peek()
+                    .is_none_or(|&next| next.is_whitespace() || is_sentence_end(next)))
+        {
+            count += 1;
+            in_sentence = false;
+        }
+    }
+    if in_sentence {
+        count += 1;
+    }
+    count
+}
+
 /// Compute statistics for a single PO file at the given path.
-fn stats_file(path: &PathBuf, args: &args::StatsArgs) -> Result<StatsFile, std::io::Error> {
-    let mut file = File::open(path)?;
-    let mut buf = Vec::new();
-    let _ = file.read_to_end(&mut buf)?;
-    let parser = Parser::new(&buf);
-    let mut stats = StatsFile::new(path.as_path());
+fn stats_file(path: &Path, args: &args::StatsArgs) -> Result<StatsFile, std::io::Error> {
+    let file = Parser::from_path(path)?;
+    let mut parser = file.parser();
+    let mut stats = StatsFile::new(path);
     let mut words = Counts::default();
     let mut chars = Counts::default();
-    for entry in parser {
+    let mut sentences = Counts::default();
+    for entry in parser.by_ref() {
         if entry.is_header() {
             continue;
         }
-        let (words_id, chars_id) = if args.words
-            && let Some(msgid) = &entry.msgid
-        {
-            let stripped = strip_formats(&msgid.value, entry.format_language);
-            (count_words(&stripped), count_chars(&stripped))
-        } else {
-            (0, 0)
-        };
-        let (words_str, chars_str) = if args.words
-            && let Some(msgstr) = entry.msgstr.get(&0)
-        {
-            let stripped = strip_formats(&msgstr.value, entry.format_language);
-            (count_words(&stripped), count_chars(&stripped))
-        } else {
-            (0, 0)
-        };
         stats.entries.total += 1;
-        words.id_total += words_id;
-        chars.id_total += chars_id;
         if entry.fuzzy {
             stats.entries.fuzzy += 1;
-            words.id_fuzzy += words_id;
-            chars.id_fuzzy += chars_id;
-            words.str_fuzzy += words_str;
-            chars.str_fuzzy += chars_str;
         } else if entry.obsolete {
             stats.entries.obsolete += 1;
-            words.id_obsolete += words_id;
-            chars.id_obsolete += chars_id;
-            words.str_obsolete += words_str;
-            chars.str_obsolete += chars_str;
         } else if entry.is_translated() {
             stats.entries.translated += 1;
-            words.id_translated += words_id;
-            chars.id_translated += chars_id;
-            words.str_translated += words_str;
-            chars.str_translated += chars_str;
         } else {
             stats.entries.untranslated += 1;
-            words.id_untranslated += words_id;
-            chars.id_untranslated += chars_id;
+        }
+        if !args.words {
+            continue;
+        }
+        let stripped_id = entry
+            .msgid
+            .as_ref()
+            .map(|msgid| strip_formats(&msgid.value, entry.format_language))
+            .unwrap_or_default();
+        let stripped_str = entry
+            .msgstr
+            .get(&0)
+            .map(|msgstr| strip_formats(&msgstr.value, entry.format_language))
+            .unwrap_or_default();
+        words.add_entry(
+            &entry,
+            count_words(&stripped_id, args.words_keep_underscore),
+            count_words(&stripped_str, args.words_keep_underscore),
+        );
+        chars.add_entry(
+            &entry,
+            count_chars(&stripped_id),
+            count_chars(&stripped_str),
+        );
+        if args.sentences {
+            sentences.add_entry(
+                &entry,
+                count_sentences(&stripped_id),
+                count_sentences(&stripped_str),
+            );
         }
     }
     if args.words {
         stats.words = Some(words);
         stats.chars = Some(chars);
     }
+    if args.sentences {
+        stats.sentences = Some(sentences);
+    }
+    stats.language = parser.language().to_string();
     Ok(stats)
 }
 
 /// Compute total for statistics.
 fn compute_total_stats(stats: &Vec<StatsFile>) -> StatsFile {
-    let mut total = StatsFile::default();
-    let mut words = Counts::default();
-    let mut chars = Counts::default();
-    let mut add_words = false;
-    let mut add_chars = false;
+    let mut total = StatsFile::new_sum(Path::new(""));
     for stat in stats {
-        total.entries += stat.entries;
-        if let Some(stat_words) = &stat.words {
-            words += *stat_words;
-            add_words = true;
-        }
-        if let Some(stat_chars) = &stat.chars {
-            chars += *stat_chars;
-            add_chars = true;
-        }
-    }
-    total.path = PathBuf::from(format!("Total ({})", stats.len()));
-    if add_words {
-        total.words = Some(words);
-    }
-    if add_chars {
-        total.chars = Some(chars);
+        total.add(stat);
     }
+    total.path = PathBuf::from(format!("Total ({})", total.files));
     total
 }
 
+/// Group statistics by language, sorted by language (files without language are
+/// grouped under "unknown").
+fn group_by_language(stats: &[StatsFile]) -> Vec<StatsFile> {
+    let mut groups: BTreeMap<&str, StatsFile> = BTreeMap::new();
+    for stat in stats {
+        let language = if stat.language.is_empty() {
+            "unknown"
+        } else {
+            stat.language.as_str()
+        };
+        groups
+            .entry(language)
+            .or_insert_with(|| {
+                let mut group = StatsFile::new_sum(Path::new(language));
+                group.language = language.to_string();
+                group
+            })
+            .add(stat);
+    }
+    groups.into_values().collect()
+}
+
 /// Display statistics for a list of PO files, formatted according to the arguments.
 fn display_stats(stats: &Vec<StatsFile>, args: &args::StatsArgs) -> i32 {
     let path_max_len = stats
@@ -528,20 +693,44 @@ fn display_stats(stats: &Vec<StatsFile>, args: &args::StatsArgs) -> i32 {
     0
 }
 
-/// Compute and display statistics for all PO files.
-pub fn run_stats(args: &args::StatsArgs) -> i32 {
-    let po_files = find_po_files(&args.files);
-    let mut stats: Vec<StatsFile> = po_files
-        .par_iter()
-        .map(|path| {
-            stats_file(path, args).map_err(|e| {
-                eprintln!("Error processing file {}: {}", path.display(), e);
-                e
-            })
-        })
-        .filter_map(Result::ok)
-        .collect();
-    match args.sort {
+/// Return the exit code according to the minimum percentage of translated entries
+/// (options `--min-translated` for each file and `--min-translated-total` for all
+/// files), and display the files below the minimum on stderr.
+///
+/// Files without entries are ignored.
+fn check_min_translated(stats: &[StatsFile], total: &Entries, args: &args::StatsArgs) -> i32 {
+    let mut rc = 0;
+    if let Some(min_translated) = args.min_translated {
+        for stat in stats
+            .iter()
+            .filter(|s| s.entries.total > 0 && s.entries.pct_translated() < min_translated)
+        {
+            eprintln!(
+                "{}: {}: {}% translated (min: {min_translated}%)",
+                "Error".red(),
+                stat.path.display(),
+                stat.entries.pct_translated(),
+            );
+            rc = 1;
+        }
+    }
+    if let Some(min_translated_total) = args.min_translated_total
+        && total.total > 0
+        && total.pct_translated() < min_translated_total
+    {
+        eprintln!(
+            "{}: total: {}% translated (min: {min_translated_total}%)",
+            "Error".red(),
+            total.pct_translated(),
+        );
+        rc = 1;
+    }
+    rc
+}
+
+/// Sort statistics of files.
+fn sort_stats(stats: &mut [StatsFile], sort: &args::StatsSort) {
+    match sort {
         args::StatsSort::Path => {
             stats.sort_by(|a, b| a.path.cmp(&b.path));
         }
@@ -560,11 +749,60 @@ pub fn run_stats(args: &args::StatsArgs) -> i32 {
                 )
             });
         }
+        args::StatsSort::Size => {
+            stats.sort_by(|a, b| {
+                b.entries
+                    .total
+                    .cmp(&a.entries.total)
+                    .then_with(|| a.path.cmp(&b.path))
+            });
+        }
     }
-    if stats.len() > 1 {
-        stats.push(compute_total_stats(&stats));
+}
+
+/// Compute and display statistics for all PO files.
+pub fn run_stats(args: &args::StatsArgs) -> i32 {
+    let Some(po_files) = get_po_files(
+        &args.files,
+        args.files_from.as_deref(),
+        &args.exclude,
+        false,
+    ) else {
+        return 1;
+    };
+    let progress = Progress::new(
+        po_files.len(),
+        args.progress && args.output == args::StatsOutputFormat::Human,
+    );
+    let mut stats: Vec<StatsFile> = po_files
+        .par_iter()
+        .map(|path| {
+            let result = stats_file(path, args).map_err(|e| {
+                eprintln!("Error processing file {}: {}", path.display(), e);
+                e
+            });
+            progress.inc();
+            result
+        })
+        .filter_map(Result::ok)
+        .collect();
+    progress.finish();
+    sort_stats(&mut stats, &args.sort);
+    if args.group_by_language {
+        stats = group_by_language(&stats);
     }
-    display_stats(&stats, args)
+    let count_files = stats.len();
+    let total = compute_total_stats(&stats);
+    let total_entries = total.entries;
+    if count_files > 1 {
+        stats.push(total);
+    }
+    let rc = display_stats(&stats, args);
+    rc.max(check_min_translated(
+        &stats[..count_files],
+        &total_entries,
+        args,
+    ))
 }
 
 #[cfg(test)]
@@ -783,9 +1021,11 @@ mod tests {
 
     #[test]
     fn test_count_words() {
-        assert_eq!(count_words(""), 0);
-        assert_eq!(count_words("hello"), 1);
-        assert_eq!(count_words("hello, world!"), 2);
+        assert_eq!(count_words("", false), 0);
+        assert_eq!(count_words("hello", false), 1);
+        assert_eq!(count_words("hello, world!", false), 2);
+        assert_eq!(count_words("set max_count to 10", false), 5);
+        assert_eq!(count_words("set max_count to 10", true), 4);
     }
 
     #[test]
@@ -853,4 +1093,210 @@ mod tests {
 
         assert!(total.path.display().to_string().contains("Total (2)"));
     }
+
+    fn stats_args() -> args::StatsArgs {
+        args::StatsArgs {
+            files: vec![],
+            exclude: vec![],
+            files_from: None,
+            output: args::StatsOutputFormat::default(),
+            sort: args::StatsSort::default(),
+            words: false,
+            sentences: false,
+            words_keep_underscore: false,
+            group_by_language: false,
+            min_translated: None,
+            min_translated_total: None,
+            progress: false,
+        }
+    }
+
+    fn make_stats_files() -> Vec<StatsFile> {
+        let mut sf1 = StatsFile::new(Path::new("de.po"));
+        sf1.entries = make_entries(10, 10, 0, 0, 0);
+        let mut sf2 = StatsFile::new(Path::new("fr.po"));
+        sf2.entries = make_entries(10, 7, 1, 2, 0);
+        let empty = StatsFile::new(Path::new("ja.po"));
+        vec![sf1, sf2, empty]
+    }
+
+    #[test]
+    fn test_check_min_translated_no_threshold() {
+        let stats = make_stats_files();
+        let total = compute_total_stats(&stats);
+        assert_eq!(
+            check_min_translated(&stats, &total.entries, &stats_args()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_check_min_translated_per_file() {
+        let stats = make_stats_files();
+        let total = compute_total_stats(&stats);
+        let mut args = stats_args();
+        args.min_translated = Some(70);
+        assert_eq!(check_min_translated(&stats, &total.entries, &args), 0);
+        // fr.po is 70% translated (ja.po has no entries and is ignored).
+        args.min_translated = Some(71);
+        assert_eq!(check_min_translated(&stats, &total.entries, &args), 1);
+    }
+
+    #[test]
+    fn test_check_min_translated_total() {
+        let stats = make_stats_files();
+        let total = compute_total_stats(&stats);
+        let mut args = stats_args();
+        // Total is 85% translated.
+        args.min_translated_total = Some(85);
+        assert_eq!(check_min_translated(&stats, &total.entries, &args), 0);
+        args.min_translated_total = Some(86);
+        assert_eq!(check_min_translated(&stats, &total.entries, &args), 1);
+        // No entries at all.
+        assert_eq!(check_min_translated(&[], &Entries::default(), &args), 0);
+    }
+
+    #[test]
+    fn test_group_by_language() {
+        let mut sf1 = StatsFile::new(Path::new("po/fr/a.po"));
+        sf1.language = "fr".to_string();
+        sf1.entries = make_entries(10, 8, 1, 1, 0);
+        sf1.words = Some(make_counts(50, 40, 5, 5, 0, 38, 4, 0, 0));
+        let mut sf2 = StatsFile::new(Path::new("po/de.po"));
+        sf2.language = "de".to_string();
+        sf2.entries = make_entries(5, 5, 0, 0, 0);
+        sf2.words = Some(make_counts(20, 20, 0, 0, 0, 22, 0, 0, 0));
+        let mut sf3 = StatsFile::new(Path::new("po/fr/b.po"));
+        sf3.language = "fr".to_string();
+        sf3.entries = make_entries(20, 15, 3, 1, 1);
+        sf3.words = Some(make_counts(100, 75, 15, 5, 5, 70, 12, 0, 4));
+        let sf4 = StatsFile::new(Path::new("po/xx.po"));
+
+        let groups = group_by_language(&[sf1, sf2, sf3, sf4]);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].path, PathBuf::from("de"));
+        assert_eq!(groups[0].entries.total, 5);
+        assert_eq!(groups[0].words.unwrap().id_total, 20);
+        assert_eq!(groups[1].path, PathBuf::from("fr"));
+        assert_eq!(groups[1].language, "fr");
+        assert_eq!(groups[1].files, 2);
+        assert_eq!(groups[1].entries.total, 30);
+        assert_eq!(groups[1].entries.translated, 23);
+        assert_eq!(groups[1].words.unwrap().id_total, 150);
+        assert!(groups[1].chars.is_none());
+        assert_eq!(groups[2].path, PathBuf::from("unknown"));
+        assert_eq!(groups[2].entries.total, 0);
+        // The total counts the files, not the groups.
+        let total = compute_total_stats(&groups);
+        assert_eq!(total.path, PathBuf::from("Total (4)"));
+        assert_eq!(total.entries.total, 35);
+    }
+
+    #[test]
+    fn test_stats_file_language() {
+        let tmp = tempfile::TempDir::with_prefix("poexam-stats-").expect("create temp dir");
+        let path = tmp.path().join("fr.po");
+        std::fs::write(
+            &path,
+            "msgid \"\"\nmsgstr \"Language: fr\\n\"\n\nmsgid \"a\"\nmsgstr \"b\"\n",
+        )
+        .expect("write po file");
+        let stats = stats_file(&path, &stats_args()).expect("stats");
+        assert_eq!(stats.language, "fr");
+        assert_eq!(stats.entries.total, 1);
+        assert_eq!(stats.entries.translated, 1);
+    }
+
+    #[test]
+    fn test_stats_file_json_percentages() {
+        let mut sf = StatsFile::new(Path::new("fr.po"));
+        sf.entries = make_entries(3, 1, 1, 1, 0);
+        sf.words = Some(make_counts(50, 40, 5, 5, 0, 38, 4, 0, 0));
+        let json = serde_json::to_string(&sf).expect("serialize stats");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+        let entries = &value["entries"];
+        assert_eq!(entries["total"], 3);
+        assert_eq!(entries["translated"], 1);
+        assert_eq!(entries["pct_translated"], sf.entries.pct_translated());
+        assert_eq!(entries["pct_translated"], 33);
+        assert_eq!(entries["pct_fuzzy"], 33);
+        assert_eq!(entries["pct_untranslated"], 33);
+        assert_eq!(entries["pct_obsolete"], 0);
+        let words = &value["words"];
+        assert_eq!(words["id_total"], 50);
+        assert_eq!(words["pct_id_translated"], 80);
+        assert_eq!(words["pct_id_fuzzy"], 10);
+        assert!(value.get("chars").is_none());
+    }
+
+    #[test]
+    fn test_sort_stats() {
+        let mut stats = vec![];
+        for (path, total, translated) in [
+            ("c.po", 10, 5),
+            ("a.po", 20, 10),
+            ("d.po", 5, 5),
+            ("b.po", 10, 10),
+        ] {
+            let mut sf = StatsFile::new(Path::new(path));
+            sf.entries = make_entries(total, translated, 0, total - translated, 0);
+            stats.push(sf);
+        }
+        let paths = |stats: &[StatsFile]| {
+            stats
+                .iter()
+                .map(|s| s.path.display().to_string())
+                .collect::<Vec<_>>()
+        };
+        sort_stats(&mut stats, &args::StatsSort::Size);
+        assert_eq!(paths(&stats), vec!["a.po", "b.po", "c.po", "d.po"]);
+        sort_stats(&mut stats, &args::StatsSort::Path);
+        assert_eq!(paths(&stats), vec!["a.po", "b.po", "c.po", "d.po"]);
+        sort_stats(&mut stats, &args::StatsSort::Status);
+        assert_eq!(paths(&stats), vec!["b.po", "d.po", "a.po", "c.po"]);
+        sort_stats(&mut stats, &args::StatsSort::Size);
+        assert_eq!(paths(&stats), vec!["a.po", "b.po", "c.po", "d.po"]);
+    }
+
+    #[test]
+    fn test_count_sentences() {
+        assert_eq!(count_sentences(""), 0);
+        assert_eq!(count_sentences("..."), 0);
+        assert_eq!(count_sentences("Open file"), 1);
+        assert_eq!(count_sentences("Hello."), 1);
+        assert_eq!(count_sentences("Hello. How are you? Fine!"), 3);
+        assert_eq!(count_sentences("What?! Really..."), 2);
+        assert_eq!(count_sentences("Version 3.14 is out. See e.g. the news"), 3);
+        assert_eq!(count_sentences("你好。世界！"), 2);
+        assert_eq!(count_sentences("Bonjour ! Ça va ?"), 2);
+    }
+
+    #[test]
+    fn test_stats_file_sentences() {
+        let tmp = tempfile::TempDir::with_prefix("poexam-stats-").expect("create temp dir");
+        let path = tmp.path().join("fr.po");
+        std::fs::write(
+            &path,
+            "msgid \"\"\nmsgstr \"Language: fr\\n\"\n\n\
+             msgid \"One. Two. Three.\"\nmsgstr \"Un. Deux, trois.\"\n\n\
+             msgid \"Untranslated! Really?\"\nmsgstr \"\"\n",
+        )
+        .expect("write po file");
+        let mut args = stats_args();
+        args.words = true;
+        let stats = stats_file(&path, &args).expect("stats");
+        assert!(stats.sentences.is_none());
+        args.sentences = true;
+        let stats = stats_file(&path, &args).expect("stats");
+        let sentences = stats.sentences.expect("sentences counted");
+        assert_eq!(sentences.id_total, 5);
+        assert_eq!(sentences.id_translated, 3);
+        assert_eq!(sentences.str_translated, 2);
+        assert_eq!(sentences.id_untranslated, 2);
+        assert!(
+            stats
+                .to_string_words()
+                .contains("Sentences (src / translated)")
+        );
+    }
 }
@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Watch mode of the `check` command: check again the PO files when they change.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Duration,
+};
+
+use colored::Colorize;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use crate::{args, checker::check_files};
+
+/// Delay to wait for other events after a change, so that rapid saves (or editors
+/// writing a file in several steps) trigger a single check.
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(200);
+
+/// Return the absolute path of a file, used to match the paths of the events.
+fn absolute_path(path: &Path) -> PathBuf {
+    path.canonicalize()
+        .or_else(|_| std::path::absolute(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Return the sorted list of watched files changed by the events.
+///
+/// The keys of `files` are the absolute paths of the watched files and the values
+/// the paths displayed in diagnostics. Events on other files in the watched
+/// directories and access events (no change in the content) are ignored.
+fn changed_files(events: &[Event], files: &HashMap<PathBuf, PathBuf>) -> Vec<PathBuf> {
+    events
+        .iter()
+        .filter(|event| {
+            matches!(
+                event.kind,
+                EventKind::Any | EventKind::Create(_) | EventKind::Modify(_)
+            )
+        })
+        .flat_map(|event| &event.paths)
+        .filter_map(|path| files.get(path))
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Watch the PO files and check again the files changed, until the process is
+/// interrupted (Ctrl-C).
+///
+/// The parent directories of the files are watched (and not the files themselves),
+/// so that the files saved by editors with a rename (write to a temporary file then
+/// rename) are still detected.
+pub fn watch_files(paths: &[PathBuf], args: &args::CheckArgs) -> i32 {
+    let files: HashMap<PathBuf, PathBuf> = paths
+        .iter()
+        .map(|path| (absolute_path(path), path.clone()))
+        .collect();
+    let dirs: BTreeSet<&Path> = files.keys().filter_map(|path| path.parent()).collect();
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            eprintln!("{}: could not watch files: {err}", "Error".red());
+            return 1;
+        }
+    };
+    for dir in dirs {
+        if let Err(err) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+            eprintln!(
+                "{}: could not watch directory {}: {err}",
+                "Error".red(),
+                dir.display()
+            );
+            return 1;
+        }
+    }
+    println!(
+        "Watching {} files for changes (press Ctrl-C to stop)...",
+        files.len()
+    );
+    while let Ok(event) = rx.recv() {
+        let mut events: Vec<Event> = event.into_iter().collect();
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE_DELAY) {
+            events.extend(event);
+        }
+        let changed = changed_files(&events, &files);
+        if !changed.is_empty() {
+            println!();
+            check_files(&changed, args);
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind, DataChange, ModifyKind, RemoveKind};
+
+    fn event(kind: EventKind, paths: &[&str]) -> Event {
+        paths.iter().fold(Event::new(kind), |event, path| {
+            event.add_path(PathBuf::from(path))
+        })
+    }
+
+    fn watched_files() -> HashMap<PathBuf, PathBuf> {
+        HashMap::from([
+            (
+                PathBuf::from("/project/po/fr.po"),
+                PathBuf::from("po/fr.po"),
+            ),
+            (
+                PathBuf::from("/project/po/de.po"),
+                PathBuf::from("po/de.po"),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_changed_files_no_events() {
+        assert!(changed_files(&[], &watched_files()).is_empty());
+    }
+
+    #[test]
+    fn test_changed_files_modified() {
+        let events = [
+            event(
+                EventKind::Modify(ModifyKind::Data(DataChange::Content)),
+                &["/project/po/fr.po"],
+            ),
+            event(EventKind::Create(CreateKind::File), &["/project/po/de.po"]),
+        ];
+        assert_eq!(
+            changed_files(&events, &watched_files()),
+            vec![PathBuf::from("po/de.po"), PathBuf::from("po/fr.po")]
+        );
+    }
+
+    #[test]
+    fn test_changed_files_debounced() {
+        // Several events on the same file (rapid saves) check it only once.
+        let events = [
+            event(EventKind::Any, &["/project/po/fr.po"]),
+            event(
+                EventKind::Modify(ModifyKind::Data(DataChange::Content)),
+                &["/project/po/fr.po"],
+            ),
+            event(
+                EventKind::Modify(ModifyKind::Any),
+                &["/project/po/fr.po.tmp", "/project/po/fr.po"],
+            ),
+        ];
+        assert_eq!(
+            changed_files(&events, &watched_files()),
+            vec![PathBuf::from("po/fr.po")]
+        );
+    }
+
+    #[test]
+    fn test_changed_files_ignored() {
+        let events = [
+            // Not a watched file.
+            event(
+                EventKind::Modify(ModifyKind::Data(DataChange::Content)),
+                &["/project/po/fr.po~", "/project/po/it.po"],
+            ),
+            // Content not changed.
+            event(EventKind::Access(AccessKind::Any), &["/project/po/fr.po"]),
+            event(EventKind::Remove(RemoveKind::File), &["/project/po/de.po"]),
+        ];
+        assert!(changed_files(&events, &watched_files()).is_empty());
+    }
+}
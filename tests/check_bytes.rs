@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2026 Sébastien Helleu <flashcode@flashtux.org>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Integration tests of the library API.
+
+use std::path::PathBuf;
+
+use poexam::{CheckConfig, Config, Severity, check_bytes};
+
+const PO_CONTENT: &str = r#"
+msgid ""
+msgstr ""
+"Project-Id-Version: poexam\n"
+"Report-Msgid-Bugs-To: flashcode@flashtux.org\n"
+"POT-Creation-Date: 2026-02-01 18:12:08+0100\n"
+"PO-Revision-Date: 2026-02-01 18:12:08+0100\n"
+"Last-Translator: Sébastien Helleu <flashcode@flashtux.org>\n"
+"Language-Team: French <traduc@traduc.org>\n"
+"Language: fr\n"
+"Content-Type: text/plain; charset=UTF-8\n"
+"Content-Transfer-Encoding: 8bit\n"
+
+msgid "this is a test"
+msgstr "ceci est une fautte "
+
+#, c-format
+msgid "Hello, %s"
+msgstr "ceci"
+"#;
+
+#[test]
+fn test_check_bytes_default_rules() {
+    let diags = check_bytes(PO_CONTENT.as_bytes(), &Config::default());
+    let rules: Vec<_> = diags.iter().map(|diag| diag.rule).collect();
+    assert_eq!(rules, vec!["whitespace-end", "formats"]);
+    assert!(diags.iter().all(|diag| diag.path.as_os_str().is_empty()));
+    assert_eq!(diags[1].severity, Severity::Error);
+    assert_eq!(diags[1].lines[0].line_number, 18);
+    assert_eq!(diags[1].lines[0].column, 1);
+}
+
+#[test]
+fn test_check_bytes_select_ignore() {
+    let config = Config {
+        check: CheckConfig {
+            ignore: vec!["formats".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let diags = check_bytes(PO_CONTENT.as_bytes(), &config);
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0].rule, "whitespace-end");
+
+    let config = Config {
+        check: CheckConfig {
+            select: vec!["spelling-str".to_string()],
+            path_dicts: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("resources/test"),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let diags = check_bytes(PO_CONTENT.as_bytes(), &config);
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0].rule, "spelling-str");
+    assert_eq!(
+        diags[0].misspelled_words.iter().collect::<Vec<_>>(),
+        vec!["fautte"]
+    );
+}
+
+#[test]
+fn test_check_bytes_severity_override() {
+    let mut config = Config::default();
+    config
+        .severity
+        .insert("whitespace-end".to_string(), Severity::Error);
+    let diags = check_bytes(PO_CONTENT.as_bytes(), &config);
+    assert_eq!(diags[0].rule, "whitespace-end");
+    assert_eq!(diags[0].severity, Severity::Error);
+}
+
+#[test]
+fn test_check_bytes_unknown_rule() {
+    let config = Config {
+        check: CheckConfig {
+            select: vec!["unknown".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let diags = check_bytes(PO_CONTENT.as_bytes(), &config);
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0].rule, "rules-error");
+    assert_eq!(diags[0].severity, Severity::Error);
+}